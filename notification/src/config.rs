@@ -14,6 +14,19 @@ pub fn config_path() -> PathBuf {
     )
 }
 
+/// Resolves a relative path of the config against the directory of the config file, like the
+/// paths of the queue and the history
+pub fn resolve_path(path: impl AsRef<Path>) -> PathBuf {
+    let path = path.as_ref();
+    match std::env::var_os(CONFIG_PATH_ENV) {
+        Some(config_path) if path.is_relative() => Path::new(&config_path)
+            .parent()
+            .unwrap_or(Path::new("."))
+            .join(path),
+        _ => path.to_path_buf(),
+    }
+}
+
 pub trait NotificationServiceConfig: schemars::JsonSchema {
     type Patch: schemars::JsonSchema;
     fn schema() -> schemars::Schema {
@@ -47,6 +60,25 @@ pub struct Config {
     pub default_notification_service: Option<String>,
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub notification_services: HashMap<String, NotisNotificationService>,
+    /// Named lists of service ids, a notification sent to a group is sent via each of its services
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub service_groups: HashMap<String, Vec<String>>,
+    /// Named lists of service ids which are tried in order until one delivers the notification, a
+    /// chain can be used like a service, e.g. as default service
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub failover_chains: HashMap<String, Vec<String>>,
+    /// Named routes which select the services of notifications sent without a service
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub routes: HashMap<String, crate::routing::Route>,
+    /// Named templates rendering the notifications which reference them
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub templates: HashMap<String, crate::templates::Template>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub smtp_ingress: Option<crate::smtp_ingress::Config>,
+    #[serde(default, skip_serializing_if = "crate::queue::Config::is_default")]
+    pub queue: crate::queue::Config,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub history: Option<crate::history::Config>,
 }
 
 impl Default for Config {
@@ -55,7 +87,14 @@ impl Default for Config {
             port: DEFAULT_PORT,
             notification_services: Default::default(),
             default_notification_service: Default::default(),
+            service_groups: Default::default(),
+            failover_chains: Default::default(),
+            routes: Default::default(),
+            templates: Default::default(),
             trace_filter: Default::default(),
+            smtp_ingress: Default::default(),
+            queue: Default::default(),
+            history: None,
         }
     }
 }
@@ -73,6 +112,79 @@ impl Config {
                 (smtp.type_string(), smtp),
                 (log.type_string(), log),
             ]),
+            service_groups: HashMap::from([(
+                "operators".to_string(),
+                vec!["smtp".to_string(), "log".to_string()],
+            )]),
+            failover_chains: HashMap::from([(
+                "alarms".to_string(),
+                vec!["smtp".to_string(), "log".to_string()],
+            )]),
+            routes: HashMap::from([(
+                "critical".to_string(),
+                crate::routing::Route {
+                    conditions: crate::routing::Conditions {
+                        severity: vec![crate::services::Severity::Critical],
+                        ..Default::default()
+                    },
+                    targets: vec![crate::routing::Target {
+                        service: "alarms".to_string(),
+                        options: None,
+                    }],
+                },
+            )]),
+            templates: HashMap::from([(
+                "alarm".to_string(),
+                crate::templates::Template {
+                    subject: Some("{{ machine }}: {{ notification.title }}".to_string()),
+                    body: Some("{{ notification.content }}\n\nMachine: {{ machine }}".to_string()),
+                    ..Default::default()
+                },
+            )]),
+            smtp_ingress: None,
+            queue: Default::default(),
+            history: Some(crate::history::Config::example()),
+        }
+    }
+}
+
+/// Type of failover chains when they are referenced like a service
+pub const FAILOVER_CHAIN_TYPE: &str = "failover";
+
+/// Service or failover chain to which a notification can be sent
+pub enum Target<'a> {
+    Service(&'a NotisNotificationService),
+    FailoverChain(&'a [String]),
+}
+
+impl Target<'_> {
+    pub fn type_string(&self) -> String {
+        match self {
+            Self::Service(service) => service.type_string(),
+            Self::FailoverChain(_) => FAILOVER_CHAIN_TYPE.to_string(),
+        }
+    }
+
+    /// Failover chains do not accept options, as they would differ between their services
+    pub fn validate_options(&self, options: &serde_json::Value) -> Result<(), serde_json::Error> {
+        match self {
+            Self::Service(service) => service.validate_options(options),
+            Self::FailoverChain(_) => Err(serde::de::Error::custom(
+                "Options are not supported by failover chains",
+            )),
+        }
+    }
+}
+
+impl Config {
+    /// Resolves a service or failover chain by its id, services take precedence over chains
+    pub fn target(&self, id: &str) -> Option<Target<'_>> {
+        match self.notification_services.get(id) {
+            Some(service) => Some(Target::Service(service)),
+            None => self
+                .failover_chains
+                .get(id)
+                .map(|services| Target::FailoverChain(services)),
         }
     }
 }
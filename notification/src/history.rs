@@ -0,0 +1,583 @@
+//! Persistent history of delivered and discarded notifications with retention and full-text search
+
+mod config;
+
+use crate::queue::{DeliveryState, Entry};
+pub use config::Config;
+use rusqlite::types::Value;
+use rusqlite::{Connection, OptionalExtension, params, params_from_iter};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS notifications (
+    id INTEGER PRIMARY KEY,
+    uuid BLOB NOT NULL UNIQUE,
+    service_id TEXT NOT NULL,
+    title TEXT NOT NULL,
+    content TEXT,
+    receivers TEXT NOT NULL,
+    state TEXT NOT NULL,
+    attempts INTEGER NOT NULL,
+    error TEXT,
+    created INTEGER NOT NULL,
+    finished INTEGER NOT NULL,
+    delivered_by TEXT
+);
+CREATE INDEX IF NOT EXISTS notifications_finished ON notifications (finished);
+CREATE TABLE IF NOT EXISTS attachments (
+    id INTEGER PRIMARY KEY,
+    notification INTEGER NOT NULL REFERENCES notifications (id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    file_name TEXT NOT NULL,
+    content_type TEXT NOT NULL,
+    size INTEGER NOT NULL,
+    content BLOB
+);
+CREATE INDEX IF NOT EXISTS attachments_notification ON attachments (notification);
+CREATE VIRTUAL TABLE IF NOT EXISTS search USING fts5 (
+    title, content, content = 'notifications', content_rowid = 'id'
+);
+CREATE TRIGGER IF NOT EXISTS notifications_insert AFTER INSERT ON notifications BEGIN
+    INSERT INTO search (rowid, title, content) VALUES (new.id, new.title, new.content);
+END;
+CREATE TRIGGER IF NOT EXISTS notifications_delete AFTER DELETE ON notifications BEGIN
+    INSERT INTO search (search, rowid, title, content) VALUES ('delete', old.id, old.title, old.content);
+END;
+";
+const DELIVERED: &str = "delivered";
+const FAILED: &str = "failed";
+
+/// Notification which was delivered or discarded
+#[derive(Clone, Debug)]
+pub struct Record {
+    pub id: Uuid,
+    pub service_id: String,
+    pub title: String,
+    pub content: Option<String>,
+    pub receivers: Vec<String>,
+    pub attachments: Vec<AttachmentRecord>,
+    /// Either [DeliveryState::Delivered] or [DeliveryState::Failed]
+    pub state: DeliveryState,
+    pub attempts: u32,
+    pub error: Option<String>,
+    pub created: SystemTime,
+    pub finished: SystemTime,
+    /// Id of the service which delivered the notification, differs from `service_id` for failover
+    /// chains
+    pub delivered_by: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AttachmentRecord {
+    pub file_name: String,
+    pub content_type: String,
+    pub size: u64,
+    /// Whether the content of the attachment is still stored
+    pub stored: bool,
+}
+
+/// Criteria of [History::search], unset criteria match every notification
+#[derive(Default)]
+pub struct Query {
+    pub service_id: Option<String>,
+    pub state: Option<DeliveryState>,
+    /// Matches notifications finished at or after this time
+    pub since: Option<SystemTime>,
+    /// Matches notifications finished before this time
+    pub until: Option<SystemTime>,
+    /// Words which all have to occur in the title or content
+    pub search: Option<String>,
+    pub offset: u32,
+    pub limit: u32,
+}
+
+/// Notifications matching a query, the most recent first
+pub struct Page {
+    /// Number of all matching notifications
+    pub total: u64,
+    pub records: Vec<Record>,
+}
+
+pub struct History {
+    config: Config,
+    connection: Arc<Mutex<Connection>>,
+}
+
+fn to_millis(time: SystemTime) -> i64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|duration| i64::try_from(duration.as_millis()).unwrap_or(i64::MAX))
+        .unwrap_or_default()
+}
+
+fn from_millis(millis: i64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_millis(millis.max(0).unsigned_abs())
+}
+
+fn state_name(state: DeliveryState) -> Option<&'static str> {
+    match state {
+        DeliveryState::Delivered => Some(DELIVERED),
+        DeliveryState::Failed => Some(FAILED),
+        DeliveryState::Queued | DeliveryState::Sending | DeliveryState::Retrying => None,
+    }
+}
+
+/// Quotes every word, so that the search cannot contain fts5 operators or syntax errors
+fn match_expression(search: &str) -> Option<String> {
+    let words: Vec<_> = search
+        .split_whitespace()
+        .map(|word| format!("\"{}\"", word.replace('"', "\"\"")))
+        .collect();
+    (!words.is_empty()).then(|| words.join(" "))
+}
+
+fn insert(
+    connection: &Connection,
+    entry: &Entry,
+    state: DeliveryState,
+    receivers: Vec<String>,
+    store_attachments: bool,
+) -> rusqlite::Result<()> {
+    connection.execute(
+        "INSERT INTO notifications (
+            uuid, service_id, title, content, receivers, state, attempts, error, created, finished,
+            delivered_by
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+        params![
+            entry.id,
+            entry.service_id,
+            entry.title,
+            entry.content,
+            serde_json::Value::from(receivers),
+            state_name(state).unwrap_or(FAILED),
+            entry.attempts,
+            entry.last_error,
+            to_millis(entry.created),
+            to_millis(entry.updated),
+            entry.delivered_by,
+        ],
+    )?;
+    let notification = connection.last_insert_rowid();
+    for (position, attachment) in entry.attachments.iter().enumerate() {
+        connection.execute(
+            "INSERT INTO attachments (notification, position, file_name, content_type, size, content)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                notification,
+                position,
+                attachment.file_name,
+                attachment.mime_type(),
+                attachment.file_content.len(),
+                store_attachments.then_some(attachment.file_content.as_slice()),
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+/// Removes notifications exceeding the retention and attachment contents exceeding the budget
+fn prune(connection: &Connection, config: &Config) -> rusqlite::Result<()> {
+    if let Some(max_age) = config.max_age() {
+        let oldest = SystemTime::now().checked_sub(max_age).unwrap_or(UNIX_EPOCH);
+        connection.execute(
+            "DELETE FROM notifications WHERE finished < ?1",
+            [to_millis(oldest)],
+        )?;
+    }
+    if let Some(max_entries) = config.max_entries {
+        connection.execute(
+            "DELETE FROM notifications WHERE id NOT IN (
+                SELECT id FROM notifications ORDER BY finished DESC, id DESC LIMIT ?1
+            )",
+            [i64::try_from(max_entries).unwrap_or(i64::MAX)],
+        )?;
+    }
+    connection.execute(
+        "UPDATE attachments SET content = NULL WHERE id IN (
+            SELECT id FROM (
+                SELECT id, SUM(length(content)) OVER (ORDER BY id DESC) AS total
+                FROM attachments WHERE content IS NOT NULL
+            ) WHERE total > ?1
+        )",
+        [i64::try_from(config.attachment_budget).unwrap_or(i64::MAX)],
+    )?;
+    Ok(())
+}
+
+fn attachments(
+    connection: &Connection,
+    notification: i64,
+) -> rusqlite::Result<Vec<AttachmentRecord>> {
+    connection
+        .prepare_cached(
+            "SELECT file_name, content_type, size, content IS NOT NULL FROM attachments
+            WHERE notification = ?1 ORDER BY position",
+        )?
+        .query_map([notification], |row| {
+            Ok(AttachmentRecord {
+                file_name: row.get(0)?,
+                content_type: row.get(1)?,
+                size: row.get(2)?,
+                stored: row.get(3)?,
+            })
+        })?
+        .collect()
+}
+
+fn search(connection: &Connection, query: &Query) -> rusqlite::Result<Page> {
+    let mut conditions = Vec::new();
+    let mut values = Vec::new();
+    if let Some(service_id) = &query.service_id {
+        conditions.push("service_id = ?");
+        values.push(Value::Text(service_id.clone()));
+    }
+    if let Some(state) = query.state {
+        match state_name(state) {
+            Some(state) => {
+                conditions.push("state = ?");
+                values.push(Value::Text(state.to_string()));
+            }
+            // Pending notifications are not part of the history
+            None => conditions.push("FALSE"),
+        }
+    }
+    if let Some(since) = query.since {
+        conditions.push("finished >= ?");
+        values.push(Value::Integer(to_millis(since)));
+    }
+    if let Some(until) = query.until {
+        conditions.push("finished < ?");
+        values.push(Value::Integer(to_millis(until)));
+    }
+    if let Some(expression) = query.search.as_deref().and_then(match_expression) {
+        conditions.push("id IN (SELECT rowid FROM search WHERE search MATCH ?)");
+        values.push(Value::Text(expression));
+    }
+    let filter = match conditions.is_empty() {
+        true => String::new(),
+        false => format!("WHERE {}", conditions.join(" AND ")),
+    };
+
+    let total = connection.query_row(
+        &format!("SELECT COUNT(*) FROM notifications {filter}"),
+        params_from_iter(&values),
+        |row| row.get(0),
+    )?;
+    values.push(Value::Integer(query.limit.into()));
+    values.push(Value::Integer(query.offset.into()));
+    let mut statement = connection.prepare(&format!(
+        "SELECT id, uuid, service_id, title, content, receivers, state, attempts, error, created,
+            finished, delivered_by
+        FROM notifications {filter} ORDER BY finished DESC, id DESC LIMIT ? OFFSET ?"
+    ))?;
+    let rows = statement.query_map(params_from_iter(&values), |row| {
+        let receivers: serde_json::Value = row.get(5)?;
+        let state: String = row.get(6)?;
+        Ok((
+            row.get::<_, i64>(0)?,
+            Record {
+                id: row.get(1)?,
+                service_id: row.get(2)?,
+                title: row.get(3)?,
+                content: row.get(4)?,
+                receivers: serde_json::from_value(receivers).unwrap_or_default(),
+                attachments: Vec::new(),
+                state: match state.as_str() {
+                    DELIVERED => DeliveryState::Delivered,
+                    _ => DeliveryState::Failed,
+                },
+                attempts: row.get(7)?,
+                error: row.get(8)?,
+                created: from_millis(row.get(9)?),
+                finished: from_millis(row.get(10)?),
+                delivered_by: row.get(11)?,
+            },
+        ))
+    })?;
+    let mut records = Vec::new();
+    for row in rows {
+        let (notification, mut record) = row?;
+        record.attachments = attachments(connection, notification)?;
+        records.push(record);
+    }
+    Ok(Page { total, records })
+}
+
+impl History {
+    /// Opens or creates the database below `base_dir` and applies the retention
+    pub fn open(config: Config, base_dir: &Path) -> Result<Self, crate::Error> {
+        let connection = Connection::open(base_dir.join(&config.path))?;
+        connection.execute_batch("PRAGMA foreign_keys = ON;")?;
+        connection.execute_batch(SCHEMA)?;
+        prune(&connection, &config)?;
+        Ok(Self {
+            config,
+            connection: Arc::new(Mutex::new(connection)),
+        })
+    }
+
+    /// Runs the database access on the blocking thread pool
+    async fn run<T: Send + 'static>(
+        &self,
+        f: impl FnOnce(&mut Connection, &Config) -> rusqlite::Result<T> + Send + 'static,
+    ) -> Result<T, crate::Error> {
+        let connection = self.connection.clone();
+        let config = self.config.clone();
+        tokio::task::spawn_blocking(move || f(&mut connection.lock().unwrap(), &config))
+            .await
+            .unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic()))
+            .map_err(crate::Error::from)
+    }
+
+    /// Records a delivered or discarded notification
+    pub async fn record(
+        &self,
+        entry: Entry,
+        state: DeliveryState,
+        receivers: Vec<String>,
+    ) -> Result<(), crate::Error> {
+        self.run(move |connection, config| {
+            let transaction = connection.transaction()?;
+            insert(
+                &transaction,
+                &entry,
+                state,
+                receivers,
+                config.store_attachments,
+            )?;
+            prune(&transaction, config)?;
+            transaction.commit()
+        })
+        .await
+    }
+
+    pub async fn search(&self, query: Query) -> Result<Page, crate::Error> {
+        self.run(move |connection, _| search(connection, &query))
+            .await
+    }
+
+    /// Returns the stored content of the attachment at `index` of a notification
+    pub async fn attachment(&self, id: Uuid, index: u32) -> Result<Option<Vec<u8>>, crate::Error> {
+        self.run(move |connection, _| {
+            connection
+                .query_row(
+                    "SELECT attachments.content FROM attachments
+                    JOIN notifications ON notifications.id = attachments.notification
+                    WHERE notifications.uuid = ?1 AND attachments.position = ?2",
+                    params![id, index],
+                    |row| row.get::<_, Option<Vec<u8>>>(0),
+                )
+                .optional()
+                .map(Option::flatten)
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::{Attachment, Metadata};
+
+    fn temp_dir() -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("notis-history-test-{}", Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn config() -> Config {
+        Config {
+            max_age_days: None,
+            max_entries: None,
+            store_attachments: true,
+            ..Config::example()
+        }
+    }
+
+    fn entry(service_id: &str, title: &str, content: &str, finished: SystemTime) -> Entry {
+        let mut entry = Entry::new(
+            service_id.to_string(),
+            title.to_string(),
+            Some(content.to_string()),
+            None,
+            None,
+            vec![Attachment {
+                file_name: "export.csv".to_string(),
+                content_type: "text/csv".parse().unwrap(),
+                file_content: b"id;value\n1;2".to_vec(),
+            }],
+            Metadata::default(),
+        );
+        entry.attempts = 1;
+        entry.updated = finished;
+        entry
+    }
+
+    async fn search(history: &History, query: Query) -> Vec<String> {
+        let query = Query {
+            limit: 100,
+            ..query
+        };
+        history
+            .search(query)
+            .await
+            .unwrap()
+            .records
+            .into_iter()
+            .map(|record| record.title)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn search_and_filter() {
+        let dir = temp_dir();
+        let history = History::open(config(), &dir).unwrap();
+        let now = SystemTime::now();
+        let day = Duration::from_secs(24 * 60 * 60);
+        let entries = [
+            ("smtp", "Pump 3 stopped", "Pressure too low", now - 3 * day),
+            (
+                "smtp",
+                "Export ready",
+                "Export of pump 3 created",
+                now - 2 * day,
+            ),
+            ("log", "Pump 4 stopped", "Pressure too high", now - day),
+        ];
+        for (service_id, title, content, finished) in entries {
+            let state = match service_id {
+                "log" => DeliveryState::Failed,
+                _ => DeliveryState::Delivered,
+            };
+            let receivers = vec!["admin@example.com".to_string()];
+            history
+                .record(
+                    entry(service_id, title, content, finished),
+                    state,
+                    receivers,
+                )
+                .await
+                .unwrap();
+        }
+
+        let all = history
+            .search(Query {
+                limit: 100,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(all.total, 3);
+        let latest = &all.records[0];
+        assert_eq!(latest.title, "Pump 4 stopped");
+        assert_eq!(latest.state, DeliveryState::Failed);
+        assert_eq!(latest.receivers, ["admin@example.com"]);
+        assert_eq!(
+            latest.attachments,
+            [AttachmentRecord {
+                file_name: "export.csv".to_string(),
+                content_type: "text/csv".to_string(),
+                size: 12,
+                stored: true,
+            }]
+        );
+
+        let query = Query {
+            search: Some("pump 3".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            search(&history, query).await,
+            ["Export ready", "Pump 3 stopped"]
+        );
+        let query = Query {
+            search: Some("\"pressure".to_string()),
+            service_id: Some("smtp".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(search(&history, query).await, ["Pump 3 stopped"]);
+        let query = Query {
+            state: Some(DeliveryState::Delivered),
+            since: Some(now - 2 * day),
+            ..Default::default()
+        };
+        assert_eq!(search(&history, query).await, ["Export ready"]);
+        let query = Query {
+            until: Some(now - 2 * day),
+            ..Default::default()
+        };
+        assert_eq!(search(&history, query).await, ["Pump 3 stopped"]);
+
+        let page = history
+            .search(Query {
+                offset: 1,
+                limit: 1,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(page.total, 3);
+        assert_eq!(page.records[0].title, "Export ready");
+
+        let id = latest.id;
+        assert_eq!(
+            history.attachment(id, 0).await.unwrap().unwrap(),
+            b"id;value\n1;2"
+        );
+        assert!(history.attachment(id, 1).await.unwrap().is_none());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn retention() {
+        let dir = temp_dir();
+        let config = Config {
+            max_age_days: Some(30),
+            max_entries: Some(2),
+            attachment_budget: 20,
+            ..config()
+        };
+        let history = History::open(config, &dir).unwrap();
+        let now = SystemTime::now();
+        let old = now - Duration::from_secs(31 * 24 * 60 * 60);
+        for (title, finished) in [
+            ("Old", old),
+            ("First", now),
+            ("Second", now),
+            ("Third", now),
+        ] {
+            let entry = entry("smtp", title, "", finished);
+            history
+                .record(entry, DeliveryState::Delivered, Vec::new())
+                .await
+                .unwrap();
+        }
+
+        let page = history
+            .search(Query {
+                limit: 100,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let titles: Vec<_> = page.records.iter().map(|record| &record.title).collect();
+        assert_eq!(titles, ["Third", "Second"]);
+        // Only the content of the most recent attachment fits into the budget
+        assert!(page.records[0].attachments[0].stored);
+        assert!(!page.records[1].attachments[0].stored);
+        assert!(
+            search(
+                &history,
+                Query {
+                    search: Some("first".to_string()),
+                    ..Default::default()
+                }
+            )
+            .await
+            .is_empty()
+        );
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
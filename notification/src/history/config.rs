@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Duration;
+
+const DEFAULT_PATH: &str = "history.sqlite";
+const DEFAULT_ATTACHMENT_BUDGET: u64 = 100 * 1024 * 1024;
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+fn default_path() -> PathBuf {
+    PathBuf::from(DEFAULT_PATH)
+}
+
+fn default_attachment_budget() -> u64 {
+    DEFAULT_ATTACHMENT_BUDGET
+}
+
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
+pub struct Config {
+    /// Database file of the history, relative paths are resolved against the directory of the config file
+    #[serde(default = "default_path")]
+    pub path: PathBuf,
+    /// Notifications which finished more than this number of days ago are removed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_age_days: Option<u64>,
+    /// Only this number of the most recent notifications is kept
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_entries: Option<u64>,
+    /// Whether the content of attachments is stored, otherwise only their name, type and size
+    #[serde(default)]
+    pub store_attachments: bool,
+    /// Total size of stored attachment contents in bytes, the contents of the oldest attachments are
+    /// removed first
+    #[serde(default = "default_attachment_budget")]
+    pub attachment_budget: u64,
+}
+
+impl Config {
+    pub fn example() -> Self {
+        Self {
+            path: default_path(),
+            max_age_days: Some(365),
+            max_entries: Some(100_000),
+            store_attachments: false,
+            attachment_budget: DEFAULT_ATTACHMENT_BUDGET,
+        }
+    }
+
+    pub fn max_age(&self) -> Option<Duration> {
+        self.max_age_days
+            .map(|days| Duration::from_secs(days.saturating_mul(SECONDS_PER_DAY)))
+    }
+}
@@ -1,13 +1,112 @@
 use thiserror::Error;
 
 pub mod config;
+pub mod history;
+pub mod queue;
+pub mod routing;
 pub mod server;
 pub mod services;
+pub mod smtp_ingress;
+pub mod templates;
 pub mod tracing;
 #[derive(Debug, Error)]
 pub enum Error {
+    #[error(transparent)]
+    IO(#[from] std::io::Error),
     #[error(transparent)]
     Serde(#[from] serde_json::Error),
+    #[error("Unknown notification service {0}")]
+    UnknownService(String),
+    #[error("The failover chain failed: {}", failover_errors(.0))]
+    Failover(Vec<(String, Error)>),
+    #[error(transparent)]
+    History(#[from] rusqlite::Error),
+    #[error(transparent)]
+    Routing(#[from] routing::Error),
+    #[error(transparent)]
+    Template(#[from] templates::Error),
     #[error(transparent)]
     Smtp(#[from] services::smtp::Error),
+    #[error(transparent)]
+    Zulip(#[from] services::zulip::Error),
+    #[error(transparent)]
+    Snmp(#[from] services::snmp::Error),
+    #[error(transparent)]
+    OpcUa(#[from] services::opcua::Error),
+    #[error(transparent)]
+    Modbus(#[from] services::modbus::Error),
+}
+
+fn failover_errors(errors: &[(String, Error)]) -> String {
+    errors
+        .iter()
+        .map(|(service_id, error)| format!("{service_id}: {error}"))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+impl Error {
+    /// Errors which will not resolve by retrying the delivery
+    pub fn is_permanent(&self) -> bool {
+        match self {
+            Self::Serde(_) | Self::UnknownService(_) | Self::Routing(_) | Self::Template(_) => true,
+            Self::Failover(errors) => match errors.last() {
+                // The chain stopped at a service which delivered the notification to some receivers
+                Some((service_id, error)) if error.partially_delivered_by(service_id).is_some() => {
+                    error.is_permanent()
+                }
+                _ => errors.iter().all(|(_, error)| error.is_permanent()),
+            },
+            Self::Smtp(error) => error.is_permanent(),
+            Self::Zulip(error) => error.is_permanent(),
+            Self::Snmp(error) => error.is_permanent(),
+            Self::OpcUa(error) => error.is_permanent(),
+            Self::Modbus(error) => error.is_permanent(),
+            Self::IO(_) | Self::History(_) => false,
+        }
+    }
+
+    /// Receivers which the server refused while others may have received the notification
+    pub fn rejected_receivers(&self) -> &[services::RejectedReceiver] {
+        match self {
+            Self::Smtp(error) => error.rejected_receivers(),
+            Self::Failover(errors) => errors
+                .last()
+                .map_or(&[], |(_, error)| error.rejected_receivers()),
+            _ => &[],
+        }
+    }
+
+    /// Service which delivered the notification to some of its receivers despite the error, the
+    /// notification being sent to `service_id`
+    pub fn partially_delivered_by<'a>(&'a self, service_id: &'a str) -> Option<&'a str> {
+        match self {
+            Self::Smtp(services::smtp::Error::PartiallyRejected(_))
+            | Self::Snmp(services::snmp::Error::PartiallyFailed(_)) => Some(service_id),
+            Self::Failover(errors) => errors
+                .last()
+                .and_then(|(service_id, error)| error.partially_delivered_by(service_id)),
+            _ => None,
+        }
+    }
+
+    /// Options for retrying the delivery to the receivers which were rejected transiently only,
+    /// the options of a failover chain are keyed by the id of the service they belong to
+    pub fn retry_options(&self, options: Option<&serde_json::Value>) -> Option<serde_json::Value> {
+        match self {
+            Self::Smtp(error) => error.retry_options(options),
+            Self::Snmp(error) => error.retry_options(options),
+            Self::Failover(errors) => {
+                let options: serde_json::Map<_, _> = errors
+                    .iter()
+                    .filter_map(|(service_id, error)| {
+                        let options = options.and_then(|options| options.get(service_id));
+                        Some((service_id.clone(), error.retry_options(options)?))
+                    })
+                    .collect();
+                (!options.is_empty()).then_some(options.into())
+            }
+            _ => None,
+        }
+    }
 }
@@ -0,0 +1,986 @@
+//! Durable queue of notifications which are delivered by workers, retrying failed deliveries with
+//! exponential backoff
+
+mod config;
+
+use crate::services::{Attachment, Metadata, ReceiverReport, RejectedReceiver};
+pub use config::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Notify;
+use tracing::{Instrument, error, info, info_span, warn};
+use uuid::Uuid;
+
+const FAILED_DIR: &str = "failed";
+const STATUSES_DIR: &str = "statuses";
+const EXTENSION: &str = "json";
+/// Number of delivered or failed notifications whose status is kept
+const MAX_FINISHED: usize = 10_000;
+
+/// Notification waiting for its delivery
+#[derive(Clone, Deserialize, Serialize)]
+pub struct Entry {
+    pub id: Uuid,
+    pub service_id: String,
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// Html variant of the content, e.g. rendered from a template
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<Attachment>,
+    #[serde(default, skip_serializing_if = "Metadata::is_empty")]
+    pub metadata: Metadata,
+    pub attempts: u32,
+    pub next_attempt: SystemTime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+    /// Receivers the server refused, those refused transiently are retried
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rejected_receivers: Vec<RejectedReceiver>,
+    #[serde(default = "SystemTime::now")]
+    pub created: SystemTime,
+    #[serde(default = "SystemTime::now")]
+    pub updated: SystemTime,
+    /// Service which delivered the notification, differs from `service_id` for failover chains
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delivered_by: Option<String>,
+}
+
+impl Entry {
+    pub fn new(
+        service_id: String,
+        title: String,
+        content: Option<String>,
+        html: Option<String>,
+        options: Option<serde_json::Value>,
+        attachments: Vec<Attachment>,
+        metadata: Metadata,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            service_id,
+            title,
+            content,
+            html,
+            options,
+            attachments,
+            metadata,
+            attempts: 0,
+            next_attempt: SystemTime::now(),
+            last_error: None,
+            rejected_receivers: Vec::new(),
+            created: SystemTime::now(),
+            updated: SystemTime::now(),
+            delivered_by: None,
+        }
+    }
+
+    /// Keeps the receivers refused permanently by earlier attempts and adds those of the last one
+    fn reject(&mut self, rejected: &[RejectedReceiver]) {
+        self.rejected_receivers
+            .retain(|receiver| !receiver.is_transient());
+        self.rejected_receivers.extend_from_slice(rejected);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum DeliveryState {
+    Queued,
+    Sending,
+    Retrying,
+    Delivered,
+    Failed,
+}
+
+/// Delivery status of a notification which is queued or was recently delivered or discarded
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Status {
+    pub id: Uuid,
+    pub service_id: String,
+    pub state: DeliveryState,
+    pub attempts: u32,
+    pub created: SystemTime,
+    pub updated: SystemTime,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_attempt: Option<SystemTime>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rejected_receivers: Vec<RejectedReceiver>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delivered_by: Option<String>,
+    /// Latest reports of the receivers' mail servers after the notification was sent
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub receiver_reports: Vec<ReceiverReport>,
+}
+
+impl Status {
+    fn new(entry: &Entry, state: DeliveryState) -> Self {
+        let pending = matches!(state, DeliveryState::Queued | DeliveryState::Retrying);
+        Self {
+            id: entry.id,
+            service_id: entry.service_id.clone(),
+            state,
+            attempts: entry.attempts,
+            created: entry.created,
+            updated: entry.updated,
+            next_attempt: pending.then_some(entry.next_attempt),
+            last_error: entry.last_error.clone(),
+            rejected_receivers: entry.rejected_receivers.clone(),
+            delivered_by: entry.delivered_by.clone(),
+            receiver_reports: Vec::new(),
+        }
+    }
+}
+
+/// Criteria of [Queue::statuses], unset criteria match every notification
+#[derive(Default)]
+pub struct Filter {
+    pub service_id: Option<String>,
+    pub state: Option<DeliveryState>,
+    /// Matches notifications created at or after this time
+    pub since: Option<SystemTime>,
+}
+
+impl Filter {
+    fn matches(&self, status: &Status) -> bool {
+        self.service_id
+            .as_ref()
+            .is_none_or(|service_id| *service_id == status.service_id)
+            && self.state.is_none_or(|state| state == status.state)
+            && self.since.is_none_or(|since| status.created >= since)
+    }
+}
+
+/// Target of the queued notifications
+pub trait Deliverer: Send + Sync + 'static {
+    /// Returns the id of the service which delivered the notification
+    fn deliver(&self, entry: &Entry) -> impl Future<Output = Result<String, crate::Error>> + Send;
+
+    /// Called once the entry was delivered or discarded
+    fn finished(&self, _entry: &Entry, _state: DeliveryState) -> impl Future<Output = ()> + Send {
+        async {}
+    }
+}
+
+#[derive(Default)]
+struct State {
+    entries: HashMap<Uuid, Entry>,
+    claimed: HashSet<Uuid>,
+    /// Statuses of delivered and discarded notifications, oldest first, each is also stored in the
+    /// statuses subdirectory
+    finished: VecDeque<Status>,
+    /// Ids of the notifications in the failed subdirectory, oldest first
+    failed: VecDeque<Uuid>,
+}
+
+impl State {
+    fn status(&self, entry: &Entry) -> Status {
+        let state = if self.claimed.contains(&entry.id) {
+            DeliveryState::Sending
+        } else if entry.attempts == 0 {
+            DeliveryState::Queued
+        } else {
+            DeliveryState::Retrying
+        };
+        Status::new(entry, state)
+    }
+
+    /// Adds the status of a finished notification, returns the id of the status which was dropped
+    /// to make room for it
+    fn finish(&mut self, status: Status) -> Option<Uuid> {
+        let dropped = (self.finished.len() >= MAX_FINISHED)
+            .then(|| self.finished.pop_front())
+            .flatten();
+        self.finished.push_back(status);
+        dropped.map(|status| status.id)
+    }
+}
+
+pub struct Queue {
+    config: Config,
+    dir: PathBuf,
+    state: Mutex<State>,
+    notify: Notify,
+}
+
+impl Queue {
+    /// Opens the queue directory below `base_dir` and loads the notifications queued before
+    pub fn open(config: Config, base_dir: &Path) -> std::io::Result<Self> {
+        let dir = base_dir.join(&config.path);
+        std::fs::create_dir_all(dir.join(FAILED_DIR))?;
+        std::fs::create_dir_all(dir.join(STATUSES_DIR))?;
+        let entries: HashMap<_, _> = load::<Entry>(&dir)?
+            .into_iter()
+            .map(|entry| (entry.id, entry))
+            .collect();
+        if !entries.is_empty() {
+            info!("Loaded {} queued notifications from {dir:?}", entries.len());
+        }
+        let mut failed = load::<Entry>(&dir.join(FAILED_DIR))?;
+        failed.sort_by_key(|entry| entry.updated);
+        let mut finished = load::<Status>(&dir.join(STATUSES_DIR))?;
+        let stored: HashSet<_> = finished.iter().map(|status| status.id).collect();
+        // Notifications discarded before their statuses were stored
+        finished.extend(
+            failed
+                .iter()
+                .filter(|entry| !stored.contains(&entry.id))
+                .map(|entry| Status::new(entry, DeliveryState::Failed)),
+        );
+        finished.sort_by_key(|status| status.updated);
+        let excess = finished.len().saturating_sub(MAX_FINISHED);
+        for status in finished.drain(..excess) {
+            remove_status(&dir, &status.id);
+        }
+        let queue = Self {
+            config,
+            dir,
+            state: Mutex::new(State {
+                entries,
+                claimed: HashSet::new(),
+                finished: finished.into(),
+                failed: failed.iter().map(|entry| entry.id).collect(),
+            }),
+            notify: Notify::new(),
+        };
+        queue.prune_failed();
+        Ok(queue)
+    }
+
+    fn path(&self, id: &Uuid) -> PathBuf {
+        self.dir.join(id.to_string()).with_extension(EXTENSION)
+    }
+
+    fn failed_path(&self, id: &Uuid) -> PathBuf {
+        self.dir
+            .join(FAILED_DIR)
+            .join(id.to_string())
+            .with_extension(EXTENSION)
+    }
+
+    /// Deletes the oldest discarded notifications exceeding `max_failed`
+    fn prune_failed(&self) {
+        let pruned: Vec<_> = {
+            let mut state = self.state.lock().unwrap();
+            let excess = state.failed.len().saturating_sub(self.config.max_failed);
+            state.failed.drain(..excess).collect()
+        };
+        for id in pruned {
+            if let Err(e) = std::fs::remove_file(self.failed_path(&id)) {
+                error!(%id, "Could not delete discarded notification: {e}");
+            }
+        }
+    }
+
+    fn status_path(&self, id: &Uuid) -> PathBuf {
+        status_path(&self.dir, id)
+    }
+
+    async fn persist(&self, entry: &Entry) -> Result<(), crate::Error> {
+        write(&self.path(&entry.id), entry).await
+    }
+
+    /// Stores the entry on disk before it is scheduled for delivery
+    pub async fn enqueue(&self, entry: Entry) -> Result<Uuid, crate::Error> {
+        self.persist(&entry).await?;
+        let id = entry.id;
+        info!(%id, service_id = entry.service_id, "Queued notification");
+        self.state.lock().unwrap().entries.insert(id, entry);
+        self.notify.notify_waiters();
+        Ok(id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the status of a queued or recently finished notification
+    pub fn status(&self, id: &Uuid) -> Option<Status> {
+        let state = self.state.lock().unwrap();
+        match state.entries.get(id) {
+            Some(entry) => Some(state.status(entry)),
+            None => state
+                .finished
+                .iter()
+                .rev()
+                .find(|status| status.id == *id)
+                .cloned(),
+        }
+    }
+
+    /// Adds the report of a receiver to the stored status of a recently finished notification,
+    /// replacing the previous report of the receiver. Returns false if the notification is unknown.
+    pub fn report(&self, id: &Uuid, report: ReceiverReport) -> bool {
+        let status = {
+            let mut state = self.state.lock().unwrap();
+            let Some(status) = state
+                .finished
+                .iter_mut()
+                .rev()
+                .find(|status| status.id == *id)
+            else {
+                return false;
+            };
+            status
+                .receiver_reports
+                .retain(|previous| !previous.address.eq_ignore_ascii_case(&report.address));
+            status.receiver_reports.push(report);
+            status.clone()
+        };
+        let path = self.status_path(id);
+        let temporary = path.with_extension("tmp");
+        let result = serde_json::to_vec(&status)
+            .map_err(crate::Error::from)
+            .and_then(|data| {
+                std::fs::write(&temporary, data)?;
+                Ok(std::fs::rename(&temporary, &path)?)
+            });
+        if let Err(e) = result {
+            error!(%id, "Could not store the status of the notification: {e}");
+        }
+        true
+    }
+
+    /// Returns the statuses of the queued and recently finished notifications matching the filter,
+    /// ordered by their creation
+    pub fn statuses(&self, filter: &Filter) -> Vec<Status> {
+        let state = self.state.lock().unwrap();
+        let mut statuses: Vec<_> = state
+            .finished
+            .iter()
+            .cloned()
+            .chain(state.entries.values().map(|entry| state.status(entry)))
+            .filter(|status| filter.matches(status))
+            .collect();
+        statuses.sort_by_key(|status| status.created);
+        statuses
+    }
+
+    /// Waits until an entry is due and claims it for the calling worker
+    async fn claim(&self) -> Entry {
+        loop {
+            let notified = self.notify.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+            let next_attempt = {
+                let mut state = self.state.lock().unwrap();
+                let next = state
+                    .entries
+                    .values()
+                    .filter(|entry| !state.claimed.contains(&entry.id))
+                    .min_by_key(|entry| entry.next_attempt)
+                    .map(|entry| (entry.id, entry.next_attempt));
+                match next {
+                    Some((id, next_attempt)) if next_attempt <= SystemTime::now() => {
+                        state.claimed.insert(id);
+                        return state.entries[&id].clone();
+                    }
+                    next => next.map(|(_, next_attempt)| next_attempt),
+                }
+            };
+            match next_attempt {
+                Some(next_attempt) => {
+                    let delay = next_attempt
+                        .duration_since(SystemTime::now())
+                        .unwrap_or_default();
+                    tokio::select! {
+                        _ = tokio::time::sleep(delay) => {}
+                        _ = notified => {}
+                    }
+                }
+                None => notified.await,
+            }
+        }
+    }
+
+    fn release(&self, entry: Entry) {
+        let mut state = self.state.lock().unwrap();
+        state.claimed.remove(&entry.id);
+        state.entries.insert(entry.id, entry);
+        drop(state);
+        self.notify.notify_waiters();
+    }
+
+    /// Removes the entry from the queue and stores its final status
+    async fn remove(&self, entry: &Entry, state: DeliveryState) {
+        let status = Status::new(entry, state);
+        if let Err(e) = write(&self.status_path(&entry.id), &status).await {
+            error!("Could not store the status of the notification: {e}");
+        }
+        let dropped = {
+            let mut queue_state = self.state.lock().unwrap();
+            queue_state.claimed.remove(&entry.id);
+            queue_state.entries.remove(&entry.id);
+            queue_state.finish(status)
+        };
+        if let Some(id) = dropped {
+            remove_status(&self.dir, &id);
+        }
+    }
+
+    /// Removes the delivered entry from the queue
+    async fn delivered(&self, entry: Entry, deliverer: &impl Deliverer) {
+        if let Err(e) = tokio::fs::remove_file(self.path(&entry.id)).await {
+            error!("Could not remove delivered notification from the queue: {e}");
+        }
+        self.remove(&entry, DeliveryState::Delivered).await;
+        deliverer.finished(&entry, DeliveryState::Delivered).await;
+    }
+
+    async fn attempt(&self, mut entry: Entry, deliverer: &impl Deliverer) {
+        let result = deliverer.deliver(&entry).await;
+        entry.attempts += 1;
+        entry.updated = SystemTime::now();
+        let policy = self.config.retry_policy(&entry.service_id);
+        if let Err(e) = &result
+            && let Some(service_id) = e.partially_delivered_by(&entry.service_id)
+        {
+            entry.delivered_by = Some(service_id.to_string());
+        }
+        match result {
+            Ok(service_id) => {
+                info!(delivered_by = service_id, "Delivered notification");
+                entry.delivered_by = Some(service_id);
+                entry.reject(&[]);
+                self.delivered(entry, deliverer).await;
+            }
+            // Receivers accepted the notification in this or an earlier attempt
+            Err(e)
+                if entry.delivered_by.is_some()
+                    && (e.is_permanent() || entry.attempts >= policy.max_attempts.max(1)) =>
+            {
+                warn!(
+                    "Delivered notification after {} attempts, except to the rejected receivers: {e}",
+                    entry.attempts
+                );
+                entry.last_error = Some(e.to_string());
+                entry.reject(e.rejected_receivers());
+                self.delivered(entry, deliverer).await;
+            }
+            Err(e) if e.is_permanent() || entry.attempts >= policy.max_attempts.max(1) => {
+                error!(
+                    "Discarding notification after {} attempts: {e}",
+                    entry.attempts
+                );
+                entry.last_error = Some(e.to_string());
+                entry.reject(e.rejected_receivers());
+                let result = match self.persist(&entry).await {
+                    Ok(()) => tokio::fs::rename(self.path(&entry.id), self.failed_path(&entry.id))
+                        .await
+                        .map_err(crate::Error::from),
+                    Err(e) => Err(e),
+                };
+                match result {
+                    Ok(()) => self.state.lock().unwrap().failed.push_back(entry.id),
+                    Err(e) => error!("Could not move discarded notification: {e}"),
+                }
+                self.prune_failed();
+                self.remove(&entry, DeliveryState::Failed).await;
+                deliverer.finished(&entry, DeliveryState::Failed).await;
+            }
+            Err(e) => {
+                let backoff = policy.backoff(entry.attempts);
+                warn!(
+                    "Attempt {} failed, retrying in {}s: {e}",
+                    entry.attempts,
+                    backoff.as_secs()
+                );
+                entry.last_error = Some(e.to_string());
+                entry.reject(e.rejected_receivers());
+                if let Some(options) = e.retry_options(entry.options.as_ref()) {
+                    entry.options = Some(options);
+                }
+                entry.next_attempt = SystemTime::now() + backoff;
+                if let Err(e) = self.persist(&entry).await {
+                    error!("Could not update queued notification: {e}");
+                }
+                self.release(entry);
+            }
+        }
+    }
+}
+
+/// Writes the value to a temporary file which replaces the previous file once it is synced
+async fn write(path: &Path, value: &impl Serialize) -> Result<(), crate::Error> {
+    let temporary = path.with_extension("tmp");
+    let data = serde_json::to_vec(value)?;
+    let mut file = tokio::fs::File::create(&temporary).await?;
+    file.write_all(&data).await?;
+    file.sync_all().await?;
+    tokio::fs::rename(&temporary, path).await?;
+    Ok(())
+}
+
+fn status_path(dir: &Path, id: &Uuid) -> PathBuf {
+    dir.join(STATUSES_DIR)
+        .join(id.to_string())
+        .with_extension(EXTENSION)
+}
+
+/// Deletes the stored status, statuses of notifications discarded before they were stored have none
+fn remove_status(dir: &Path, id: &Uuid) {
+    match std::fs::remove_file(status_path(dir, id)) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+            error!(%id, "Could not delete the status of the notification: {e}")
+        }
+        _ => {}
+    }
+}
+
+/// Reads the entries or statuses stored in `dir`, skipping files which cannot be parsed
+fn load<T: DeserializeOwned>(dir: &Path) -> std::io::Result<Vec<T>> {
+    let mut entries = Vec::new();
+    for file in std::fs::read_dir(dir)? {
+        let path = file?.path();
+        if path.extension().and_then(|extension| extension.to_str()) != Some(EXTENSION) {
+            continue;
+        }
+        match std::fs::read(&path)
+            .map_err(crate::Error::from)
+            .and_then(|data| Ok(serde_json::from_slice::<T>(&data)?))
+        {
+            Ok(entry) => entries.push(entry),
+            Err(e) => error!("Could not load notification {path:?}: {e}"),
+        }
+    }
+    Ok(entries)
+}
+
+/// Delivers the queued notifications with the configured number of workers
+pub async fn run(queue: Arc<Queue>, deliverer: Arc<impl Deliverer>) {
+    let workers: Vec<_> = (0..queue.config.workers.max(1))
+        .map(|_| {
+            let queue = queue.clone();
+            let deliverer = deliverer.clone();
+            tokio::spawn(async move {
+                loop {
+                    let entry = queue.claim().await;
+                    let span = info_span!(
+                        "deliver_notification",
+                        id = %entry.id,
+                        service_id = entry.service_id,
+                    );
+                    queue.attempt(entry, &*deliverer).instrument(span).await;
+                }
+            })
+        })
+        .collect();
+    for worker in workers {
+        // Workers only stop if they panic
+        let Err(e) = worker.await;
+        error!("Delivery worker stopped: {e}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::{ReceiverState, Severity};
+    use std::time::Duration;
+
+    /// Fails the first `failures` attempts of each notification
+    struct Flaky {
+        failures: u32,
+        attempts: Mutex<HashMap<Uuid, u32>>,
+        delivered: Mutex<Vec<Entry>>,
+    }
+
+    impl Flaky {
+        fn new(failures: u32) -> Arc<Self> {
+            Arc::new(Self {
+                failures,
+                attempts: Default::default(),
+                delivered: Default::default(),
+            })
+        }
+    }
+
+    impl Deliverer for Flaky {
+        async fn deliver(&self, entry: &Entry) -> Result<String, crate::Error> {
+            let attempts = {
+                let mut attempts = self.attempts.lock().unwrap();
+                let attempts = attempts.entry(entry.id).or_default();
+                *attempts += 1;
+                *attempts
+            };
+            if attempts <= self.failures {
+                return Err(std::io::Error::from(std::io::ErrorKind::ConnectionRefused).into());
+            }
+            self.delivered.lock().unwrap().push(entry.clone());
+            Ok(entry.service_id.clone())
+        }
+    }
+
+    /// Rejects a receiver transiently and another one permanently in the first attempt
+    #[derive(Default)]
+    struct Rejecting {
+        options: Mutex<Vec<Option<serde_json::Value>>>,
+    }
+
+    fn rejected(address: &str, code: u16) -> RejectedReceiver {
+        RejectedReceiver {
+            address: address.to_string(),
+            code,
+            message: "rejected".to_string(),
+        }
+    }
+
+    impl Deliverer for Rejecting {
+        async fn deliver(&self, entry: &Entry) -> Result<String, crate::Error> {
+            let mut options = self.options.lock().unwrap();
+            options.push(entry.options.clone());
+            if options.len() > 1 {
+                return Ok(entry.service_id.clone());
+            }
+            Err(crate::services::smtp::Error::PartiallyRejected(vec![
+                rejected("full@mail.com", 452),
+                rejected("unknown@mail.com", 550),
+            ])
+            .into())
+        }
+    }
+
+    /// Fails every attempt with the error
+    struct Failing(fn() -> crate::Error);
+
+    impl Deliverer for Failing {
+        async fn deliver(&self, _entry: &Entry) -> Result<String, crate::Error> {
+            Err(self.0())
+        }
+    }
+
+    fn temp_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("notis-queue-test-{}", Uuid::new_v4()))
+    }
+
+    fn config(max_attempts: u32) -> Config {
+        Config {
+            retry: RetryPolicy {
+                max_attempts,
+                initial_backoff_secs: 0,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    fn entry() -> Entry {
+        Entry::new(
+            "smtp".to_string(),
+            "Export ready".to_string(),
+            Some("Export #1234 was created".to_string()),
+            Some("Export <b>#1234</b> was created".to_string()),
+            Some(serde_json::json!({"receiver_groups": ["admins"]})),
+            vec![Attachment {
+                file_name: "export.csv".to_string(),
+                content_type: "text/csv".parse().unwrap(),
+                file_content: b"id;value\n1;2".to_vec(),
+            }],
+            Metadata {
+                severity: Some(Severity::Warning),
+                source: Some("exporter".to_string()),
+                ..Default::default()
+            },
+        )
+    }
+
+    async fn wait_until_empty(queue: &Queue) {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !queue.is_empty() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn survives_restart() {
+        let dir = temp_dir();
+        let id = Queue::open(config(3), &dir)
+            .unwrap()
+            .enqueue(entry())
+            .await
+            .unwrap();
+
+        let queue = Arc::new(Queue::open(config(3), &dir).unwrap());
+        assert_eq!(queue.len(), 1);
+        let flaky = Flaky::new(0);
+        tokio::spawn(run(queue.clone(), flaky.clone()));
+        wait_until_empty(&queue).await;
+
+        let delivered = flaky.delivered.lock().unwrap();
+        assert_eq!(delivered.len(), 1);
+        assert_eq!(delivered[0].id, id);
+        assert_eq!(delivered[0].title, "Export ready");
+        assert_eq!(delivered[0].options, entry().options);
+        assert_eq!(delivered[0].attachments[0].file_name, "export.csv");
+        assert_eq!(delivered[0].attachments[0].mime_type(), "text/csv");
+        assert_eq!(delivered[0].attachments[0].file_content, b"id;value\n1;2");
+        assert_eq!(delivered[0].html, entry().html);
+        assert_eq!(delivered[0].metadata, entry().metadata);
+        assert!(!queue.path(&id).exists());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn retries() {
+        let dir = temp_dir();
+        let queue = Arc::new(Queue::open(config(3), &dir).unwrap());
+        let flaky = Flaky::new(2);
+        tokio::spawn(run(queue.clone(), flaky.clone()));
+        let id = queue.enqueue(entry()).await.unwrap();
+        wait_until_empty(&queue).await;
+
+        assert_eq!(flaky.attempts.lock().unwrap()[&id], 3);
+        let delivered = flaky.delivered.lock().unwrap();
+        assert_eq!(delivered[0].attempts, 2);
+        assert!(delivered[0].last_error.is_some());
+        let status = queue.status(&id).unwrap();
+        assert_eq!(status.state, DeliveryState::Delivered);
+        assert_eq!(status.attempts, 3);
+        assert_eq!(status.delivered_by.as_deref(), Some("smtp"));
+        assert_eq!(status.next_attempt, None);
+
+        // The status of the delivered notification is kept after a restart
+        let queue = Queue::open(config(3), &dir).unwrap();
+        let status = queue.status(&id).unwrap();
+        assert_eq!(status.state, DeliveryState::Delivered);
+        assert_eq!(status.attempts, 3);
+        assert_eq!(status.delivered_by.as_deref(), Some("smtp"));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn retries_transiently_rejected_receivers() {
+        let dir = temp_dir();
+        let queue = Arc::new(Queue::open(config(3), &dir).unwrap());
+        let rejecting = Arc::new(Rejecting::default());
+        tokio::spawn(run(queue.clone(), rejecting.clone()));
+        let id = queue.enqueue(entry()).await.unwrap();
+        wait_until_empty(&queue).await;
+
+        assert_eq!(
+            rejecting.options.lock().unwrap()[1],
+            Some(serde_json::json!({
+                "receiver_groups": ["admins"],
+                "retry_receivers": ["full@mail.com"],
+            }))
+        );
+        let status = queue.status(&id).unwrap();
+        assert_eq!(status.state, DeliveryState::Delivered);
+        assert_eq!(
+            status.rejected_receivers,
+            [rejected("unknown@mail.com", 550)]
+        );
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn partial_delivery() {
+        let dir = temp_dir();
+        let queue = Arc::new(Queue::open(config(3), &dir).unwrap());
+        tokio::spawn(run(
+            queue.clone(),
+            Arc::new(Failing(|| {
+                crate::services::smtp::Error::PartiallyRejected(vec![rejected(
+                    "unknown@mail.com",
+                    550,
+                )])
+                .into()
+            })),
+        ));
+        let id = queue.enqueue(entry()).await.unwrap();
+        wait_until_empty(&queue).await;
+
+        let status = queue.status(&id).unwrap();
+        assert_eq!(status.state, DeliveryState::Delivered);
+        assert_eq!(status.attempts, 1);
+        assert_eq!(status.delivered_by.as_deref(), Some("smtp"));
+        assert_eq!(
+            status.rejected_receivers,
+            [rejected("unknown@mail.com", 550)]
+        );
+        assert!(status.last_error.is_some());
+        assert!(!queue.failed_path(&id).exists());
+        std::fs::remove_dir_all(dir).unwrap();
+
+        // The notification fails if every receiver was rejected
+        let dir = temp_dir();
+        let queue = Arc::new(Queue::open(config(3), &dir).unwrap());
+        tokio::spawn(run(
+            queue.clone(),
+            Arc::new(Failing(|| {
+                crate::services::smtp::Error::Rejected(vec![rejected("unknown@mail.com", 550)])
+                    .into()
+            })),
+        ));
+        let id = queue.enqueue(entry()).await.unwrap();
+        wait_until_empty(&queue).await;
+        let status = queue.status(&id).unwrap();
+        assert_eq!(status.state, DeliveryState::Failed);
+        assert_eq!(status.delivered_by, None);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn receiver_reports() {
+        let dir = temp_dir();
+        let queue = Arc::new(Queue::open(config(3), &dir).unwrap());
+        tokio::spawn(run(queue.clone(), Flaky::new(0)));
+        let id = queue.enqueue(entry()).await.unwrap();
+        wait_until_empty(&queue).await;
+
+        let report = |address: &str, state| ReceiverReport {
+            address: address.to_string(),
+            state,
+            status: "4.2.2".to_string(),
+            diagnostic: None,
+            time: SystemTime::now(),
+        };
+        assert!(queue.report(&id, report("bob@mail.com", ReceiverState::Delayed)));
+        assert!(queue.report(&id, report("alice@mail.com", ReceiverState::Delivered)));
+        assert!(queue.report(&id, report("Bob@mail.com", ReceiverState::Failed)));
+        assert!(!queue.report(
+            &Uuid::new_v4(),
+            report("bob@mail.com", ReceiverState::Failed)
+        ));
+        let reports: Vec<_> = queue
+            .status(&id)
+            .unwrap()
+            .receiver_reports
+            .into_iter()
+            .map(|report| (report.address, report.state))
+            .collect();
+        assert_eq!(
+            reports,
+            [
+                ("alice@mail.com".to_string(), ReceiverState::Delivered),
+                ("Bob@mail.com".to_string(), ReceiverState::Failed),
+            ]
+        );
+
+        // Reports are kept after a restart and still match their notification
+        let queue = Queue::open(config(3), &dir).unwrap();
+        assert_eq!(queue.status(&id).unwrap().receiver_reports.len(), 2);
+        assert!(queue.report(&id, report("carol@mail.com", ReceiverState::Delayed)));
+        assert_eq!(queue.status(&id).unwrap().receiver_reports.len(), 3);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn gives_up() {
+        let dir = temp_dir();
+        let queue = Arc::new(Queue::open(config(2), &dir).unwrap());
+        let flaky = Flaky::new(u32::MAX);
+        tokio::spawn(run(queue.clone(), flaky.clone()));
+        let id = queue.enqueue(entry()).await.unwrap();
+        wait_until_empty(&queue).await;
+
+        assert_eq!(flaky.attempts.lock().unwrap()[&id], 2);
+        assert!(flaky.delivered.lock().unwrap().is_empty());
+        assert!(!queue.path(&id).exists());
+        let failed: Entry = serde_json::from_slice(
+            &std::fs::read(
+                dir.join("queue")
+                    .join(FAILED_DIR)
+                    .join(format!("{id}.json")),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(failed.attempts, 2);
+
+        let queue = Queue::open(config(2), &dir).unwrap();
+        let status = queue.status(&id).unwrap();
+        assert_eq!(status.state, DeliveryState::Failed);
+        assert!(status.last_error.is_some());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn prunes_failed() {
+        let dir = temp_dir();
+        let failed_files = |dir: &Path| {
+            std::fs::read_dir(dir.join("queue").join(FAILED_DIR))
+                .unwrap()
+                .count()
+        };
+        let config = Config {
+            max_failed: 2,
+            ..config(1)
+        };
+        let queue = Arc::new(Queue::open(config.clone(), &dir).unwrap());
+        tokio::spawn(run(queue.clone(), Flaky::new(u32::MAX)));
+        let mut ids = Vec::new();
+        for _ in 0..3 {
+            ids.push(queue.enqueue(entry()).await.unwrap());
+            wait_until_empty(&queue).await;
+        }
+        assert_eq!(failed_files(&dir), 2);
+        assert!(!queue.failed_path(&ids[0]).exists());
+        assert!(queue.failed_path(&ids[2]).exists());
+
+        let config = Config {
+            max_failed: 1,
+            ..config
+        };
+        let queue = Queue::open(config, &dir).unwrap();
+        assert_eq!(failed_files(&dir), 1);
+        assert!(queue.failed_path(&ids[2]).exists());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn statuses() {
+        let dir = temp_dir();
+        let queue = Queue::open(config(2), &dir).unwrap();
+        let first = queue.enqueue(entry()).await.unwrap();
+        let mut other = entry();
+        other.service_id = "log".to_string();
+        let second = queue.enqueue(other).await.unwrap();
+        let claimed = queue.claim().await;
+
+        let sending = if claimed.id == first { first } else { second };
+        assert_eq!(
+            queue.status(&sending).unwrap().state,
+            DeliveryState::Sending
+        );
+        let all = queue.statuses(&Filter::default());
+        assert_eq!(
+            all.iter().map(|status| status.id).collect::<Vec<_>>(),
+            [first, second]
+        );
+        let filter = Filter {
+            service_id: Some("log".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(queue.statuses(&filter)[0].id, second);
+        let filter = Filter {
+            state: Some(DeliveryState::Queued),
+            ..Default::default()
+        };
+        assert_eq!(queue.statuses(&filter).len(), 1);
+        let filter = Filter {
+            since: Some(SystemTime::now()),
+            ..Default::default()
+        };
+        assert!(queue.statuses(&filter).is_empty());
+        assert!(queue.status(&Uuid::new_v4()).is_none());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,161 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const DEFAULT_PATH: &str = "queue";
+const DEFAULT_WORKERS: usize = 4;
+const DEFAULT_MAX_FAILED: usize = 1000;
+const DEFAULT_MAX_ATTEMPTS: u32 = 10;
+const DEFAULT_INITIAL_BACKOFF_SECS: u64 = 10;
+const DEFAULT_MAX_BACKOFF_SECS: u64 = 3600;
+const DEFAULT_MULTIPLIER: f64 = 2.0;
+const DEFAULT_JITTER: f64 = 0.1;
+
+fn default_path() -> PathBuf {
+    PathBuf::from(DEFAULT_PATH)
+}
+
+fn default_workers() -> usize {
+    DEFAULT_WORKERS
+}
+
+fn default_max_failed() -> usize {
+    DEFAULT_MAX_FAILED
+}
+
+fn default_max_attempts() -> u32 {
+    DEFAULT_MAX_ATTEMPTS
+}
+
+fn default_initial_backoff_secs() -> u64 {
+    DEFAULT_INITIAL_BACKOFF_SECS
+}
+
+fn default_max_backoff_secs() -> u64 {
+    DEFAULT_MAX_BACKOFF_SECS
+}
+
+fn default_multiplier() -> f64 {
+    DEFAULT_MULTIPLIER
+}
+
+fn default_jitter() -> f64 {
+    DEFAULT_JITTER
+}
+
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
+pub struct RetryPolicy {
+    /// Number of delivery attempts after which a notification is discarded
+    #[serde(default = "default_max_attempts")]
+    pub max_attempts: u32,
+    #[serde(default = "default_initial_backoff_secs")]
+    pub initial_backoff_secs: u64,
+    #[serde(default = "default_max_backoff_secs")]
+    pub max_backoff_secs: u64,
+    /// Factor by which the backoff grows with each failed attempt
+    #[serde(default = "default_multiplier")]
+    pub multiplier: f64,
+    /// Fraction of the backoff which is randomly added or subtracted
+    #[serde(default = "default_jitter")]
+    pub jitter: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            initial_backoff_secs: DEFAULT_INITIAL_BACKOFF_SECS,
+            max_backoff_secs: DEFAULT_MAX_BACKOFF_SECS,
+            multiplier: DEFAULT_MULTIPLIER,
+            jitter: DEFAULT_JITTER,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay before the next attempt after `attempts` failed attempts
+    pub fn backoff(&self, attempts: u32) -> Duration {
+        let exponent = attempts.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let backoff = (self.initial_backoff_secs as f64 * self.multiplier.max(1.0).powi(exponent))
+            .min(self.max_backoff_secs as f64);
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        let factor = 1.0 + rand::random_range(-jitter..=jitter);
+        Duration::from_secs_f64((backoff * factor).max(0.0))
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
+pub struct Config {
+    /// Directory of the queued notifications, relative paths are resolved against the directory of the config file
+    #[serde(default = "default_path")]
+    pub path: PathBuf,
+    /// Number of notifications which are delivered concurrently
+    #[serde(default = "default_workers")]
+    pub workers: usize,
+    #[serde(default)]
+    pub retry: RetryPolicy,
+    /// Retry policies of individual services by their id, overriding `retry`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub service_retry: HashMap<String, RetryPolicy>,
+    /// Number of discarded notifications kept in the failed subdirectory, the oldest are deleted
+    #[serde(default = "default_max_failed")]
+    pub max_failed: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            path: default_path(),
+            workers: DEFAULT_WORKERS,
+            retry: Default::default(),
+            service_retry: Default::default(),
+            max_failed: DEFAULT_MAX_FAILED,
+        }
+    }
+}
+
+impl Config {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn retry_policy(&self, service_id: &str) -> &RetryPolicy {
+        self.service_retry.get(service_id).unwrap_or(&self.retry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exponential_backoff() {
+        let policy = RetryPolicy {
+            initial_backoff_secs: 10,
+            max_backoff_secs: 100,
+            multiplier: 2.0,
+            jitter: 0.0,
+            ..Default::default()
+        };
+        assert_eq!(policy.backoff(1), Duration::from_secs(10));
+        assert_eq!(policy.backoff(2), Duration::from_secs(20));
+        assert_eq!(policy.backoff(4), Duration::from_secs(80));
+        assert_eq!(policy.backoff(5), Duration::from_secs(100));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(100));
+    }
+
+    #[test]
+    fn jitter() {
+        let policy = RetryPolicy {
+            initial_backoff_secs: 100,
+            jitter: 0.5,
+            ..Default::default()
+        };
+        for _ in 0..100 {
+            let backoff = policy.backoff(1);
+            assert!(backoff >= Duration::from_secs(50));
+            assert!(backoff <= Duration::from_secs(150));
+        }
+    }
+}
@@ -0,0 +1,346 @@
+//! Rules selecting the services of notifications which are sent without a service
+
+use crate::config::Config;
+use crate::services::{Metadata, Severity};
+use chrono::{DateTime, Local, NaiveTime};
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const TIME_FORMAT: &str = "%H:%M";
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("A route needs at least one target")]
+    NoTargets,
+    #[error("Invalid title pattern: {0}")]
+    Title(#[from] regex::Error),
+    #[error("Invalid time '{0}', expected HH:MM")]
+    Time(String),
+    #[error("Unknown notification service {0}")]
+    UnknownService(String),
+    #[error("Invalid options for {service}: {error}")]
+    Options {
+        service: String,
+        error: serde_json::Error,
+    },
+}
+
+/// Sends notifications matching all conditions to each target
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Route {
+    /// Conditions a notification has to fulfill, a route without conditions matches every
+    /// notification
+    #[serde(
+        default,
+        rename = "match",
+        skip_serializing_if = "Conditions::is_empty"
+    )]
+    pub conditions: Conditions,
+    pub targets: Vec<Target>,
+}
+
+#[derive(Debug, Default, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Conditions {
+    /// Matches notifications with one of the severities
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub severity: Vec<Severity>,
+    /// Matches notifications which have each of the tags with the same value
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub tags: HashMap<String, String>,
+    /// Matches notifications of one of the sources
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub source: Vec<String>,
+    /// Regular expression matching a part of the title
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Matches notifications sent during the time of the day in the local time zone
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_of_day: Option<TimeOfDay>,
+}
+
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TimeOfDay {
+    /// Start of the period as HH:MM
+    pub from: String,
+    /// End of the period as HH:MM (exclusive), a period ending before its start spans midnight
+    pub until: String,
+}
+
+/// Service or failover chain a routed notification is sent to
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Target {
+    pub service: String,
+    /// Options of the notification for the service, e.g. the `receiver_groups` of a smtp service
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options: Option<serde_json::Value>,
+}
+
+/// Fields of a notification routes match on
+pub struct Notification<'a> {
+    pub title: &'a str,
+    pub severity: Option<Severity>,
+    pub tags: &'a HashMap<String, String>,
+    pub source: Option<&'a str>,
+    pub time: DateTime<Local>,
+}
+
+/// Result of [route]
+#[derive(Debug, Default, PartialEq)]
+pub struct Routing<'a> {
+    /// Ids of the matching routes in alphabetical order
+    pub routes: Vec<&'a str>,
+    /// Distinct targets of the matching routes
+    pub targets: Vec<&'a Target>,
+}
+
+impl<'a> Notification<'a> {
+    /// Notification with the metadata, sent now if the metadata has no timestamp
+    pub fn new(title: &'a str, metadata: &'a Metadata) -> Self {
+        Self {
+            title,
+            severity: metadata.severity,
+            tags: &metadata.tags,
+            source: metadata.source.as_deref(),
+            time: metadata
+                .timestamp
+                .map(DateTime::from)
+                .unwrap_or_else(Local::now),
+        }
+    }
+}
+
+fn parse_time(time: &str) -> Result<NaiveTime, Error> {
+    NaiveTime::parse_from_str(time, TIME_FORMAT).map_err(|_| Error::Time(time.to_string()))
+}
+
+impl TimeOfDay {
+    fn contains(&self, time: NaiveTime) -> Result<bool, Error> {
+        let from = parse_time(&self.from)?;
+        let until = parse_time(&self.until)?;
+        Ok(if from <= until {
+            from <= time && time < until
+        } else {
+            from <= time || time < until
+        })
+    }
+}
+
+impl Conditions {
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    fn matches(&self, notification: &Notification) -> Result<bool, Error> {
+        if !self.severity.is_empty()
+            && !notification
+                .severity
+                .is_some_and(|severity| self.severity.contains(&severity))
+        {
+            return Ok(false);
+        }
+        if !self
+            .tags
+            .iter()
+            .all(|(key, value)| notification.tags.get(key) == Some(value))
+        {
+            return Ok(false);
+        }
+        if !self.source.is_empty()
+            && !notification
+                .source
+                .is_some_and(|source| self.source.iter().any(|s| s == source))
+        {
+            return Ok(false);
+        }
+        if let Some(title) = &self.title
+            && !Regex::new(title)?.is_match(notification.title)
+        {
+            return Ok(false);
+        }
+        if let Some(time_of_day) = &self.time_of_day
+            && !time_of_day.contains(notification.time.time())?
+        {
+            return Ok(false);
+        }
+        Ok(true)
+    }
+}
+
+impl Route {
+    /// Checks the conditions and that each target exists and accepts the options
+    pub fn validate(&self, config: &Config) -> Result<(), Error> {
+        if self.targets.is_empty() {
+            return Err(Error::NoTargets);
+        }
+        if let Some(title) = &self.conditions.title {
+            Regex::new(title)?;
+        }
+        if let Some(time_of_day) = &self.conditions.time_of_day {
+            parse_time(&time_of_day.from)?;
+            parse_time(&time_of_day.until)?;
+        }
+        for target in &self.targets {
+            target.validate(config)?;
+        }
+        Ok(())
+    }
+}
+
+impl Target {
+    /// Checks that the service or failover chain exists and accepts the options
+    pub fn validate(&self, config: &Config) -> Result<(), Error> {
+        let Some(service) = config.target(&self.service) else {
+            return Err(Error::UnknownService(self.service.clone()));
+        };
+        if let Some(options) = &self.options {
+            service
+                .validate_options(options)
+                .map_err(|error| Error::Options {
+                    service: self.service.clone(),
+                    error,
+                })?;
+        }
+        Ok(())
+    }
+}
+
+/// Selects the routes matching the notification, routes with invalid conditions never match
+pub fn route<'a>(routes: &'a HashMap<String, Route>, notification: &Notification) -> Routing<'a> {
+    let mut ids: Vec<_> = routes.keys().collect();
+    ids.sort();
+    let mut routing = Routing::default();
+    for id in ids {
+        let route = &routes[id];
+        match route.conditions.matches(notification) {
+            Ok(true) => {
+                routing.routes.push(id);
+                for target in &route.targets {
+                    if !routing.targets.contains(&target) {
+                        routing.targets.push(target);
+                    }
+                }
+            }
+            Ok(false) => {}
+            Err(e) => tracing::warn!("Skipping route {id}: {e}"),
+        }
+    }
+    routing
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn target(service: &str) -> Target {
+        Target {
+            service: service.to_string(),
+            options: None,
+        }
+    }
+
+    #[test]
+    fn matching() {
+        let routes = HashMap::from([
+            (
+                "critical".to_string(),
+                Route {
+                    conditions: Conditions {
+                        severity: vec![Severity::Critical],
+                        ..Default::default()
+                    },
+                    targets: vec![target("smtp"), target("log")],
+                },
+            ),
+            (
+                "night".to_string(),
+                Route {
+                    conditions: Conditions {
+                        title: Some("(?i)pump".to_string()),
+                        time_of_day: Some(TimeOfDay {
+                            from: "22:00".to_string(),
+                            until: "06:00".to_string(),
+                        }),
+                        ..Default::default()
+                    },
+                    targets: vec![target("log")],
+                },
+            ),
+            (
+                "line".to_string(),
+                Route {
+                    conditions: Conditions {
+                        tags: HashMap::from([("line".to_string(), "3".to_string())]),
+                        source: vec!["plc-monitor".to_string()],
+                        ..Default::default()
+                    },
+                    targets: vec![target("zulip")],
+                },
+            ),
+        ]);
+        let tags = HashMap::from([("line".to_string(), "3".to_string())]);
+        let notification = Notification {
+            title: "Pump 3 stopped",
+            severity: Some(Severity::Critical),
+            tags: &tags,
+            source: Some("plc-monitor"),
+            time: Local.with_ymd_and_hms(2025, 1, 1, 23, 30, 0).unwrap(),
+        };
+        let routing = route(&routes, &notification);
+        assert_eq!(routing.routes, ["critical", "line", "night"]);
+        assert_eq!(
+            routing.targets,
+            [&target("smtp"), &target("log"), &target("zulip")]
+        );
+
+        let no_tags = HashMap::new();
+        let notification = Notification {
+            title: "Valve opened",
+            severity: Some(Severity::Info),
+            tags: &no_tags,
+            source: Some("plc-monitor"),
+            time: Local.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap(),
+        };
+        assert_eq!(route(&routes, &notification), Routing::default());
+    }
+
+    #[test]
+    fn validation() {
+        let config = Config::example();
+        let mut route = Route {
+            conditions: Default::default(),
+            targets: vec![target("alarms")],
+        };
+        assert!(route.validate(&config).is_ok());
+        route.conditions.title = Some("(".to_string());
+        assert!(matches!(route.validate(&config), Err(Error::Title(_))));
+        route.conditions.title = None;
+        route.conditions.time_of_day = Some(TimeOfDay {
+            from: "8".to_string(),
+            until: "18:00".to_string(),
+        });
+        assert!(matches!(route.validate(&config), Err(Error::Time(_))));
+        route.conditions.time_of_day = None;
+        route.targets = vec![target("missing")];
+        assert!(matches!(
+            route.validate(&config),
+            Err(Error::UnknownService(_))
+        ));
+        route.targets = vec![Target {
+            service: "alarms".to_string(),
+            options: Some(serde_json::json!({})),
+        }];
+        assert!(matches!(
+            route.validate(&config),
+            Err(Error::Options { .. })
+        ));
+        route.targets.clear();
+        assert!(matches!(route.validate(&config), Err(Error::NoTargets)));
+    }
+}
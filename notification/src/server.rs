@@ -1,41 +1,117 @@
 mod api;
+mod form;
 
+use crate::config::Target;
+use crate::history::History;
+use crate::queue::{DeliveryState, Entry, Queue};
+use crate::services::smtp::bounces::{DeliveryReport, Health, SeenMessages};
+use crate::services::smtp::{BounceMailbox, Grant, OAuth2};
+use crate::services::{
+    Metadata, NotisNotificationService, ReceiverReport, ReceiverState, Severity,
+};
 use axum::async_trait;
 use axum::http::Method;
+use notis_server::apis::history::{HistoryGetResponse, HistoryIdAttachmentsIndexGetResponse};
 use notis_server::apis::notifications::{
+    NotificationsFanoutPostResponse, NotificationsGetResponse, NotificationsIdGetResponse,
     NotificationsPostResponse, ServicesIdNotificationsPostResponse,
 };
+use notis_server::apis::routes::{
+    RoutesDryRunPostResponse, RoutesGetResponse, RoutesIdDeleteResponse, RoutesIdGetResponse,
+    RoutesIdPutResponse, SchemaRouteGetResponse,
+};
 use notis_server::apis::services::{
     DefaultServiceDeleteResponse, DefaultServiceGetResponse, DefaultServicePostResponse,
     SchemaServiceTypesServiceTypeConfigGetResponse, ServicesGetResponse,
     ServicesIdConfigGetResponse, ServicesIdConfigPatchResponse, ServicesIdConfigSchemaGetResponse,
     ServicesIdDeleteResponse, ServicesIdGetResponse, ServicesIdNotificationsSchemaGetResponse,
-    ServicesIdPutResponse,
+    ServicesIdPutResponse, ServicesIdReceiversGetResponse,
+};
+use notis_server::apis::templates::{
+    SchemaTemplateGetResponse, TemplatesGetResponse, TemplatesIdDeleteResponse,
+    TemplatesIdGetResponse, TemplatesIdPreviewPostResponse, TemplatesIdPutResponse,
 };
 use notis_server::models;
 use notis_server::models::{
-    DefaultServicePostRequest, NotificationsPostRequest,
+    DefaultServicePostRequest, HistoryGetQueryParams, HistoryIdAttachmentsIndexGetPathParams,
+    NotificationsGetQueryParams, NotificationsIdGetPathParams, NotificationsPostRequest,
+    RouteSample, RoutesIdDeletePathParams, RoutesIdGetPathParams, RoutesIdPutPathParams,
     SchemaServiceTypesServiceTypeConfigGetPathParams, ServicesIdConfigGetPathParams,
     ServicesIdConfigPatchPathParams, ServicesIdConfigSchemaGetPathParams,
     ServicesIdConfigSchemaGetQueryParams, ServicesIdDeletePathParams, ServicesIdGetPathParams,
     ServicesIdNotificationsPostPathParams, ServicesIdNotificationsSchemaGetPathParams,
-    ServicesIdPutPathParams, ServicesIdPutRequest,
+    ServicesIdPutPathParams, ServicesIdPutRequest, ServicesIdReceiversGetPathParams,
+    TemplateSample, TemplatesIdDeletePathParams, TemplatesIdGetPathParams,
+    TemplatesIdPreviewPostPathParams, TemplatesIdPutPathParams,
 };
 use notis_server::types::Object;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Display;
 use std::ops::Deref;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock, RwLockWriteGuard};
+use tracing::{error, warn};
+
+/// Subdirectory of the queue directory which keeps the state of the bounce mailboxes
+const BOUNCES_DIR: &str = "bounces";
 
 fn reason(reason: impl Display) -> models::Reason {
     models::Reason {
         reason: Some(reason.to_string()),
+        rejected_receivers: None,
+    }
+}
+
+fn delivery_status(state: DeliveryState) -> models::DeliveryStatus {
+    match state {
+        DeliveryState::Queued => models::DeliveryStatus::Queued,
+        DeliveryState::Sending => models::DeliveryStatus::Sending,
+        DeliveryState::Retrying => models::DeliveryStatus::Retrying,
+        DeliveryState::Delivered => models::DeliveryStatus::Delivered,
+        DeliveryState::Failed => models::DeliveryStatus::Failed,
+    }
+}
+
+fn delivery_state(status: models::DeliveryStatus) -> DeliveryState {
+    match status {
+        models::DeliveryStatus::Queued => DeliveryState::Queued,
+        models::DeliveryStatus::Sending => DeliveryState::Sending,
+        models::DeliveryStatus::Retrying => DeliveryState::Retrying,
+        models::DeliveryStatus::Delivered => DeliveryState::Delivered,
+        models::DeliveryStatus::Failed => DeliveryState::Failed,
+    }
+}
+
+fn receiver_report(report: ReceiverReport) -> models::ReceiverReport {
+    models::ReceiverReport {
+        address: report.address,
+        state: match report.state {
+            ReceiverState::Delivered => models::ReceiverState::Delivered,
+            ReceiverState::Delayed => models::ReceiverState::Delayed,
+            ReceiverState::Failed => models::ReceiverState::Failed,
+        },
+        status: report.status,
+        diagnostic: report.diagnostic,
+        time: report.time.into(),
+    }
+}
+
+fn severity(severity: models::Severity) -> Severity {
+    match severity {
+        models::Severity::Critical => Severity::Critical,
+        models::Severity::Error => Severity::Error,
+        models::Severity::Warning => Severity::Warning,
+        models::Severity::Info => Severity::Info,
     }
 }
 
 pub struct Server {
     config: Arc<RwLock<crate::config::Config>>,
     config_path: PathBuf,
+    queue: Arc<Queue>,
+    history: Option<History>,
+    receiver_health: Health,
+    seen_messages: SeenMessages,
 }
 
 pub struct ConfigWriter<'a> {
@@ -44,13 +120,30 @@ pub struct ConfigWriter<'a> {
     pub new_config: crate::config::Config,
 }
 
-impl ConfigWriter<'_> {
+impl<'a> ConfigWriter<'a> {
+    fn new(config: &'a RwLock<crate::config::Config>, config_path: &'a Path) -> Self {
+        let lock = config.write().unwrap_or_else(|e| e.into_inner());
+        Self {
+            new_config: lock.deref().clone(),
+            lock,
+            config_path,
+        }
+    }
+
     pub fn write_config(mut self) -> std::io::Result<bool> {
         if self.new_config != *self.lock {
             serde_json::to_writer_pretty(
                 std::fs::File::create(self.config_path)?,
                 &self.new_config,
             )?;
+            // Connections of deleted or changed smtp services are not reused
+            for (id, service) in &self.lock.notification_services {
+                if matches!(service, NotisNotificationService::SMTP(_))
+                    && self.new_config.notification_services.get(id) != Some(service)
+                {
+                    crate::services::smtp::pool::evict(id);
+                }
+            }
             *self.lock = self.new_config.clone();
             Ok(true)
         } else {
@@ -60,19 +153,249 @@ impl ConfigWriter<'_> {
 }
 
 impl Server {
-    pub fn new(config: crate::config::Config, config_path: PathBuf) -> Self {
-        Self {
+    /// Opens the delivery queue and the history relative to the directory of the config file
+    pub fn new(config: crate::config::Config, config_path: PathBuf) -> Result<Self, crate::Error> {
+        let base_dir = config_path.parent().unwrap_or(Path::new("."));
+        let queue = Queue::open(config.queue.clone(), base_dir)?;
+        let bounces_dir = base_dir.join(&config.queue.path).join(BOUNCES_DIR);
+        std::fs::create_dir_all(&bounces_dir)?;
+        let history = config
+            .history
+            .clone()
+            .map(|history| History::open(history, base_dir))
+            .transpose()?;
+        Ok(Self {
             config: Arc::new(RwLock::new(config)),
             config_path,
-        }
+            queue: Arc::new(queue),
+            history,
+            receiver_health: Health::open(bounces_dir.join("health.json")),
+            seen_messages: SeenMessages::open(bounces_dir.join("pop3_seen.json")),
+        })
+    }
+
+    pub fn queue(&self) -> Arc<Queue> {
+        self.queue.clone()
     }
 
     pub fn config_writer(&'_ self) -> ConfigWriter<'_> {
-        let lock = self.config.write().unwrap_or_else(|e| e.into_inner());
-        ConfigWriter {
-            new_config: lock.deref().clone(),
-            lock,
-            config_path: self.config_path.as_path(),
+        ConfigWriter::new(&self.config, &self.config_path)
+    }
+
+    /// Sends the notification via its service or the first service of its failover chain which
+    /// succeeds, and returns the id of that service. The chain stops at a permanent error, at
+    /// rejected receivers and at services which reached some of their receivers, the remaining
+    /// receivers are retried via that service.
+    async fn send(&self, entry: &Entry) -> Result<String, crate::Error> {
+        let chain = {
+            let config = self.config.read().unwrap();
+            match config.target(&entry.service_id) {
+                Some(Target::Service(service)) => Err(service.clone()),
+                Some(Target::FailoverChain(service_ids)) => Ok(service_ids
+                    .iter()
+                    .map(|id| (id.clone(), config.notification_services.get(id).cloned()))
+                    .collect::<Vec<_>>()),
+                None => return Err(crate::Error::UnknownService(entry.service_id.clone())),
+            }
+        };
+        let metadata = Metadata {
+            notification_id: Some(entry.id),
+            ..entry.metadata.clone()
+        };
+        let chain = match chain {
+            Ok(chain) => chain,
+            Err(service) => {
+                service
+                    .send_notification_with_raw_options(
+                        &entry.service_id,
+                        entry.options.clone(),
+                        entry.attachments.clone(),
+                        &entry.title,
+                        entry.content.as_deref(),
+                        entry.html.as_deref(),
+                        &metadata,
+                    )
+                    .await?;
+                return Ok(entry.service_id.clone());
+            }
+        };
+        let mut errors = Vec::new();
+        for (service_id, service) in chain {
+            let result = match &service {
+                Some(service) => {
+                    service
+                        .send_notification_with_raw_options(
+                            &service_id,
+                            entry
+                                .options
+                                .as_ref()
+                                .and_then(|options| options.get(&service_id))
+                                .cloned(),
+                            entry.attachments.clone(),
+                            &entry.title,
+                            entry.content.as_deref(),
+                            entry.html.as_deref(),
+                            &metadata,
+                        )
+                        .await
+                }
+                None => Err(crate::Error::UnknownService(service_id.clone())),
+            };
+            match result {
+                Ok(()) => return Ok(service_id),
+                Err(e) if service.is_some() && e.is_permanent() => return Err(e),
+                Err(e)
+                    if !e.rejected_receivers().is_empty()
+                        || e.partially_delivered_by(&service_id).is_some() =>
+                {
+                    errors.push((service_id, e));
+                    return Err(crate::Error::Failover(errors));
+                }
+                Err(e) => {
+                    warn!("Could not send notification via {service_id}, failing over: {e}");
+                    errors.push((service_id, e));
+                }
+            }
+        }
+        Err(crate::Error::Failover(errors))
+    }
+
+    /// Updates the refresh tokens in the config which were rotated by OAuth2 token endpoints, the
+    /// config file is written on the blocking thread pool so the delivery worker is not stalled
+    async fn persist_refresh_tokens(&self, rotated: HashMap<String, String>) {
+        if rotated.is_empty() {
+            return;
+        }
+        let config = self.config.clone();
+        let config_path = self.config_path.clone();
+        let result = tokio::task::spawn_blocking(move || {
+            let mut config_writer = ConfigWriter::new(&config, &config_path);
+            for (service_id, refresh_token) in rotated {
+                if let Some(NotisNotificationService::SMTP(config)) = config_writer
+                    .new_config
+                    .notification_services
+                    .get_mut(&service_id)
+                    && let Some(OAuth2 {
+                        grant:
+                            Grant::RefreshToken {
+                                refresh_token: current,
+                            },
+                        ..
+                    }) = &mut config.oauth2
+                {
+                    *current = refresh_token;
+                }
+            }
+            config_writer.write_config()
+        })
+        .await
+        .unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic()));
+        if let Err(e) = result {
+            error!("Could not persist rotated OAuth2 refresh tokens: {e}");
+        }
+    }
+}
+
+impl crate::smtp_ingress::Dispatcher for Server {
+    fn has_service(&self, id: &str) -> bool {
+        let config = self.config.read().unwrap();
+        config.target(id).is_some()
+    }
+
+    async fn dispatch(
+        &self,
+        id: &str,
+        notification: crate::smtp_ingress::Notification,
+    ) -> Result<(), crate::Error> {
+        let entry = Entry::new(
+            id.to_string(),
+            notification.title,
+            notification.content,
+            None,
+            None,
+            notification.attachments,
+            Metadata::default(),
+        );
+        self.queue.enqueue(entry).await?;
+        Ok(())
+    }
+}
+
+impl crate::services::smtp::bounces::Reports for Server {
+    fn bounce_mailboxes(&self) -> HashMap<String, BounceMailbox> {
+        let config = self.config.read().unwrap();
+        config
+            .notification_services
+            .iter()
+            .filter_map(|(id, service)| match service {
+                NotisNotificationService::SMTP(config) => {
+                    Some((id.clone(), config.bounce_mailbox.clone()?))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn report(&self, service_id: &str, mailbox: &BounceMailbox, report: DeliveryReport) {
+        for receiver in report.receivers {
+            if let Some(id) = report.notification_id
+                && !self.queue.report(&id, receiver.clone())
+            {
+                warn!(%id, "Delivery status notification of an unknown notification");
+            }
+            if let Some(health) =
+                self.receiver_health
+                    .record(service_id, &receiver, mailbox.dead_after)
+            {
+                warn!(
+                    service_id,
+                    "Receiver {} is dead after {} failed deliveries: {}",
+                    health.address,
+                    health.failures,
+                    health
+                        .last_report
+                        .diagnostic
+                        .as_deref()
+                        .unwrap_or(&health.last_report.status)
+                );
+            }
+        }
+    }
+
+    fn seen_messages(&self, service_id: &str) -> HashSet<String> {
+        self.seen_messages.get(service_id)
+    }
+
+    fn set_seen_messages(&self, service_id: &str, seen: &HashSet<String>) {
+        if let Err(e) = self.seen_messages.set(service_id, seen) {
+            error!(service_id, "Could not store the seen POP3 messages: {e}");
+        }
+    }
+}
+
+impl crate::queue::Deliverer for Server {
+    async fn deliver(&self, entry: &Entry) -> Result<String, crate::Error> {
+        let result = self.send(entry).await;
+        self.persist_refresh_tokens(crate::services::smtp::oauth2::take_rotated_refresh_tokens())
+            .await;
+        result
+    }
+
+    async fn finished(&self, entry: &Entry, state: DeliveryState) {
+        let Some(history) = &self.history else {
+            return;
+        };
+        let receivers = {
+            let config = self.config.read().unwrap();
+            let service_id = entry.delivered_by.as_ref().unwrap_or(&entry.service_id);
+            config
+                .notification_services
+                .get(service_id)
+                .map(|service| service.receivers(entry.options.as_ref()))
+                .unwrap_or_default()
+        };
+        if let Err(e) = history.record(entry.clone(), state, receivers).await {
+            error!("Could not record notification in the history: {e}");
         }
     }
 }
@@ -239,10 +562,78 @@ impl notis_server::apis::services::Services for Server {
             Ok(_) => result,
         })
     }
+
+    async fn services_id_receivers_get(
+        &self,
+        _method: Method,
+        _host: axum::extract::Host,
+        _cookies: axum_extra::extract::cookie::CookieJar,
+        path_params: ServicesIdReceiversGetPathParams,
+    ) -> Result<ServicesIdReceiversGetResponse, ()> {
+        let config = self.config.read().unwrap();
+        Ok(api::services::id::receivers::get(
+            &config,
+            &self.receiver_health,
+            path_params,
+        ))
+    }
+}
+
+#[async_trait]
+impl notis_server::apis::history::History for Server {
+    async fn history_get(
+        &self,
+        _method: Method,
+        _host: axum::extract::Host,
+        _cookies: axum_extra::extract::cookie::CookieJar,
+        query_params: HistoryGetQueryParams,
+    ) -> Result<HistoryGetResponse, ()> {
+        Ok(api::history::get(self.history.as_ref(), query_params).await)
+    }
+
+    async fn history_id_attachments_index_get(
+        &self,
+        _method: Method,
+        _host: axum::extract::Host,
+        _cookies: axum_extra::extract::cookie::CookieJar,
+        path_params: HistoryIdAttachmentsIndexGetPathParams,
+    ) -> Result<HistoryIdAttachmentsIndexGetResponse, ()> {
+        Ok(api::history::id::attachments::index::get(self.history.as_ref(), path_params).await)
+    }
 }
 
 #[async_trait]
 impl notis_server::apis::notifications::Notifications for Server {
+    async fn notifications_fanout_post(
+        &self,
+        _method: Method,
+        _host: axum::extract::Host,
+        _cookies: axum_extra::extract::cookie::CookieJar,
+        body: axum_extra::extract::Multipart,
+    ) -> Result<NotificationsFanoutPostResponse, ()> {
+        Ok(api::notifications::fanout::post(&self.config, &self.queue, body).await)
+    }
+
+    async fn notifications_get(
+        &self,
+        _method: Method,
+        _host: axum::extract::Host,
+        _cookies: axum_extra::extract::cookie::CookieJar,
+        query_params: NotificationsGetQueryParams,
+    ) -> Result<NotificationsGetResponse, ()> {
+        Ok(api::notifications::get(&self.queue, query_params))
+    }
+
+    async fn notifications_id_get(
+        &self,
+        _method: Method,
+        _host: axum::extract::Host,
+        _cookies: axum_extra::extract::cookie::CookieJar,
+        path_params: NotificationsIdGetPathParams,
+    ) -> Result<NotificationsIdGetResponse, ()> {
+        Ok(api::notifications::id::get(&self.queue, path_params))
+    }
+
     async fn notifications_post(
         &self,
         _method: Method,
@@ -250,8 +641,7 @@ impl notis_server::apis::notifications::Notifications for Server {
         _cookies: axum_extra::extract::cookie::CookieJar,
         body: NotificationsPostRequest,
     ) -> Result<NotificationsPostResponse, ()> {
-        let config = self.config.read().unwrap();
-        Ok(api::notifications::post(&config, body))
+        Ok(api::notifications::post(&self.config, &self.queue, body).await)
     }
 
     async fn services_id_notifications_post(
@@ -267,10 +657,310 @@ impl notis_server::apis::notifications::Notifications for Server {
                 Ok(request) => request,
                 Err(e) => return Ok(e),
             };
-        let config = self.config.read().unwrap();
         Ok(
-            api::services::id::notifications::post(&config, path_params, request)
+            api::services::id::notifications::post(&self.config, &self.queue, path_params, request)
+                .await
                 .unwrap_or_else(|e| e),
         )
     }
 }
+
+#[async_trait]
+impl notis_server::apis::routes::Routes for Server {
+    async fn routes_dry_run_post(
+        &self,
+        _method: Method,
+        _host: axum::extract::Host,
+        _cookies: axum_extra::extract::cookie::CookieJar,
+        body: RouteSample,
+    ) -> Result<RoutesDryRunPostResponse, ()> {
+        let config = self.config.read().unwrap();
+        Ok(api::routes::dry_run::post(&config, body))
+    }
+
+    async fn routes_get(
+        &self,
+        _method: Method,
+        _host: axum::extract::Host,
+        _cookies: axum_extra::extract::cookie::CookieJar,
+    ) -> Result<RoutesGetResponse, ()> {
+        let config = self.config.read().unwrap();
+        Ok(api::routes::get(&config))
+    }
+
+    async fn routes_id_delete(
+        &self,
+        _method: Method,
+        _host: axum::extract::Host,
+        _cookies: axum_extra::extract::cookie::CookieJar,
+        path_params: RoutesIdDeletePathParams,
+    ) -> Result<RoutesIdDeleteResponse, ()> {
+        let mut config_writer = self.config_writer();
+        let result = api::routes::id::delete(&mut config_writer.new_config, path_params);
+        Ok(match config_writer.write_config() {
+            Err(e) => RoutesIdDeleteResponse::Status500_InternalServerError(reason(e)),
+            Ok(_) => result,
+        })
+    }
+
+    async fn routes_id_get(
+        &self,
+        _method: Method,
+        _host: axum::extract::Host,
+        _cookies: axum_extra::extract::cookie::CookieJar,
+        path_params: RoutesIdGetPathParams,
+    ) -> Result<RoutesIdGetResponse, ()> {
+        let config = self.config.read().unwrap();
+        Ok(api::routes::id::get(&config, path_params))
+    }
+
+    async fn routes_id_put(
+        &self,
+        _method: Method,
+        _host: axum::extract::Host,
+        _cookies: axum_extra::extract::cookie::CookieJar,
+        path_params: RoutesIdPutPathParams,
+        body: Object,
+    ) -> Result<RoutesIdPutResponse, ()> {
+        let mut config_writer = self.config_writer();
+        let result = api::routes::id::put(&mut config_writer.new_config, path_params, body);
+        Ok(match config_writer.write_config() {
+            Err(e) => RoutesIdPutResponse::Status500_InternalServerError(reason(e)),
+            Ok(_) => result,
+        })
+    }
+
+    async fn schema_route_get(
+        &self,
+        _method: Method,
+        _host: axum::extract::Host,
+        _cookies: axum_extra::extract::cookie::CookieJar,
+    ) -> Result<SchemaRouteGetResponse, ()> {
+        Ok(api::schema::route::get())
+    }
+}
+
+#[async_trait]
+impl notis_server::apis::templates::Templates for Server {
+    async fn schema_template_get(
+        &self,
+        _method: Method,
+        _host: axum::extract::Host,
+        _cookies: axum_extra::extract::cookie::CookieJar,
+    ) -> Result<SchemaTemplateGetResponse, ()> {
+        Ok(api::schema::template::get())
+    }
+
+    async fn templates_get(
+        &self,
+        _method: Method,
+        _host: axum::extract::Host,
+        _cookies: axum_extra::extract::cookie::CookieJar,
+    ) -> Result<TemplatesGetResponse, ()> {
+        let config = self.config.read().unwrap();
+        Ok(api::templates::get(&config))
+    }
+
+    async fn templates_id_delete(
+        &self,
+        _method: Method,
+        _host: axum::extract::Host,
+        _cookies: axum_extra::extract::cookie::CookieJar,
+        path_params: TemplatesIdDeletePathParams,
+    ) -> Result<TemplatesIdDeleteResponse, ()> {
+        let mut config_writer = self.config_writer();
+        let result = api::templates::id::delete(&mut config_writer.new_config, path_params);
+        Ok(match config_writer.write_config() {
+            Err(e) => TemplatesIdDeleteResponse::Status500_InternalServerError(reason(e)),
+            Ok(_) => result,
+        })
+    }
+
+    async fn templates_id_get(
+        &self,
+        _method: Method,
+        _host: axum::extract::Host,
+        _cookies: axum_extra::extract::cookie::CookieJar,
+        path_params: TemplatesIdGetPathParams,
+    ) -> Result<TemplatesIdGetResponse, ()> {
+        let config = self.config.read().unwrap();
+        Ok(api::templates::id::get(&config, path_params))
+    }
+
+    async fn templates_id_preview_post(
+        &self,
+        _method: Method,
+        _host: axum::extract::Host,
+        _cookies: axum_extra::extract::cookie::CookieJar,
+        path_params: TemplatesIdPreviewPostPathParams,
+        body: TemplateSample,
+    ) -> Result<TemplatesIdPreviewPostResponse, ()> {
+        let config = self.config.read().unwrap();
+        Ok(api::templates::id::preview::post(
+            &config,
+            path_params,
+            body,
+        ))
+    }
+
+    async fn templates_id_put(
+        &self,
+        _method: Method,
+        _host: axum::extract::Host,
+        _cookies: axum_extra::extract::cookie::CookieJar,
+        path_params: TemplatesIdPutPathParams,
+        body: Object,
+    ) -> Result<TemplatesIdPutResponse, ()> {
+        let mut config_writer = self.config_writer();
+        let result = api::templates::id::put(&mut config_writer.new_config, path_params, body);
+        Ok(match config_writer.write_config() {
+            Err(e) => TemplatesIdPutResponse::Status500_InternalServerError(reason(e)),
+            Ok(_) => result,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::queue::Deliverer;
+    use crate::smtp_ingress::Dispatcher;
+    use std::collections::HashMap;
+
+    fn server(failover_chains: HashMap<String, Vec<String>>) -> Server {
+        let dir = std::env::temp_dir().join(format!("notis-server-test-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut config = crate::config::Config::example();
+        config.notification_services.remove("smtp");
+        config.failover_chains = failover_chains;
+        config.history = None;
+        Server::new(config, dir.join("config.json")).unwrap()
+    }
+
+    #[tokio::test]
+    async fn persists_rotated_refresh_tokens() {
+        let dir = std::env::temp_dir().join(format!("notis-server-test-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut config = crate::config::Config::example();
+        config.history = None;
+        let Some(NotisNotificationService::SMTP(smtp)) =
+            config.notification_services.get_mut("smtp")
+        else {
+            unreachable!()
+        };
+        smtp.oauth2 = Some(OAuth2 {
+            token_url: "http://localhost/token".to_string(),
+            client_id: "notis".to_string(),
+            client_secret: None,
+            scopes: Vec::new(),
+            grant: Grant::RefreshToken {
+                refresh_token: "first".to_string(),
+            },
+        });
+        let config_path = dir.join("config.json");
+        let server = Server::new(config, config_path.clone()).unwrap();
+
+        server
+            .persist_refresh_tokens(HashMap::from([("smtp".to_string(), "rotated".to_string())]))
+            .await;
+        let written: crate::config::Config =
+            serde_json::from_slice(&std::fs::read(&config_path).unwrap()).unwrap();
+        let Some(NotisNotificationService::SMTP(smtp)) = written.notification_services.get("smtp")
+        else {
+            unreachable!()
+        };
+        assert_eq!(
+            smtp.oauth2.as_ref().unwrap().grant,
+            Grant::RefreshToken {
+                refresh_token: "rotated".to_string()
+            }
+        );
+        assert_eq!(*server.config.read().unwrap(), written);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn failover() {
+        let server = server(HashMap::from([
+            (
+                "alarms".to_string(),
+                vec!["missing".to_string(), "log".to_string()],
+            ),
+            ("broken".to_string(), vec!["missing".to_string()]),
+        ]));
+        assert!(server.has_service("alarms"));
+
+        let entry = Entry::new(
+            "alarms".to_string(),
+            "Title".to_string(),
+            None,
+            None,
+            None,
+            Vec::new(),
+            Metadata::default(),
+        );
+        assert_eq!(server.deliver(&entry).await.unwrap(), "log");
+
+        let entry = Entry::new(
+            "broken".to_string(),
+            "Title".to_string(),
+            None,
+            None,
+            None,
+            Vec::new(),
+            Metadata::default(),
+        );
+        let error = server.deliver(&entry).await.unwrap_err();
+        assert!(matches!(&error, crate::Error::Failover(errors) if errors.len() == 1));
+        assert!(error.is_permanent());
+    }
+
+    #[tokio::test]
+    async fn failover_stops_at_permanent_errors() {
+        let server = server(HashMap::from([(
+            "alarms".to_string(),
+            vec!["log".to_string(), "missing".to_string()],
+        )]));
+        let mut entry = Entry::new(
+            "alarms".to_string(),
+            "Title".to_string(),
+            None,
+            None,
+            None,
+            Vec::new(),
+            Metadata::default(),
+        );
+        entry.options = Some(serde_json::json!({"log": {"level": "Loud"}}));
+        let error = server.deliver(&entry).await.unwrap_err();
+        assert!(matches!(error, crate::Error::Serde(_)), "{error}");
+
+        entry.options = Some(serde_json::json!({"log": {"level": "Warn"}}));
+        assert_eq!(server.deliver(&entry).await.unwrap(), "log");
+    }
+
+    #[test]
+    fn failover_retry_options() {
+        let rejected = |address: &str| crate::services::RejectedReceiver {
+            address: address.to_string(),
+            code: 450,
+            message: "Mailbox busy".to_string(),
+        };
+        let error = crate::Error::Failover(vec![
+            (
+                "zulip".to_string(),
+                crate::Error::UnknownService("zulip".to_string()),
+            ),
+            (
+                "smtp".to_string(),
+                crate::services::smtp::Error::Rejected(vec![rejected("bob@example.com")]).into(),
+            ),
+        ]);
+        assert_eq!(error.rejected_receivers(), [rejected("bob@example.com")]);
+        let options = serde_json::json!({"smtp": {"retry_receivers": ["bob@example.com", "eve@example.com"]}});
+        assert_eq!(
+            error.retry_options(Some(&options)),
+            Some(serde_json::json!({"smtp": {"retry_receivers": ["bob@example.com"]}}))
+        );
+        assert!(!error.is_permanent());
+    }
+}
@@ -1,4 +1,7 @@
 pub mod default_service;
+pub mod history;
 pub mod notifications;
+pub mod routes;
 pub mod schema;
 pub mod services;
+pub mod templates;
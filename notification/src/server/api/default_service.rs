@@ -9,18 +9,18 @@ use notis_server::models::DefaultServicePostRequest as PostRequest;
 pub fn get(config: &Config) -> GetResponse {
     match &config.default_notification_service {
         None => GetResponse::Status404_NoDefaultServiceRegistered,
-        Some(id) => match config.notification_services.get(id) {
+        Some(id) => match config.target(id) {
             None => GetResponse::Status404_NoDefaultServiceRegistered,
-            Some(service) => GetResponse::Status200_Success(models::DefaultServiceGet200Response {
+            Some(target) => GetResponse::Status200_Success(models::DefaultServiceGet200Response {
                 id: id.clone(),
-                r#type: service.type_string(),
+                r#type: target.type_string(),
             }),
         },
     }
 }
 
 pub fn post(config: &mut Config, request: PostRequest) -> PostResponse {
-    if config.notification_services.contains_key(&request.id) {
+    if config.target(&request.id).is_some() {
         match config.default_notification_service.replace(request.id) {
             None => PostResponse::Status201_DefaultServiceWasSet,
             Some(_) => PostResponse::Status200_DefaultServiceWasReplaced,
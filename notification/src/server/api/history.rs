@@ -0,0 +1,71 @@
+pub mod id;
+
+use crate::history::{History, Query, Record};
+use crate::queue::DeliveryState;
+use crate::server::{delivery_state, delivery_status, reason};
+use notis_server::apis::history::HistoryGetResponse as GetResponse;
+use notis_server::models;
+use notis_server::models::HistoryGetQueryParams as GetQueryParams;
+
+const DEFAULT_LIMIT: u32 = 50;
+
+fn to_model(record: Record) -> models::HistoryEntry {
+    models::HistoryEntry {
+        id: record.id,
+        service: record.service_id,
+        title: record.title,
+        content: record.content,
+        receivers: record.receivers,
+        attachments: record
+            .attachments
+            .into_iter()
+            .map(|attachment| models::HistoryAttachment {
+                file_name: attachment.file_name,
+                content_type: attachment.content_type,
+                size: attachment.size.try_into().unwrap_or(i64::MAX),
+                stored: attachment.stored,
+            })
+            .collect(),
+        status: delivery_status(record.state),
+        attempts: record.attempts.try_into().unwrap_or(i32::MAX),
+        error: record.error.map(reason),
+        created: record.created.into(),
+        finished: record.finished.into(),
+        delivered_by: record.delivered_by,
+    }
+}
+
+pub async fn get(history: Option<&History>, query_params: GetQueryParams) -> GetResponse {
+    let Some(history) = history else {
+        return GetResponse::Status404_HistoryIsNotEnabled;
+    };
+    let state = query_params.status.map(delivery_state);
+    if state.is_some_and(|state| !matches!(state, DeliveryState::Delivered | DeliveryState::Failed))
+    {
+        return GetResponse::Status400_BadRequest(reason(
+            "The history only contains delivered and failed notifications",
+        ));
+    }
+    let query = Query {
+        service_id: query_params.service,
+        state,
+        since: query_params.since.map(Into::into),
+        until: query_params.until.map(Into::into),
+        search: query_params.search,
+        offset: query_params
+            .offset
+            .and_then(|offset| offset.try_into().ok())
+            .unwrap_or_default(),
+        limit: query_params
+            .limit
+            .and_then(|limit| limit.try_into().ok())
+            .unwrap_or(DEFAULT_LIMIT),
+    };
+    match history.search(query).await {
+        Ok(page) => GetResponse::Status200_Success(models::HistoryPage {
+            total: page.total.try_into().unwrap_or(i64::MAX),
+            entries: page.records.into_iter().map(to_model).collect(),
+        }),
+        Err(e) => GetResponse::Status500_InternalServerError(reason(e)),
+    }
+}
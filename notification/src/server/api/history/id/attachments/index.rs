@@ -0,0 +1,16 @@
+use crate::history::History;
+use crate::server::reason;
+use notis_server::apis::history::HistoryIdAttachmentsIndexGetResponse as GetResponse;
+use notis_server::models::HistoryIdAttachmentsIndexGetPathParams as GetPathParams;
+use notis_server::types::ByteArray;
+
+pub async fn get(history: Option<&History>, path_params: GetPathParams) -> GetResponse {
+    let (Some(history), Ok(index)) = (history, path_params.index.try_into()) else {
+        return GetResponse::Status404_AttachmentNotFound;
+    };
+    match history.attachment(path_params.id, index).await {
+        Ok(Some(content)) => GetResponse::Status200_Success(ByteArray(content)),
+        Ok(None) => GetResponse::Status404_AttachmentNotFound,
+        Err(e) => GetResponse::Status500_InternalServerError(reason(e)),
+    }
+}
@@ -1,17 +1,151 @@
-use crate::server::reason;
-use notis_server::apis::notifications::NotificationsPostResponse as PostResponse;
-use notis_server::models::NotificationsPostRequest as PostRequest;
+pub mod fanout;
+pub mod id;
 
-pub fn post(config: &crate::config::Config, request: PostRequest) -> PostResponse {
-    match &config
-        .default_notification_service
-        .as_ref()
-        .and_then(|default| config.notification_services.get(default))
-    {
-        Some(service) => {
-            match service.send_notification(&request.title, request.content.as_deref(), Vec::new())
-            {
-                Ok(_) => PostResponse::Status200_Success,
+use crate::queue::{Entry, Filter, Queue, Status};
+use crate::routing;
+use crate::server::{delivery_state, delivery_status, reason, receiver_report, severity};
+use crate::services::{Metadata, RejectedReceiver};
+use crate::templates;
+use notis_server::apis::notifications::{
+    NotificationsGetResponse as GetResponse, NotificationsPostResponse as PostResponse,
+};
+use notis_server::models;
+use notis_server::models::{
+    NotificationsGetQueryParams as GetQueryParams, NotificationsPostRequest as PostRequest,
+};
+use std::sync::RwLock;
+
+fn rejected_receiver(receiver: RejectedReceiver) -> models::RejectedReceiver {
+    models::RejectedReceiver {
+        transient: receiver.is_transient(),
+        address: receiver.address,
+        code: receiver.code.into(),
+        message: Some(receiver.message),
+    }
+}
+
+fn to_model(status: Status) -> models::NotificationStatus {
+    models::NotificationStatus {
+        id: status.id,
+        service: status.service_id,
+        status: delivery_status(status.state),
+        attempts: status.attempts.try_into().unwrap_or(i32::MAX),
+        created: status.created.into(),
+        updated: status.updated.into(),
+        next_attempt: status.next_attempt.map(Into::into),
+        last_error: status.last_error.map(|error| models::Reason {
+            rejected_receivers: (!status.rejected_receivers.is_empty()).then(|| {
+                status
+                    .rejected_receivers
+                    .into_iter()
+                    .map(rejected_receiver)
+                    .collect()
+            }),
+            ..reason(error)
+        }),
+        delivered_by: status.delivered_by,
+        receiver_reports: (!status.receiver_reports.is_empty()).then(|| {
+            status
+                .receiver_reports
+                .into_iter()
+                .map(receiver_report)
+                .collect()
+        }),
+    }
+}
+
+pub fn get(queue: &Queue, query_params: GetQueryParams) -> GetResponse {
+    let filter = Filter {
+        service_id: query_params.service,
+        state: query_params.status.map(delivery_state),
+        since: query_params.since.map(Into::into),
+    };
+    GetResponse::Status200_Success(queue.statuses(&filter).into_iter().map(to_model).collect())
+}
+
+pub async fn post(
+    config: &RwLock<crate::config::Config>,
+    queue: &Queue,
+    request: PostRequest,
+) -> PostResponse {
+    let metadata = Metadata {
+        severity: request.severity.map(severity),
+        tags: request.tags.unwrap_or_default(),
+        source: request.source,
+        timestamp: request.timestamp.map(Into::into),
+        notification_id: None,
+    };
+    let variables = request
+        .variables
+        .and_then(|variables| variables.0.as_object().cloned())
+        .unwrap_or_default();
+    let message = templates::Notification {
+        title: &request.title,
+        content: request.content.as_deref(),
+        html: request.html.as_deref(),
+        markdown: request.markdown.unwrap_or_default(),
+        metadata: &metadata,
+        variables: &variables,
+    };
+    let notification = routing::Notification::new(&request.title, &metadata);
+    let (targets, default_service) = {
+        let config = config.read().unwrap();
+        let render = |id: &str| {
+            let service_type = config
+                .target(id)
+                .map(|target| target.type_string())
+                .unwrap_or_default();
+            templates::message(
+                &config,
+                request.template.as_deref(),
+                &service_type,
+                &message,
+            )
+        };
+        let targets: Vec<_> = routing::route(&config.routes, &notification)
+            .targets
+            .into_iter()
+            .map(|target| {
+                let message = target
+                    .validate(&config)
+                    .map_err(Into::into)
+                    .and_then(|()| render(&target.service).map_err(Into::into));
+                (target.service.clone(), target.options.clone(), message)
+            })
+            .collect();
+        let default_service = config
+            .default_notification_service
+            .clone()
+            .filter(|default| config.target(default).is_some())
+            .map(|default| {
+                let message = render(&default);
+                (default, message)
+            });
+        (targets, default_service)
+    };
+    if !targets.is_empty() {
+        return PostResponse::Status202_NotificationWasQueuedForDeliveryOrRouted(
+            fanout::enqueue(queue, targets, Vec::new(), metadata)
+                .await
+                .into(),
+        );
+    }
+    match default_service {
+        Some((_, Err(e))) => PostResponse::Status400_BadRequest(reason(e)),
+        Some((id, Ok(message))) => {
+            let entry = Entry::new(
+                id,
+                message.title,
+                message.content,
+                message.html,
+                None,
+                Vec::new(),
+                metadata,
+            );
+            match queue.enqueue(entry).await {
+                Ok(id) => PostResponse::Status202_NotificationWasQueuedForDeliveryOrRouted(
+                    models::NotificationId { id }.into(),
+                ),
                 Err(e) => PostResponse::Status500_InternalServerError(reason(e)),
             }
         }
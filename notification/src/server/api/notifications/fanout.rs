@@ -0,0 +1,272 @@
+use crate::queue::{Entry, Queue};
+use crate::server::{form, reason};
+use crate::services::{Attachment, Metadata};
+use crate::templates::{self, Message};
+use futures_util::future::join_all;
+use notis_server::apis::notifications::NotificationsFanoutPostResponse as PostResponse;
+use notis_server::models;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+const SERVICE: &str = "service";
+const GROUP: &str = "group";
+
+fn bad_request(error: impl std::fmt::Display) -> PostResponse {
+    PostResponse::Status400_BadRequest(reason(error))
+}
+
+pub async fn post(
+    config: &RwLock<crate::config::Config>,
+    queue: &Queue,
+    multipart: axum_extra::extract::Multipart,
+) -> PostResponse {
+    let to_response = |error| match error {
+        form::Error::BadRequest(e) => bad_request(e),
+        form::Error::Internal(e) => PostResponse::Status500_InternalServerError(reason(e)),
+    };
+    let mut form = match form::read(multipart, &[SERVICE, GROUP]).await {
+        Ok(form) => form,
+        Err(e) => return to_response(e),
+    };
+    let title = match form.title() {
+        Ok(title) => title,
+        Err(e) => return to_response(e),
+    };
+    let mut options: HashMap<String, serde_json::Value> =
+        match form.config.map(serde_json::from_value).transpose() {
+            Ok(options) => options.unwrap_or_default(),
+            Err(e) => return bad_request(e),
+        };
+
+    let variables = form.variables.take().unwrap_or_default();
+    let notification = templates::Notification {
+        title: &title,
+        content: form.content.as_deref(),
+        html: form.html.as_deref(),
+        markdown: form.markdown.unwrap_or_default(),
+        metadata: &form.metadata,
+        variables: &variables,
+    };
+    let targets = {
+        let config = config.read().unwrap();
+        let mut service_ids = form.fields.remove(SERVICE).unwrap_or_default();
+        for group in form.fields.remove(GROUP).unwrap_or_default() {
+            match config.service_groups.get(&group) {
+                Some(members) => service_ids.extend(members.iter().cloned()),
+                None => return bad_request(format!("Unknown service group {group}")),
+            }
+        }
+        let mut targets: Vec<(String, Result<Message, crate::Error>)> = Vec::new();
+        for id in service_ids {
+            if !targets.iter().any(|(target, _)| *target == id) {
+                let message = match config.target(&id) {
+                    None => Err(crate::Error::UnknownService(id.clone())),
+                    Some(target) => options
+                        .get(&id)
+                        .map(|options| target.validate_options(options))
+                        .transpose()
+                        .map_err(Into::into)
+                        .and_then(|_| {
+                            templates::message(
+                                &config,
+                                form.template.as_deref(),
+                                &target.type_string(),
+                                &notification,
+                            )
+                            .map_err(Into::into)
+                        }),
+                };
+                targets.push((id, message));
+            }
+        }
+        targets
+    };
+    if targets.is_empty() {
+        return bad_request(format!("Missing {SERVICE} or {GROUP}"));
+    }
+    if let Some(id) = options
+        .keys()
+        .find(|id| !targets.iter().any(|(target, _)| target == *id))
+    {
+        return bad_request(format!("Options for service {id} which is not a target"));
+    }
+
+    let targets = targets
+        .into_iter()
+        .map(|(id, message)| {
+            let options = options.remove(&id);
+            (id, options, message)
+        })
+        .collect();
+    PostResponse::Status202_ResultForEachService(
+        enqueue(queue, targets, form.attachments, form.metadata).await,
+    )
+}
+
+/// Queues the message of each target whose options were validated and whose message was rendered
+pub async fn enqueue(
+    queue: &Queue,
+    targets: Vec<(
+        String,
+        Option<serde_json::Value>,
+        Result<Message, crate::Error>,
+    )>,
+    attachments: Vec<Attachment>,
+    metadata: Metadata,
+) -> Vec<models::FanoutResult> {
+    let enqueued = targets.into_iter().map(|(id, options, message)| {
+        let entry = message.map(|message| {
+            Entry::new(
+                id.clone(),
+                message.title,
+                message.content,
+                message.html,
+                options,
+                attachments.clone(),
+                metadata.clone(),
+            )
+        });
+        async move {
+            let result = match entry {
+                Ok(entry) => queue.enqueue(entry).await,
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(queued) => models::FanoutResult {
+                    service: id,
+                    id: Some(queued),
+                    error: None,
+                },
+                Err(e) => models::FanoutResult {
+                    service: id,
+                    id: None,
+                    error: Some(reason(e)),
+                },
+            }
+        }
+    });
+    join_all(enqueued).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::extract::FromRequest;
+    use std::path::PathBuf;
+
+    const BOUNDARY: &str = "notis-boundary";
+
+    fn config() -> RwLock<crate::config::Config> {
+        RwLock::new(
+            serde_json::from_value(serde_json::json!({
+                "port": 80,
+                "notification_services": {
+                    "log": {"type": "LOG", "level": "Info"},
+                    "audit": {"type": "LOG", "level": "Warn"},
+                },
+                "service_groups": {"ops": ["log", "audit"]},
+            }))
+            .unwrap(),
+        )
+    }
+
+    fn queue() -> (Queue, PathBuf) {
+        let dir = std::env::temp_dir().join(format!("notis-fanout-test-{}", uuid::Uuid::new_v4()));
+        (Queue::open(Default::default(), &dir).unwrap(), dir)
+    }
+
+    /// Multipart form with the text fields and an attachment
+    async fn multipart(fields: &[(&str, &str)]) -> axum_extra::extract::Multipart {
+        let mut body = String::new();
+        for (name, value) in fields {
+            body.push_str(&format!(
+                "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"{name}\"\r\n\r\n{value}\r\n"
+            ));
+        }
+        body.push_str(&format!(
+            "--{BOUNDARY}\r\n\
+            Content-Disposition: form-data; name=\"attachment\"; filename=\"status.csv\"\r\n\
+            Content-Type: text/csv\r\n\r\nlevel;5\r\n--{BOUNDARY}--\r\n"
+        ));
+        let request = axum::http::Request::builder()
+            .header(
+                "content-type",
+                format!("multipart/form-data; boundary={BOUNDARY}"),
+            )
+            .body(axum::body::Body::from(body))
+            .unwrap();
+        axum_extra::extract::Multipart::from_request(request, &())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn fans_out_to_services_and_groups() {
+        let (queue, dir) = queue();
+        let form = multipart(&[
+            ("title", "Toner low"),
+            ("content", "Replace the black toner"),
+            ("tags", r#"{"line": "3"}"#),
+            ("service", "log"),
+            ("group", "ops"),
+            ("service", "unknown"),
+            ("config", r#"{"audit": {"level": "Error"}}"#),
+        ])
+        .await;
+        let PostResponse::Status202_ResultForEachService(results) =
+            post(&config(), &queue, form).await
+        else {
+            panic!("fanout was rejected");
+        };
+        let services: Vec<_> = results
+            .iter()
+            .map(|result| (result.service.as_str(), result.id.is_some()))
+            .collect();
+        assert_eq!(
+            services,
+            [("log", true), ("unknown", false), ("audit", true)]
+        );
+        assert!(results[1].error.is_some());
+        assert_eq!(queue.len(), 2);
+
+        let entry: Entry = serde_json::from_slice(
+            &std::fs::read(
+                dir.join("queue")
+                    .join(format!("{}.json", results[2].id.unwrap())),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(entry.title, "Toner low");
+        assert_eq!(entry.content.as_deref(), Some("Replace the black toner"));
+        assert_eq!(entry.options, Some(serde_json::json!({"level": "Error"})));
+        assert_eq!(entry.metadata.tags["line"], "3");
+        assert_eq!(entry.attachments[0].file_name, "status.csv");
+        assert_eq!(entry.attachments[0].mime_type(), "text/csv");
+        assert_eq!(entry.attachments[0].file_content, b"level;5");
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_targets() {
+        let (queue, dir) = queue();
+        for fields in [
+            vec![("title", "Toner low"), ("group", "unknown")],
+            vec![("title", "Toner low")],
+            vec![
+                ("title", "Toner low"),
+                ("service", "log"),
+                ("config", r#"{"audit": {"level": "Error"}}"#),
+            ],
+            vec![("service", "log")],
+        ] {
+            let response = post(&config(), &queue, multipart(&fields).await).await;
+            assert!(
+                matches!(response, PostResponse::Status400_BadRequest(_)),
+                "{fields:?}"
+            );
+        }
+        assert!(queue.is_empty());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,11 @@
+use crate::queue::Queue;
+use crate::server::api::notifications::to_model;
+use notis_server::apis::notifications::NotificationsIdGetResponse as GetResponse;
+use notis_server::models::NotificationsIdGetPathParams as GetPathParams;
+
+pub fn get(queue: &Queue, path_params: GetPathParams) -> GetResponse {
+    match queue.status(&path_params.id) {
+        Some(status) => GetResponse::Status200_Success(to_model(status)),
+        None => GetResponse::Status404_NotificationNotFound,
+    }
+}
@@ -0,0 +1,21 @@
+pub mod dry_run;
+pub mod id;
+
+use crate::config::Config;
+use notis_server::apis::routes::RoutesGetResponse as GetResponse;
+use notis_server::types;
+
+pub fn get(config: &Config) -> GetResponse {
+    GetResponse::Status200_Success(
+        config
+            .routes
+            .iter()
+            .map(|(id, route)| {
+                (
+                    id.clone(),
+                    types::Object(serde_json::to_value(route).unwrap()),
+                )
+            })
+            .collect(),
+    )
+}
@@ -0,0 +1,44 @@
+use crate::config::Config;
+use crate::routing;
+use crate::server::severity;
+use crate::services::Metadata;
+use notis_server::apis::routes::RoutesDryRunPostResponse as PostResponse;
+use notis_server::models;
+use notis_server::models::RouteSample as PostRequest;
+use notis_server::types;
+
+pub fn post(config: &Config, request: PostRequest) -> PostResponse {
+    let metadata = Metadata {
+        severity: request.severity.map(severity),
+        tags: request.tags.unwrap_or_default(),
+        source: request.source,
+        timestamp: request.time.map(Into::into),
+        notification_id: None,
+    };
+    let notification = routing::Notification::new(&request.title, &metadata);
+    let routing = routing::route(&config.routes, &notification);
+    let targets = if routing.targets.is_empty() {
+        config
+            .default_notification_service
+            .iter()
+            .filter(|default| config.target(default).is_some())
+            .map(|default| models::RouteTarget {
+                service: default.clone(),
+                options: None,
+            })
+            .collect()
+    } else {
+        routing
+            .targets
+            .into_iter()
+            .map(|target| models::RouteTarget {
+                service: target.service.clone(),
+                options: target.options.clone().map(types::Object),
+            })
+            .collect()
+    };
+    PostResponse::Status200_Success(models::RouteDryRun {
+        routes: routing.routes.into_iter().map(str::to_string).collect(),
+        targets,
+    })
+}
@@ -0,0 +1,44 @@
+use crate::config::Config;
+use crate::routing::Route;
+use crate::server::reason;
+use notis_server::apis::routes::{
+    RoutesIdDeleteResponse as DeleteResponse, RoutesIdGetResponse as GetResponse,
+    RoutesIdPutResponse as PutResponse,
+};
+use notis_server::models::{
+    RoutesIdDeletePathParams as DeletePathParams, RoutesIdGetPathParams as GetPathParams,
+    RoutesIdPutPathParams as PutPathParams,
+};
+use notis_server::types;
+
+type PutRequest = types::Object;
+
+pub fn delete(config: &mut Config, path_params: DeletePathParams) -> DeleteResponse {
+    match config.routes.remove(&path_params.id) {
+        Some(_) => DeleteResponse::Status200_Success,
+        None => DeleteResponse::Status404_RouteNotFound,
+    }
+}
+
+pub fn get(config: &Config, path_params: GetPathParams) -> GetResponse {
+    match config.routes.get(&path_params.id) {
+        Some(route) => {
+            GetResponse::Status200_Success(types::Object(serde_json::to_value(route).unwrap()))
+        }
+        None => GetResponse::Status404_RouteNotFound,
+    }
+}
+
+pub fn put(config: &mut Config, path_params: PutPathParams, request: PutRequest) -> PutResponse {
+    let route: Route = match serde_json::from_value(request.0) {
+        Ok(route) => route,
+        Err(e) => return PutResponse::Status400_BadRequest(reason(format!("Invalid route: {e}"))),
+    };
+    if let Err(e) = route.validate(config) {
+        return PutResponse::Status400_BadRequest(reason(e));
+    }
+    match config.routes.insert(path_params.id, route) {
+        Some(_) => PutResponse::Status200_RouteWasReplaced,
+        None => PutResponse::Status201_RouteWasCreated,
+    }
+}
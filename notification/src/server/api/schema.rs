@@ -1 +1,3 @@
+pub mod route;
 pub mod service_types;
+pub mod template;
@@ -0,0 +1,9 @@
+use crate::routing::Route;
+use notis_server::apis::routes::SchemaRouteGetResponse as GetResponse;
+use notis_server::types;
+
+pub fn get() -> GetResponse {
+    GetResponse::Status200_Success(types::Object(
+        serde_json::to_value(schemars::schema_for!(Route)).unwrap(),
+    ))
+}
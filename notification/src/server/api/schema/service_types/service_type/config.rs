@@ -8,6 +8,10 @@ pub fn get(path_params: GetPathParams) -> GetResponse {
     let schema = match path_params.service_type.as_str() {
         "log" => services::log::Config::schema(),
         "smtp" => services::smtp::Config::schema(),
+        "zulip" => services::zulip::Config::schema(),
+        "snmp" => services::snmp::Config::schema(),
+        "opcua" => services::opcua::Config::schema(),
+        "modbus" => services::modbus::Config::schema(),
         _ => return GetResponse::Status404_ServiceTypeNotFound,
     };
     GetResponse::Status200_Success(types::Object(serde_json::to_value(schema).unwrap()))
@@ -0,0 +1,9 @@
+use crate::templates::Template;
+use notis_server::apis::templates::SchemaTemplateGetResponse as GetResponse;
+use notis_server::types;
+
+pub fn get() -> GetResponse {
+    GetResponse::Status200_Success(types::Object(
+        serde_json::to_value(schemars::schema_for!(Template)).unwrap(),
+    ))
+}
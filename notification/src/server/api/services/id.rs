@@ -1,5 +1,6 @@
 pub mod config;
 pub mod notifications;
+pub mod receivers;
 
 use crate::config::Config;
 use crate::server::reason;
@@ -18,6 +19,17 @@ use notis_server::models::{
 pub fn delete(config: &mut Config, path_params: DeletePathParams) -> DeleteResponse {
     match config.notification_services.remove(&path_params.id) {
         Some(_) => {
+            for members in config.service_groups.values_mut() {
+                members.retain(|id| *id != path_params.id);
+            }
+            for members in config.failover_chains.values_mut() {
+                members.retain(|id| *id != path_params.id);
+            }
+            for route in config.routes.values_mut() {
+                route
+                    .targets
+                    .retain(|target| target.service != path_params.id);
+            }
             if config.default_notification_service == Some(path_params.id) {
                 config.default_notification_service = None;
             }
@@ -45,6 +57,18 @@ pub fn put(config: &mut Config, path_params: PutPathParams, request: PutRequest)
         services::types::LOG => {
             serde_json::from_value(request.config.0).map(NotisNotificationService::LOG)
         }
+        services::types::ZULIP => {
+            serde_json::from_value(request.config.0).map(NotisNotificationService::ZULIP)
+        }
+        services::types::SNMP => {
+            serde_json::from_value(request.config.0).map(NotisNotificationService::SNMP)
+        }
+        services::types::OPCUA => {
+            serde_json::from_value(request.config.0).map(NotisNotificationService::OPCUA)
+        }
+        services::types::MODBUS => {
+            serde_json::from_value(request.config.0).map(NotisNotificationService::MODBUS)
+        }
         t => {
             return PutResponse::Status400_BadRequest(reason(format!(
                 "Unknown notification service type '{t}'"
@@ -15,9 +15,21 @@ pub fn get(config: &crate::config::Config, path_params: GetPathParams) -> GetRes
         Some(NotisNotificationService::LOG(config)) => {
             GetResponse::Status200_Success(types::Object(serde_json::to_value(config).unwrap()))
         }
+        Some(NotisNotificationService::MODBUS(config)) => {
+            GetResponse::Status200_Success(types::Object(serde_json::to_value(config).unwrap()))
+        }
         &Some(NotisNotificationService::SMTP(config)) => GetResponse::Status200_Success(
             types::Object(serde_json::to_value(config.redacted()).unwrap()),
         ),
+        &Some(NotisNotificationService::ZULIP(config)) => GetResponse::Status200_Success(
+            types::Object(serde_json::to_value(config.redacted()).unwrap()),
+        ),
+        &Some(NotisNotificationService::SNMP(config)) => GetResponse::Status200_Success(
+            types::Object(serde_json::to_value(config.redacted()).unwrap()),
+        ),
+        &Some(NotisNotificationService::OPCUA(config)) => GetResponse::Status200_Success(
+            types::Object(serde_json::to_value(config.redacted()).unwrap()),
+        ),
         None => GetResponse::Status404_ServiceNotFound,
     }
 }
@@ -39,6 +51,30 @@ pub fn patch(
             config.apply_patch(patch);
             PatchResponse::Status200_Success
         }
+        Some(NotisNotificationService::ZULIP(config)) => {
+            let patch: crate::services::zulip::ConfigPatch =
+                serde_json::from_value(request.0).unwrap();
+            config.apply_patch(patch);
+            PatchResponse::Status200_Success
+        }
+        Some(NotisNotificationService::SNMP(config)) => {
+            let patch: crate::services::snmp::ConfigPatch =
+                serde_json::from_value(request.0).unwrap();
+            config.apply_patch(patch);
+            PatchResponse::Status200_Success
+        }
+        Some(NotisNotificationService::OPCUA(config)) => {
+            let patch: crate::services::opcua::ConfigPatch =
+                serde_json::from_value(request.0).unwrap();
+            config.apply_patch(patch);
+            PatchResponse::Status200_Success
+        }
+        Some(NotisNotificationService::MODBUS(config)) => {
+            let patch: crate::services::modbus::ConfigPatch =
+                serde_json::from_value(request.0).unwrap();
+            config.apply_patch(patch);
+            PatchResponse::Status200_Success
+        }
         None => PatchResponse::Status404_ServiceNotFound,
     }
 }
@@ -1,135 +1,95 @@
-use crate::server::reason;
-use crate::services::Attachment;
+use crate::queue::{Entry, Queue};
+use crate::server::{form, reason};
+use crate::services::{Attachment, Metadata};
+use crate::templates;
 use models::ServicesIdNotificationsPostPathParams as PostPathParams;
 use notis_server::apis::notifications::ServicesIdNotificationsPostResponse as PostResponse;
 use notis_server::models;
 use std::error::Error;
+use std::sync::RwLock;
 
 pub mod schema;
 
-const TITLE: &str = "title";
-const ATTACHMENT: &str = "attachment";
-const CONFIG: &str = "config";
-const CONTENT: &str = "content";
-
-fn to_internal_server_error(error: impl Error) -> PostResponse {
-    PostResponse::Status500_InternalServerError(models::Reason {
-        reason: Some(error.to_string()),
-    })
-}
 fn to_bad_request(error: impl Error) -> PostResponse {
-    bad_request(error.to_string())
-}
-
-fn bad_request(reason: String) -> PostResponse {
-    PostResponse::Status400_BadRequest(models::Reason {
-        reason: Some(reason),
-    })
+    PostResponse::Status400_BadRequest(reason(error))
 }
 
 pub async fn read_post_request_from_multipart(
-    mut multipart: axum_extra::extract::Multipart,
+    multipart: axum_extra::extract::Multipart,
 ) -> Result<PostRequest, PostResponse> {
-    let mut attachments = Vec::new();
-    let mut title = None;
-    let mut config = None;
-    let mut content = None;
-    while let Some(part) = multipart
-        .next_field()
-        .await
-        .map_err(to_internal_server_error)?
-    {
-        match part.name() {
-            Some(TITLE) if title.is_some() => {
-                return Err(bad_request(format!("Duplicate field {TITLE}")));
-            }
-            Some(TITLE) => {
-                title.replace(part.text().await.map_err(to_bad_request)?);
-            }
-            Some(CONTENT) if content.is_some() => {
-                return Err(bad_request(format!("Duplicate field {CONTENT}")));
-            }
-            Some(CONTENT) => {
-                content.replace(part.text().await.map_err(to_bad_request)?);
-            }
-            Some(CONFIG) if config.is_some() => {
-                return Err(bad_request(format!("Duplicate field {CONFIG}")));
-            }
-            Some(CONFIG) => {
-                config.replace(
-                    serde_json::from_str(&part.text().await.map_err(to_bad_request)?)
-                        .map_err(to_bad_request)?,
-                );
-            }
-            Some(ATTACHMENT) => {
-                let file_name = part
-                    .file_name()
-                    .ok_or_else(|| {
-                        PostResponse::Status400_BadRequest(models::Reason {
-                            reason: Some(
-                                "Missing file name in Content-Disposition header of attachment"
-                                    .to_string(),
-                            ),
-                        })
-                    })?
-                    .to_string();
-                let content_type = part
-                    .content_type()
-                    .ok_or_else(|| {
-                        PostResponse::Status400_BadRequest(models::Reason {
-                            reason: Some("Missing Content-Type header of attachment".to_string()),
-                        })
-                    })?
-                    .parse()
-                    .map_err(to_bad_request)?;
-                let file_content = part
-                    .bytes()
-                    .await
-                    .map_err(to_internal_server_error)?
-                    .to_vec();
-                attachments.push(Attachment {
-                    content_type,
-                    file_name,
-                    file_content,
-                })
-            }
-            _ => {}
-        }
-    }
+    let to_response = |error| match error {
+        form::Error::BadRequest(e) => PostResponse::Status400_BadRequest(reason(e)),
+        form::Error::Internal(e) => PostResponse::Status500_InternalServerError(reason(e)),
+    };
+    let mut form = form::read(multipart, &[]).await.map_err(to_response)?;
     Ok(PostRequest {
-        config,
-        content,
-        title: title.ok_or_else(|| {
-            PostResponse::Status400_BadRequest(models::Reason {
-                reason: Some(format!("Missing {TITLE}")),
-            })
-        })?,
-        attachments,
+        title: form.title().map_err(to_response)?,
+        config: form.config,
+        content: form.content,
+        html: form.html,
+        markdown: form.markdown.unwrap_or_default(),
+        attachments: form.attachments,
+        metadata: form.metadata,
+        template: form.template,
+        variables: form.variables.unwrap_or_default(),
     })
 }
 
 pub struct PostRequest {
     config: Option<serde_json::Value>,
     content: Option<String>,
+    html: Option<String>,
+    markdown: bool,
     title: String,
     attachments: Vec<Attachment>,
+    metadata: Metadata,
+    template: Option<String>,
+    variables: serde_json::Map<String, serde_json::Value>,
 }
 
-pub fn post(
-    config: &crate::config::Config,
+pub async fn post(
+    config: &RwLock<crate::config::Config>,
+    queue: &Queue,
     path_params: PostPathParams,
     request: PostRequest,
 ) -> Result<PostResponse, PostResponse> {
-    let Some(service) = config.notification_services.get(&path_params.id) else {
-        return Err(PostResponse::Status404_ServiceNotFound);
+    let message = {
+        let config = config.read().unwrap();
+        let Some(target) = config.target(&path_params.id) else {
+            return Err(PostResponse::Status404_ServiceNotFound);
+        };
+        if let Some(options) = &request.config {
+            target.validate_options(options).map_err(to_bad_request)?;
+        }
+        let notification = templates::Notification {
+            title: &request.title,
+            content: request.content.as_deref(),
+            html: request.html.as_deref(),
+            markdown: request.markdown,
+            metadata: &request.metadata,
+            variables: &request.variables,
+        };
+        templates::message(
+            &config,
+            request.template.as_deref(),
+            &target.type_string(),
+            &notification,
+        )
+        .map_err(to_bad_request)?
     };
-    match service.send_notification_with_raw_options(
+    let entry = Entry::new(
+        path_params.id,
+        message.title,
+        message.content,
+        message.html,
         request.config,
         request.attachments,
-        &request.title,
-        request.content.as_deref(),
-    ) {
-        Ok(_) => Ok(PostResponse::Status200_Success),
+        request.metadata,
+    );
+    match queue.enqueue(entry).await {
+        Ok(id) => Ok(PostResponse::Status202_NotificationWasQueuedForDelivery(
+            models::NotificationId { id },
+        )),
         Err(e) => Err(PostResponse::Status500_InternalServerError(reason(e))),
     }
 }
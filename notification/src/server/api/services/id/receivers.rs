@@ -0,0 +1,24 @@
+use crate::config::Config;
+use crate::server::receiver_report;
+use crate::services::smtp::bounces::Health;
+use notis_server::apis::services::ServicesIdReceiversGetResponse as GetResponse;
+use notis_server::models;
+use notis_server::models::ServicesIdReceiversGetPathParams as GetPathParams;
+
+pub fn get(config: &Config, health: &Health, path_params: GetPathParams) -> GetResponse {
+    if !config.notification_services.contains_key(&path_params.id) {
+        return GetResponse::Status404_ServiceNotFound;
+    }
+    GetResponse::Status200_Success(
+        health
+            .receivers(&path_params.id)
+            .into_iter()
+            .map(|receiver| models::ReceiverHealth {
+                address: receiver.address,
+                failures: receiver.failures.try_into().unwrap_or(i32::MAX),
+                dead: receiver.dead,
+                last_report: receiver_report(receiver.last_report),
+            })
+            .collect(),
+    )
+}
@@ -0,0 +1,20 @@
+pub mod id;
+
+use crate::config::Config;
+use notis_server::apis::templates::TemplatesGetResponse as GetResponse;
+use notis_server::types;
+
+pub fn get(config: &Config) -> GetResponse {
+    GetResponse::Status200_Success(
+        config
+            .templates
+            .iter()
+            .map(|(id, template)| {
+                (
+                    id.clone(),
+                    types::Object(serde_json::to_value(template).unwrap()),
+                )
+            })
+            .collect(),
+    )
+}
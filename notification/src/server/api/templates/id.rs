@@ -0,0 +1,48 @@
+pub mod preview;
+
+use crate::config::Config;
+use crate::server::reason;
+use crate::templates::Template;
+use notis_server::apis::templates::{
+    TemplatesIdDeleteResponse as DeleteResponse, TemplatesIdGetResponse as GetResponse,
+    TemplatesIdPutResponse as PutResponse,
+};
+use notis_server::models::{
+    TemplatesIdDeletePathParams as DeletePathParams, TemplatesIdGetPathParams as GetPathParams,
+    TemplatesIdPutPathParams as PutPathParams,
+};
+use notis_server::types;
+
+type PutRequest = types::Object;
+
+pub fn delete(config: &mut Config, path_params: DeletePathParams) -> DeleteResponse {
+    match config.templates.remove(&path_params.id) {
+        Some(_) => DeleteResponse::Status200_Success,
+        None => DeleteResponse::Status404_TemplateNotFound,
+    }
+}
+
+pub fn get(config: &Config, path_params: GetPathParams) -> GetResponse {
+    match config.templates.get(&path_params.id) {
+        Some(template) => {
+            GetResponse::Status200_Success(types::Object(serde_json::to_value(template).unwrap()))
+        }
+        None => GetResponse::Status404_TemplateNotFound,
+    }
+}
+
+pub fn put(config: &mut Config, path_params: PutPathParams, request: PutRequest) -> PutResponse {
+    let template: Template = match serde_json::from_value(request.0) {
+        Ok(template) => template,
+        Err(e) => {
+            return PutResponse::Status400_BadRequest(reason(format!("Invalid template: {e}")));
+        }
+    };
+    if let Err(e) = template.validate() {
+        return PutResponse::Status400_BadRequest(reason(e));
+    }
+    match config.templates.insert(path_params.id, template) {
+        Some(_) => PutResponse::Status200_TemplateWasReplaced,
+        None => PutResponse::Status201_TemplateWasCreated,
+    }
+}
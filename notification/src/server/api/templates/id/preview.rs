@@ -0,0 +1,52 @@
+use crate::config::Config;
+use crate::server::{reason, severity};
+use crate::services::{Metadata, types};
+use crate::templates;
+use notis_server::apis::templates::TemplatesIdPreviewPostResponse as PostResponse;
+use notis_server::models;
+use notis_server::models::{
+    TemplateSample as PostRequest, TemplatesIdPreviewPostPathParams as PostPathParams,
+};
+
+pub fn post(config: &Config, path_params: PostPathParams, request: PostRequest) -> PostResponse {
+    let Some(template) = config.templates.get(&path_params.id) else {
+        return PostResponse::Status404_TemplateNotFound;
+    };
+    if let Some(service_type) = &request.service_type
+        && !types::ALL.contains(&service_type.as_str())
+    {
+        return PostResponse::Status400_BadRequest(reason(templates::Error::UnknownServiceType(
+            service_type.clone(),
+        )));
+    }
+    let metadata = Metadata {
+        severity: request.severity.map(severity),
+        tags: request.tags.unwrap_or_default(),
+        source: request.source,
+        timestamp: request.timestamp.map(Into::into),
+        notification_id: None,
+    };
+    let variables = request
+        .variables
+        .and_then(|variables| variables.0.as_object().cloned())
+        .unwrap_or_default();
+    let notification = templates::Notification {
+        title: &request.title,
+        content: request.content.as_deref(),
+        html: request.html.as_deref(),
+        markdown: request.markdown.unwrap_or_default(),
+        metadata: &metadata,
+        variables: &variables,
+    };
+    match template.render(
+        request.service_type.as_deref().unwrap_or_default(),
+        &notification,
+    ) {
+        Ok(message) => PostResponse::Status200_Success(models::RenderedTemplate {
+            title: message.title,
+            content: message.content,
+            html: message.html,
+        }),
+        Err(e) => PostResponse::Status400_BadRequest(reason(e)),
+    }
+}
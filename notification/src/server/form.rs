@@ -0,0 +1,185 @@
+//! Multipart form of notifications with attachments
+
+use crate::services::{Attachment, Metadata};
+use std::collections::HashMap;
+
+const TITLE: &str = "title";
+const ATTACHMENT: &str = "attachment";
+const CONFIG: &str = "config";
+const CONTENT: &str = "content";
+const HTML: &str = "html";
+const MARKDOWN: &str = "markdown";
+const SEVERITY: &str = "severity";
+const TAGS: &str = "tags";
+const SOURCE: &str = "source";
+const TIMESTAMP: &str = "timestamp";
+const TEMPLATE: &str = "template";
+const VARIABLES: &str = "variables";
+
+pub enum Error {
+    BadRequest(String),
+    Internal(String),
+}
+
+fn bad_request(error: impl std::fmt::Display) -> Error {
+    Error::BadRequest(error.to_string())
+}
+
+fn internal(error: impl std::fmt::Display) -> Error {
+    Error::Internal(error.to_string())
+}
+
+#[derive(Default)]
+pub struct Form {
+    pub title: Option<String>,
+    pub content: Option<String>,
+    pub html: Option<String>,
+    /// Whether the content is Markdown
+    pub markdown: Option<bool>,
+    pub config: Option<serde_json::Value>,
+    pub attachments: Vec<Attachment>,
+    pub metadata: Metadata,
+    pub template: Option<String>,
+    pub variables: Option<serde_json::Map<String, serde_json::Value>>,
+    /// Values of the additional fields, which can be present multiple times
+    pub fields: HashMap<&'static str, Vec<String>>,
+}
+
+impl Form {
+    pub fn title(&mut self) -> Result<String, Error> {
+        self.title
+            .take()
+            .ok_or_else(|| bad_request(format!("Missing {TITLE}")))
+    }
+}
+
+/// Reads the notification fields and the text fields named in `fields`, other fields are ignored
+pub async fn read(
+    mut multipart: axum_extra::extract::Multipart,
+    fields: &[&'static str],
+) -> Result<Form, Error> {
+    let mut form = Form::default();
+    while let Some(part) = multipart.next_field().await.map_err(internal)? {
+        match part.name() {
+            Some(TITLE) if form.title.is_some() => {
+                return Err(bad_request(format!("Duplicate field {TITLE}")));
+            }
+            Some(TITLE) => {
+                form.title.replace(part.text().await.map_err(bad_request)?);
+            }
+            Some(CONTENT) if form.content.is_some() => {
+                return Err(bad_request(format!("Duplicate field {CONTENT}")));
+            }
+            Some(CONTENT) => {
+                form.content
+                    .replace(part.text().await.map_err(bad_request)?);
+            }
+            Some(HTML) if form.html.is_some() => {
+                return Err(bad_request(format!("Duplicate field {HTML}")));
+            }
+            Some(HTML) => {
+                form.html.replace(part.text().await.map_err(bad_request)?);
+            }
+            Some(MARKDOWN) if form.markdown.is_some() => {
+                return Err(bad_request(format!("Duplicate field {MARKDOWN}")));
+            }
+            Some(MARKDOWN) => {
+                form.markdown.replace(
+                    part.text()
+                        .await
+                        .map_err(bad_request)?
+                        .parse()
+                        .map_err(bad_request)?,
+                );
+            }
+            Some(CONFIG) if form.config.is_some() => {
+                return Err(bad_request(format!("Duplicate field {CONFIG}")));
+            }
+            Some(CONFIG) => {
+                form.config.replace(
+                    serde_json::from_str(&part.text().await.map_err(bad_request)?)
+                        .map_err(bad_request)?,
+                );
+            }
+            Some(SEVERITY) if form.metadata.severity.is_some() => {
+                return Err(bad_request(format!("Duplicate field {SEVERITY}")));
+            }
+            Some(SEVERITY) => {
+                let severity = part.text().await.map_err(bad_request)?;
+                form.metadata.severity.replace(
+                    serde_json::from_value(serde_json::Value::String(severity))
+                        .map_err(bad_request)?,
+                );
+            }
+            Some(TAGS) if !form.metadata.tags.is_empty() => {
+                return Err(bad_request(format!("Duplicate field {TAGS}")));
+            }
+            Some(TAGS) => {
+                form.metadata.tags = serde_json::from_str(&part.text().await.map_err(bad_request)?)
+                    .map_err(bad_request)?;
+            }
+            Some(SOURCE) if form.metadata.source.is_some() => {
+                return Err(bad_request(format!("Duplicate field {SOURCE}")));
+            }
+            Some(SOURCE) => {
+                form.metadata
+                    .source
+                    .replace(part.text().await.map_err(bad_request)?);
+            }
+            Some(TIMESTAMP) if form.metadata.timestamp.is_some() => {
+                return Err(bad_request(format!("Duplicate field {TIMESTAMP}")));
+            }
+            Some(TIMESTAMP) => {
+                let timestamp = part.text().await.map_err(bad_request)?;
+                form.metadata.timestamp.replace(
+                    chrono::DateTime::parse_from_rfc3339(&timestamp)
+                        .map_err(bad_request)?
+                        .into(),
+                );
+            }
+            Some(TEMPLATE) if form.template.is_some() => {
+                return Err(bad_request(format!("Duplicate field {TEMPLATE}")));
+            }
+            Some(TEMPLATE) => {
+                form.template
+                    .replace(part.text().await.map_err(bad_request)?);
+            }
+            Some(VARIABLES) if form.variables.is_some() => {
+                return Err(bad_request(format!("Duplicate field {VARIABLES}")));
+            }
+            Some(VARIABLES) => {
+                form.variables.replace(
+                    serde_json::from_str(&part.text().await.map_err(bad_request)?)
+                        .map_err(bad_request)?,
+                );
+            }
+            Some(ATTACHMENT) => {
+                let file_name = part
+                    .file_name()
+                    .ok_or_else(|| {
+                        bad_request("Missing file name in Content-Disposition header of attachment")
+                    })?
+                    .to_string();
+                let content_type = part
+                    .content_type()
+                    .ok_or_else(|| bad_request("Missing Content-Type header of attachment"))?
+                    .parse()
+                    .map_err(bad_request)?;
+                let file_content = part.bytes().await.map_err(internal)?.to_vec();
+                form.attachments.push(Attachment {
+                    content_type,
+                    file_name,
+                    file_content,
+                })
+            }
+            Some(name) => {
+                if let Some(field) = fields.iter().find(|field| **field == name) {
+                    let value = part.text().await.map_err(bad_request)?;
+                    form.fields.entry(field).or_default().push(value);
+                }
+            }
+            None => {}
+        }
+    }
+    Ok(form)
+}
@@ -1,45 +1,214 @@
 use crate::config::NotificationServiceConfig;
 use crate::services::log::Logger;
+use crate::services::modbus::Modbus;
+use crate::services::opcua::OpcUa;
 use crate::services::smtp::MailServer;
+use crate::services::snmp::Snmp;
+use crate::services::zulip::Zulip;
 use schemars::schema_for;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::SystemTime;
+use uuid::Uuid;
 
 pub mod log;
+pub mod modbus;
+pub mod opcua;
 pub mod smtp;
+pub mod snmp;
+pub mod zulip;
 
+#[serde_with::serde_as]
+#[derive(Clone, Deserialize, Serialize)]
 pub struct Attachment {
     pub file_name: String,
     pub content_type: lettre::message::header::ContentType,
+    #[serde_as(as = "serde_with::base64::Base64")]
     pub file_content: Vec<u8>,
 }
 
-pub trait NotificationService {
-    type Config: NotificationServiceConfig;
-    type NotificationOptions: schemars::JsonSchema + DeserializeOwned;
+/// Severity of a notification
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Critical,
+    Error,
+    Warning,
+    Info,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Critical => "critical",
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::Info => "info",
+        }
+    }
+}
+
+/// Standard fields of a notification which let receivers triage it
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct Metadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub tags: HashMap<String, String>,
+    /// Name of the app or instance which sent the notification
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// Time of the event the notification is about
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<SystemTime>,
+    /// Id of the queued notification while it is delivered, lets services correlate later
+    /// responses like delivery status notifications of mails
+    #[serde(skip)]
+    pub notification_id: Option<Uuid>,
+}
+
+impl Metadata {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Title prefixed with the severity, e.g. `[CRITICAL] Pump 3 stopped`
+    pub fn prefixed_title(&self, title: &str) -> String {
+        match self.severity {
+            Some(severity) => format!("[{}] {title}", severity.as_str().to_uppercase()),
+            None => title.to_string(),
+        }
+    }
+
+    /// Tags as `key=value` pairs sorted by key
+    pub fn tag_list(&self) -> Vec<String> {
+        let mut tags: Vec<_> = self
+            .tags
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect();
+        tags.sort();
+        tags
+    }
+}
+
+impl Attachment {
+    pub fn mime_type(&self) -> String {
+        serde_json::to_value(&self.content_type)
+            .ok()
+            .and_then(|value| value.as_str().map(str::to_string))
+            .unwrap_or_default()
+    }
+}
+
+/// Receiver which the server of a service refused to deliver the notification to
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RejectedReceiver {
+    pub address: String,
+    /// Reply code of the server, e.g. 550
+    pub code: u16,
+    pub message: String,
+}
+
+impl RejectedReceiver {
+    /// Whether the server may accept the receiver later, e.g. once its mailbox is no longer full
+    pub fn is_transient(&self) -> bool {
+        (400..500).contains(&self.code)
+    }
+}
+
+impl std::fmt::Display for RejectedReceiver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({} {})", self.address, self.code, self.message)
+    }
+}
+
+/// Delivery state of a receiver reported after the service accepted the notification
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReceiverState {
+    Delivered,
+    Delayed,
+    Failed,
+}
+
+/// Report of the delivery to a receiver, e.g. from a delivery status notification of a mail
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ReceiverReport {
+    pub address: String,
+    pub state: ReceiverState,
+    /// Status code of the report, e.g. 5.1.1
+    pub status: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diagnostic: Option<String>,
+    /// Time the report was received
+    pub time: SystemTime,
+}
+
+/// Runs blocking i/o of a service on the blocking thread pool, so it does not stall the runtime
+async fn run_blocking<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> T {
+    let span = tracing::Span::current();
+    tokio::task::spawn_blocking(move || span.in_scope(f))
+        .await
+        .unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic()))
+}
+
+pub trait NotificationService: Sync {
+    type Config: NotificationServiceConfig + Sync;
+    type NotificationOptions: schemars::JsonSchema + DeserializeOwned + Send;
     fn notification_schema() -> schemars::Schema {
         schema_for!(Self::NotificationOptions)
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn send_notification(
         &self,
         options: Option<Self::NotificationOptions>,
         config: &Self::Config,
+        service_id: &str,
         title: &str,
         attachments: Vec<Attachment>,
         content: Option<&str>,
-    ) -> Result<(), crate::Error>;
+        html: Option<&str>,
+        metadata: &Metadata,
+    ) -> impl Future<Output = Result<(), crate::Error>> + Send;
 
+    /// Addresses which receive a notification sent with the options, recorded in the history
+    fn receivers(
+        &self,
+        _options: Option<&Self::NotificationOptions>,
+        _config: &Self::Config,
+    ) -> Vec<String> {
+        Vec::new()
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn send_notification_with_raw_options(
         &self,
         options: Option<serde_json::Value>,
         config: &Self::Config,
+        service_id: &str,
         attachments: Vec<Attachment>,
         title: &str,
         content: Option<&str>,
-    ) -> Result<(), crate::Error> {
-        let options = options.map(serde_json::from_value).transpose()?;
-        self.send_notification(options, config, title, attachments, content)
+        html: Option<&str>,
+        metadata: &Metadata,
+    ) -> impl Future<Output = Result<(), crate::Error>> + Send {
+        async move {
+            let options = options.map(serde_json::from_value).transpose()?;
+            self.send_notification(
+                options,
+                config,
+                service_id,
+                title,
+                attachments,
+                content,
+                html,
+                metadata,
+            )
+            .await
+        }
     }
 }
 
@@ -48,47 +217,242 @@ impl NotisNotificationService {
         match self {
             Self::LOG(_) => types::LOG,
             Self::SMTP(_) => types::SMTP,
+            Self::ZULIP(_) => types::ZULIP,
+            Self::SNMP(_) => types::SNMP,
+            Self::OPCUA(_) => types::OPCUA,
+            Self::MODBUS(_) => types::MODBUS,
         }
         .to_string()
     }
 
-    pub fn send_notification_with_raw_options(
+    #[allow(clippy::too_many_arguments)]
+    pub async fn send_notification_with_raw_options(
         &self,
+        id: &str,
         options: Option<serde_json::Value>,
         attachments: Vec<Attachment>,
         title: &str,
         content: Option<&str>,
+        html: Option<&str>,
+        metadata: &Metadata,
     ) -> Result<(), crate::Error> {
         match self {
-            Self::SMTP(config) => MailServer.send_notification_with_raw_options(
-                options,
-                config,
-                attachments,
-                title,
-                content,
-            ),
-            Self::LOG(config) => Logger.send_notification_with_raw_options(
-                options,
-                config,
-                attachments,
-                title,
-                content,
-            ),
+            Self::SMTP(config) => {
+                MailServer
+                    .send_notification_with_raw_options(
+                        options,
+                        config,
+                        id,
+                        attachments,
+                        title,
+                        content,
+                        html,
+                        metadata,
+                    )
+                    .await
+            }
+            Self::LOG(config) => {
+                Logger
+                    .send_notification_with_raw_options(
+                        options,
+                        config,
+                        id,
+                        attachments,
+                        title,
+                        content,
+                        html,
+                        metadata,
+                    )
+                    .await
+            }
+            Self::ZULIP(config) => {
+                Zulip
+                    .send_notification_with_raw_options(
+                        options,
+                        config,
+                        id,
+                        attachments,
+                        title,
+                        content,
+                        html,
+                        metadata,
+                    )
+                    .await
+            }
+            Self::SNMP(config) => {
+                Snmp.send_notification_with_raw_options(
+                    options,
+                    config,
+                    id,
+                    attachments,
+                    title,
+                    content,
+                    html,
+                    metadata,
+                )
+                .await
+            }
+            Self::OPCUA(config) => {
+                OpcUa
+                    .send_notification_with_raw_options(
+                        options,
+                        config,
+                        id,
+                        attachments,
+                        title,
+                        content,
+                        html,
+                        metadata,
+                    )
+                    .await
+            }
+            Self::MODBUS(config) => {
+                Modbus
+                    .send_notification_with_raw_options(
+                        options,
+                        config,
+                        id,
+                        attachments,
+                        title,
+                        content,
+                        html,
+                        metadata,
+                    )
+                    .await
+            }
+        }
+    }
+
+    /// Checks that the options can be used for notifications of this service
+    pub fn validate_options(&self, options: &serde_json::Value) -> Result<(), serde_json::Error> {
+        fn validate<S: NotificationService>(
+            options: &serde_json::Value,
+        ) -> Result<(), serde_json::Error> {
+            S::NotificationOptions::deserialize(options).map(drop)
+        }
+        match self {
+            Self::SMTP(_) => validate::<MailServer>(options),
+            Self::LOG(_) => validate::<Logger>(options),
+            Self::ZULIP(_) => validate::<Zulip>(options),
+            Self::SNMP(_) => validate::<Snmp>(options),
+            Self::OPCUA(_) => validate::<OpcUa>(options),
+            Self::MODBUS(_) => validate::<Modbus>(options),
         }
     }
 
-    pub fn send_notification(
+    /// Receivers of a notification sent with the options, options which are invalid are ignored
+    pub fn receivers(&self, options: Option<&serde_json::Value>) -> Vec<String> {
+        fn receivers<S: NotificationService>(
+            service: S,
+            options: Option<&serde_json::Value>,
+            config: &S::Config,
+        ) -> Vec<String> {
+            let options =
+                options.and_then(|options| S::NotificationOptions::deserialize(options).ok());
+            service.receivers(options.as_ref(), config)
+        }
+        match self {
+            Self::SMTP(config) => receivers(MailServer, options, config),
+            Self::LOG(config) => receivers(Logger, options, config),
+            Self::ZULIP(config) => receivers(Zulip, options, config),
+            Self::SNMP(config) => receivers(Snmp, options, config),
+            Self::OPCUA(config) => receivers(OpcUa, options, config),
+            Self::MODBUS(config) => receivers(Modbus, options, config),
+        }
+    }
+
+    pub async fn send_notification(
         &self,
+        id: &str,
         title: &str,
         content: Option<&str>,
+        html: Option<&str>,
         attachments: Vec<Attachment>,
+        metadata: &Metadata,
     ) -> Result<(), crate::Error> {
         match self {
             Self::SMTP(config) => {
-                MailServer.send_notification(None, config, title, attachments, content)
+                MailServer
+                    .send_notification(
+                        None,
+                        config,
+                        id,
+                        title,
+                        attachments,
+                        content,
+                        html,
+                        metadata,
+                    )
+                    .await
             }
             Self::LOG(config) => {
-                Logger.send_notification(None, config, title, attachments, content)
+                Logger
+                    .send_notification(
+                        None,
+                        config,
+                        id,
+                        title,
+                        attachments,
+                        content,
+                        html,
+                        metadata,
+                    )
+                    .await
+            }
+            Self::ZULIP(config) => {
+                Zulip
+                    .send_notification(
+                        None,
+                        config,
+                        id,
+                        title,
+                        attachments,
+                        content,
+                        html,
+                        metadata,
+                    )
+                    .await
+            }
+            Self::SNMP(config) => {
+                Snmp.send_notification(
+                    None,
+                    config,
+                    id,
+                    title,
+                    attachments,
+                    content,
+                    html,
+                    metadata,
+                )
+                .await
+            }
+            Self::OPCUA(config) => {
+                OpcUa
+                    .send_notification(
+                        None,
+                        config,
+                        id,
+                        title,
+                        attachments,
+                        content,
+                        html,
+                        metadata,
+                    )
+                    .await
+            }
+            Self::MODBUS(config) => {
+                Modbus
+                    .send_notification(
+                        None,
+                        config,
+                        id,
+                        title,
+                        attachments,
+                        content,
+                        html,
+                        metadata,
+                    )
+                    .await
             }
         }
     }
@@ -97,6 +461,10 @@ impl NotisNotificationService {
         match self {
             Self::SMTP(_) => <MailServer as NotificationService>::Config::schema(),
             Self::LOG(_) => <Logger as NotificationService>::Config::schema(),
+            Self::ZULIP(_) => <Zulip as NotificationService>::Config::schema(),
+            Self::SNMP(_) => <Snmp as NotificationService>::Config::schema(),
+            Self::OPCUA(_) => <OpcUa as NotificationService>::Config::schema(),
+            Self::MODBUS(_) => <Modbus as NotificationService>::Config::schema(),
         }
     }
 
@@ -104,6 +472,10 @@ impl NotisNotificationService {
         match self {
             Self::SMTP(_) => <MailServer as NotificationService>::notification_schema(),
             Self::LOG(_) => <Logger as NotificationService>::notification_schema(),
+            Self::ZULIP(_) => <Zulip as NotificationService>::notification_schema(),
+            Self::SNMP(_) => <Snmp as NotificationService>::notification_schema(),
+            Self::OPCUA(_) => <OpcUa as NotificationService>::notification_schema(),
+            Self::MODBUS(_) => <Modbus as NotificationService>::notification_schema(),
         }
     }
 
@@ -111,6 +483,10 @@ impl NotisNotificationService {
         match self {
             Self::SMTP(_) => <MailServer as NotificationService>::Config::patch_schema(),
             Self::LOG(_) => <Logger as NotificationService>::Config::patch_schema(),
+            Self::ZULIP(_) => <Zulip as NotificationService>::Config::patch_schema(),
+            Self::SNMP(_) => <Snmp as NotificationService>::Config::patch_schema(),
+            Self::OPCUA(_) => <OpcUa as NotificationService>::Config::patch_schema(),
+            Self::MODBUS(_) => <Modbus as NotificationService>::Config::patch_schema(),
         }
     }
 }
@@ -118,6 +494,12 @@ impl NotisNotificationService {
 pub mod types {
     pub const SMTP: &str = "smtp";
     pub const LOG: &str = "log";
+    pub const ZULIP: &str = "zulip";
+    pub const SNMP: &str = "snmp";
+    pub const OPCUA: &str = "opcua";
+    pub const MODBUS: &str = "modbus";
+
+    pub const ALL: [&str; 6] = [SMTP, LOG, ZULIP, SNMP, OPCUA, MODBUS];
 }
 
 #[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
@@ -125,4 +507,8 @@ pub mod types {
 pub enum NotisNotificationService {
     SMTP(Box<smtp::Config>),
     LOG(Box<log::Config>),
+    ZULIP(Box<zulip::Config>),
+    SNMP(Box<snmp::Config>),
+    OPCUA(Box<opcua::Config>),
+    MODBUS(Box<modbus::Config>),
 }
@@ -1,30 +1,52 @@
 mod config;
 
 use crate::Error;
-use crate::services::{Attachment, NotificationService};
+use crate::services::{Attachment, Metadata, NotificationService, Severity};
 pub use config::Config;
 use tracing::log::log;
 
 #[derive(Default)]
 pub struct Logger;
 
+fn level(severity: Severity) -> tracing::log::Level {
+    match severity {
+        Severity::Critical | Severity::Error => tracing::log::Level::Error,
+        Severity::Warning => tracing::log::Level::Warn,
+        Severity::Info => tracing::log::Level::Info,
+    }
+}
+
 impl NotificationService for Logger {
     type Config = Config;
     type NotificationOptions = Config;
 
-    fn send_notification(
+    async fn send_notification(
         &self,
         options: Option<Self::NotificationOptions>,
         config: &Self::Config,
+        _service_id: &str,
         title: &str,
         attachments: Vec<Attachment>,
         content: Option<&str>,
+        _html: Option<&str>,
+        metadata: &Metadata,
     ) -> Result<(), Error> {
-        let level = options.map(|config| config.level).unwrap_or(config.level);
+        let level = match (options, metadata.severity) {
+            (Some(options), _) => options.level,
+            (None, Some(severity)) => level(severity),
+            (None, None) => config.level,
+        };
+        let title = match &metadata.source {
+            Some(source) => format!("[{source}] {title}"),
+            None => title.to_string(),
+        };
         match content {
             Some(content) => log!(level, "{title}: {content}"),
             None => log!(level, "{title}"),
         }
+        if !metadata.tags.is_empty() {
+            log!(level, "Tags: {}", metadata.tag_list().join(", "));
+        }
         for attachment in attachments {
             log!(
                 level,
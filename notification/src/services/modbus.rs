@@ -0,0 +1,382 @@
+mod config;
+
+use crate::services::{Attachment, Metadata, NotificationService, run_blocking};
+pub use config::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{Read, Write as _};
+use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+use tracing::{error, info, info_span};
+
+const DEFAULT_PORT: u16 = 502;
+const PROTOCOL_ID: u16 = 0;
+const MBAP_HEADER_LENGTH: usize = 7;
+const MAX_PDU_LENGTH: usize = 253;
+const WRITE_SINGLE_COIL: u8 = 0x05;
+const WRITE_SINGLE_REGISTER: u8 = 0x06;
+const EXCEPTION_FLAG: u8 = 0x80;
+const COIL_ON: u16 = 0xff00;
+const COIL_OFF: u16 = 0x0000;
+
+/// Number of signals per modbus server and unit, used to only reset the latest signal
+static SIGNAL_GENERATIONS: LazyLock<Mutex<HashMap<String, u64>>> = LazyLock::new(Default::default);
+
+#[derive(Default)]
+pub struct Modbus;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    IO(#[from] std::io::Error),
+    #[error("Could not resolve modbus server {address}")]
+    UnknownServer { address: String },
+    #[error("The modbus server answered function {function:#04x} with exception code {code}")]
+    Exception { function: u8, code: u8 },
+    #[error("Invalid response of the modbus server to function {function:#04x}")]
+    InvalidResponse { function: u8 },
+}
+
+impl Error {
+    /// Errors which will not resolve by retrying, i.e. the exceptions for an illegal function,
+    /// data address or data value
+    pub fn is_permanent(&self) -> bool {
+        match self {
+            Self::Exception { code, .. } => (1..=3).contains(code),
+            Self::IO(_) | Self::UnknownServer { .. } | Self::InvalidResponse { .. } => false,
+        }
+    }
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+pub struct NotificationOptions {
+    /// Code written into registers without fixed value, e.g. an error code
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    code: Option<u16>,
+    /// Overrides the reset duration of the service
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    reset_after_secs: Option<u64>,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Signal {
+    Coil { address: u16, value: bool },
+    Register { address: u16, value: u16 },
+}
+
+impl Signal {
+    fn new(write: &Write, code: u16) -> Self {
+        match *write {
+            Write::Coil { address, value } => Self::Coil { address, value },
+            Write::Register { address, value } => Self::Register {
+                address,
+                value: value.unwrap_or(code),
+            },
+        }
+    }
+
+    fn reset(self) -> Self {
+        match self {
+            Self::Coil { address, value } => Self::Coil {
+                address,
+                value: !value,
+            },
+            Self::Register { address, .. } => Self::Register { address, value: 0 },
+        }
+    }
+
+    fn request(self) -> (u8, u16, u16) {
+        match self {
+            Self::Coil { address, value } => (
+                WRITE_SINGLE_COIL,
+                address,
+                if value { COIL_ON } else { COIL_OFF },
+            ),
+            Self::Register { address, value } => (WRITE_SINGLE_REGISTER, address, value),
+        }
+    }
+}
+
+fn resolve(address: &str) -> Result<SocketAddr, Error> {
+    address
+        .to_socket_addrs()
+        .or_else(|_| (address, DEFAULT_PORT).to_socket_addrs())
+        .ok()
+        .and_then(|mut addresses| addresses.next())
+        .ok_or_else(|| Error::UnknownServer {
+            address: address.to_string(),
+        })
+}
+
+/// Modbus TCP connection to a single unit of a server
+struct Connection {
+    stream: TcpStream,
+    unit_id: u8,
+    transaction_id: u16,
+}
+
+impl Connection {
+    fn open(config: &Config) -> Result<Self, Error> {
+        let timeout = Duration::from_secs(config.timeout_secs.max(1));
+        let stream = TcpStream::connect_timeout(&resolve(&config.address)?, timeout)?;
+        stream.set_read_timeout(Some(timeout))?;
+        stream.set_write_timeout(Some(timeout))?;
+        stream.set_nodelay(true)?;
+        Ok(Self {
+            stream,
+            unit_id: config.unit_id,
+            transaction_id: 0,
+        })
+    }
+
+    /// Sends a request and checks that the server echoes it, as it does for all single writes
+    fn write(&mut self, signal: Signal) -> Result<(), Error> {
+        let (function, address, value) = signal.request();
+        let mut pdu = vec![function];
+        pdu.extend_from_slice(&address.to_be_bytes());
+        pdu.extend_from_slice(&value.to_be_bytes());
+
+        self.transaction_id = self.transaction_id.wrapping_add(1);
+        let mut request = Vec::with_capacity(MBAP_HEADER_LENGTH + pdu.len());
+        request.extend_from_slice(&self.transaction_id.to_be_bytes());
+        request.extend_from_slice(&PROTOCOL_ID.to_be_bytes());
+        request.extend_from_slice(&(pdu.len() as u16 + 1).to_be_bytes());
+        request.push(self.unit_id);
+        request.extend_from_slice(&pdu);
+        self.stream.write_all(&request)?;
+
+        let mut header = [0u8; MBAP_HEADER_LENGTH];
+        self.stream.read_exact(&mut header)?;
+        let transaction_id = u16::from_be_bytes([header[0], header[1]]);
+        let protocol_id = u16::from_be_bytes([header[2], header[3]]);
+        let length = u16::from_be_bytes([header[4], header[5]]) as usize;
+        if transaction_id != self.transaction_id
+            || protocol_id != PROTOCOL_ID
+            || !(2..=MAX_PDU_LENGTH + 1).contains(&length)
+        {
+            return Err(Error::InvalidResponse { function });
+        }
+        let mut response = vec![0u8; length - 1];
+        self.stream.read_exact(&mut response)?;
+        if response[0] == function | EXCEPTION_FLAG {
+            return Err(Error::Exception {
+                function,
+                code: response.get(1).copied().unwrap_or_default(),
+            });
+        }
+        if response != pdu {
+            return Err(Error::InvalidResponse { function });
+        }
+        Ok(())
+    }
+}
+
+impl NotificationService for Modbus {
+    type Config = Config;
+    type NotificationOptions = NotificationOptions;
+
+    async fn send_notification(
+        &self,
+        options: Option<Self::NotificationOptions>,
+        config: &Self::Config,
+        _service_id: &str,
+        _title: &str,
+        _attachments: Vec<Attachment>,
+        _content: Option<&str>,
+        _html: Option<&str>,
+        _metadata: &Metadata,
+    ) -> Result<(), crate::Error> {
+        let (code, reset_after_secs) = match options {
+            Some(options) => (
+                options.code,
+                options.reset_after_secs.or(config.reset_after_secs),
+            ),
+            None => (None, config.reset_after_secs),
+        };
+        let config = config.clone();
+        run_blocking(move || {
+            Modbus.send(
+                &config,
+                code.unwrap_or(config.default_code),
+                reset_after_secs.map(Duration::from_secs),
+            )
+        })
+        .await?;
+        Ok(())
+    }
+}
+
+impl Modbus {
+    fn send(&self, config: &Config, code: u16, reset_after: Option<Duration>) -> Result<(), Error> {
+        let _span = info_span!("send_modbus_notification", address = config.address).entered();
+        let signals: Vec<_> = config
+            .writes
+            .iter()
+            .map(|write| Signal::new(write, code))
+            .collect();
+        let key = format!("{}/{}", config.address, config.unit_id);
+        let generation = {
+            let mut generations = SIGNAL_GENERATIONS.lock().unwrap();
+            let generation = generations.entry(key.clone()).or_default();
+            *generation += 1;
+            *generation
+        };
+        info!("Writing {} signals...", signals.len());
+        Self::write(config, &signals)?;
+        info!("... Ok");
+        if let Some(reset_after) = reset_after {
+            let config = config.clone();
+            let resets: Vec<_> = signals.into_iter().map(Signal::reset).collect();
+            std::thread::spawn(move || {
+                std::thread::sleep(reset_after);
+                let _span =
+                    info_span!("reset_modbus_notification", address = config.address).entered();
+                if SIGNAL_GENERATIONS.lock().unwrap().get(&key) != Some(&generation) {
+                    info!("Skipping reset, a newer notification was signalled");
+                    return;
+                }
+                info!("Resetting {} signals...", resets.len());
+                match Self::write(&config, &resets) {
+                    Ok(()) => info!("... Ok"),
+                    Err(e) => error!("Could not reset signals: {e}"),
+                }
+            });
+        }
+        Ok(())
+    }
+
+    fn write(config: &Config, signals: &[Signal]) -> Result<(), Error> {
+        let mut connection = Connection::open(config)?;
+        for signal in signals {
+            connection.write(*signal)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::sync::Arc;
+
+    const SIZE: usize = 64;
+    const ILLEGAL_DATA_ADDRESS: u8 = 0x02;
+
+    #[derive(Default)]
+    struct Memory {
+        coils: Vec<bool>,
+        registers: Vec<u16>,
+    }
+
+    /// Minimal modbus tcp server supporting single writes of coils and registers
+    fn server() -> (String, Arc<Mutex<Memory>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let memory = Arc::new(Mutex::new(Memory {
+            coils: vec![false; SIZE],
+            registers: vec![0; SIZE],
+        }));
+        let server_memory = memory.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let memory = server_memory.clone();
+                std::thread::spawn(move || {
+                    let mut header = [0u8; MBAP_HEADER_LENGTH];
+                    while stream.read_exact(&mut header).is_ok() {
+                        let length = u16::from_be_bytes([header[4], header[5]]) as usize;
+                        let mut pdu = vec![0u8; length - 1];
+                        stream.read_exact(&mut pdu).unwrap();
+                        let address = u16::from_be_bytes([pdu[1], pdu[2]]) as usize;
+                        let value = u16::from_be_bytes([pdu[3], pdu[4]]);
+                        let response = if address >= SIZE {
+                            vec![pdu[0] | EXCEPTION_FLAG, ILLEGAL_DATA_ADDRESS]
+                        } else {
+                            let mut memory = memory.lock().unwrap();
+                            match pdu[0] {
+                                WRITE_SINGLE_COIL => memory.coils[address] = value == COIL_ON,
+                                WRITE_SINGLE_REGISTER => memory.registers[address] = value,
+                                _ => unreachable!(),
+                            }
+                            pdu
+                        };
+                        let mut reply = header[..4].to_vec();
+                        reply.extend_from_slice(&(response.len() as u16 + 1).to_be_bytes());
+                        reply.push(header[6]);
+                        reply.extend_from_slice(&response);
+                        stream.write_all(&reply).unwrap();
+                    }
+                });
+            }
+        });
+        (address, memory)
+    }
+
+    fn config(address: String) -> Config {
+        Config {
+            address,
+            writes: vec![
+                Write::Coil {
+                    address: 16,
+                    value: true,
+                },
+                Write::Register {
+                    address: 3,
+                    value: Some(7),
+                },
+                Write::Register {
+                    address: 4,
+                    value: None,
+                },
+            ],
+            reset_after_secs: None,
+            ..Config::example()
+        }
+    }
+
+    #[test]
+    fn write() {
+        let (address, memory) = server();
+        Modbus.send(&config(address), 42, None).unwrap();
+        let memory = memory.lock().unwrap();
+        assert!(memory.coils[16]);
+        assert_eq!(memory.registers[3], 7);
+        assert_eq!(memory.registers[4], 42);
+    }
+
+    #[test]
+    fn reset() {
+        let (address, memory) = server();
+        Modbus
+            .send(&config(address), 42, Some(Duration::from_millis(200)))
+            .unwrap();
+        assert!(memory.lock().unwrap().coils[16]);
+        std::thread::sleep(Duration::from_millis(1000));
+        let memory = memory.lock().unwrap();
+        assert!(!memory.coils[16]);
+        assert_eq!(memory.registers[3], 0);
+        assert_eq!(memory.registers[4], 0);
+    }
+
+    #[test]
+    fn exception() {
+        let (address, _) = server();
+        let config = Config {
+            writes: vec![Write::Coil {
+                address: SIZE as u16,
+                value: true,
+            }],
+            ..config(address)
+        };
+        assert!(matches!(
+            Modbus.send(&config, 1, None),
+            Err(Error::Exception {
+                function: WRITE_SINGLE_COIL,
+                code: ILLEGAL_DATA_ADDRESS
+            })
+        ));
+    }
+}
@@ -0,0 +1,110 @@
+mod patch;
+
+use crate::config::NotificationServiceConfig;
+pub use patch::ConfigPatch;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_UNIT_ID: u8 = 1;
+const DEFAULT_CODE: u16 = 1;
+const DEFAULT_TIMEOUT_SECS: u64 = 5;
+
+fn default_unit_id() -> u8 {
+    DEFAULT_UNIT_ID
+}
+
+fn default_code() -> u16 {
+    DEFAULT_CODE
+}
+
+fn default_timeout_secs() -> u64 {
+    DEFAULT_TIMEOUT_SECS
+}
+
+fn default_coil_value() -> bool {
+    true
+}
+
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(tag = "kind")]
+pub enum Write {
+    /// Writes a single coil (function code 05), e.g. to set an alarm bit
+    Coil {
+        address: u16,
+        #[serde(default = "default_coil_value")]
+        value: bool,
+    },
+    /// Writes a single holding register (function code 06)
+    Register {
+        address: u16,
+        /// Fixed value of the register, if omitted the `code` of the notification is written
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        value: Option<u16>,
+    },
+}
+
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct Config {
+    /// Address of the modbus server (slave) as host:port, the port defaults to 502
+    pub address: String,
+    #[serde(default = "default_unit_id")]
+    pub unit_id: u8,
+    /// Writes executed in the given order for each notification
+    pub writes: Vec<Write>,
+    /// Code written into registers without fixed value, if the notification specifies none
+    #[serde(default = "default_code")]
+    pub default_code: u16,
+    /// Seconds after which coils are reset to the opposite value and registers to 0
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reset_after_secs: Option<u64>,
+    /// Seconds to wait for the connection and each response
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+impl Config {
+    pub fn example() -> Self {
+        Self {
+            address: "plc.example.com:502".to_string(),
+            unit_id: DEFAULT_UNIT_ID,
+            writes: vec![
+                Write::Coil {
+                    address: 16,
+                    value: true,
+                },
+                Write::Register {
+                    address: 100,
+                    value: None,
+                },
+            ],
+            default_code: DEFAULT_CODE,
+            reset_after_secs: Some(30),
+            timeout_secs: DEFAULT_TIMEOUT_SECS,
+        }
+    }
+}
+
+impl NotificationServiceConfig for Config {
+    type Patch = ConfigPatch;
+
+    fn apply_patch(&mut self, patch: ConfigPatch) {
+        if let Some(address) = patch.address {
+            self.address = address;
+        }
+        if let Some(unit_id) = patch.unit_id {
+            self.unit_id = unit_id;
+        }
+        if let Some(writes) = patch.writes {
+            self.writes = writes;
+        }
+        if let Some(default_code) = patch.default_code {
+            self.default_code = default_code;
+        }
+        if let Some(reset_after_secs) = patch.reset_after_secs {
+            self.reset_after_secs = reset_after_secs;
+        }
+        if let Some(timeout_secs) = patch.timeout_secs {
+            self.timeout_secs = timeout_secs;
+        }
+    }
+}
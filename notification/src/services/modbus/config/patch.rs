@@ -0,0 +1,65 @@
+use crate::services::modbus::Write;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, JsonSchema)]
+pub struct ConfigPatch {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit_id: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub writes: Option<Vec<Write>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_code: Option<u16>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "::serde_with::rust::double_option"
+    )]
+    #[schemars(with = "Option<Option<u64>>")]
+    pub reset_after_secs: Option<Option<u64>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde() {
+        let s = r#"{}"#;
+        let p = ConfigPatch {
+            address: None,
+            unit_id: None,
+            writes: None,
+            default_code: None,
+            reset_after_secs: None,
+            timeout_secs: None,
+        };
+        assert_eq!(p, serde_json::from_str(s).unwrap());
+        assert_eq!(s, serde_json::to_string(&p).unwrap());
+
+        let s = r#"{"writes":[{"kind":"Coil","address":3,"value":false},{"kind":"Register","address":7}],"reset_after_secs":null}"#;
+        let p = ConfigPatch {
+            address: None,
+            unit_id: None,
+            writes: Some(vec![
+                Write::Coil {
+                    address: 3,
+                    value: false,
+                },
+                Write::Register {
+                    address: 7,
+                    value: None,
+                },
+            ]),
+            default_code: None,
+            reset_after_secs: Some(None),
+            timeout_secs: None,
+        };
+        assert_eq!(p, serde_json::from_str(s).unwrap());
+        assert_eq!(s, serde_json::to_string(&p).unwrap());
+    }
+}
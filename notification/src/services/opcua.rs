@@ -0,0 +1,329 @@
+mod config;
+
+use crate::services::{Attachment, Metadata, NotificationService};
+pub use config::*;
+use opcua::client::{ClientBuilder, IdentityToken, Session};
+use opcua::types::{
+    AttributeId, CallMethodRequest, DataValue, EndpointDescription, MessageSecurityMode, NodeId,
+    NumericRange, StatusCode, Variant, WriteValue,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::time::Duration;
+use tracing::{Instrument, info, info_span};
+
+const APPLICATION_NAME: &str = "notis";
+const APPLICATION_URI: &str = "urn:notis";
+
+#[derive(Default)]
+pub struct OpcUa;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    IO(#[from] std::io::Error),
+    #[error(transparent)]
+    OpcUa(#[from] opcua::types::Error),
+    #[error("Invalid opc ua client configuration: {0}")]
+    Client(String),
+    #[error("Invalid node id {0}")]
+    InvalidNodeId(String),
+    #[error("Could not connect to opc ua server {endpoint_url}: {status}")]
+    Connection {
+        endpoint_url: String,
+        status: StatusCode,
+    },
+    #[error("The opc ua server rejected the notification with status {0}")]
+    Rejected(StatusCode),
+    #[error("The notification was not delivered to {endpoint_url} within {timeout_secs} seconds")]
+    Timeout {
+        endpoint_url: String,
+        timeout_secs: u64,
+    },
+}
+
+impl Error {
+    /// Errors which will not resolve by retrying
+    pub fn is_permanent(&self) -> bool {
+        match self {
+            Self::Client(_) | Self::InvalidNodeId(_) => true,
+            Self::IO(_)
+            | Self::OpcUa(_)
+            | Self::Connection { .. }
+            | Self::Rejected(_)
+            | Self::Timeout { .. } => false,
+        }
+    }
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+pub struct NotificationOptions {}
+
+struct Notification<'a> {
+    service_id: &'a str,
+    title: &'a str,
+    content: Option<&'a str>,
+}
+
+impl Notification<'_> {
+    fn text(&self) -> String {
+        match self.content {
+            Some(content) => format!("{}: {content}", self.title),
+            None => self.title.to_string(),
+        }
+    }
+}
+
+fn node_id(node_id: &str) -> Result<NodeId, Error> {
+    NodeId::from_str(node_id).map_err(|_| Error::InvalidNodeId(node_id.to_string()))
+}
+
+fn check(status: StatusCode) -> Result<(), Error> {
+    if status.is_good() {
+        Ok(())
+    } else {
+        Err(Error::Rejected(status))
+    }
+}
+
+impl From<SecurityPolicy> for opcua::crypto::SecurityPolicy {
+    fn from(policy: SecurityPolicy) -> Self {
+        match policy {
+            SecurityPolicy::None => Self::None,
+            SecurityPolicy::Basic256Sha256 => Self::Basic256Sha256,
+            SecurityPolicy::Aes128Sha256RsaOaep => Self::Aes128Sha256RsaOaep,
+            SecurityPolicy::Aes256Sha256RsaPss => Self::Aes256Sha256RsaPss,
+        }
+    }
+}
+
+impl From<SecurityMode> for MessageSecurityMode {
+    fn from(mode: SecurityMode) -> Self {
+        match mode {
+            SecurityMode::None => Self::None,
+            SecurityMode::Sign => Self::Sign,
+            SecurityMode::SignAndEncrypt => Self::SignAndEncrypt,
+        }
+    }
+}
+
+impl From<&Identity> for IdentityToken {
+    fn from(identity: &Identity) -> Self {
+        match identity {
+            Identity::Anonymous => Self::Anonymous,
+            Identity::UserName {
+                user_name,
+                password,
+            } => Self::new_user_name(user_name, password),
+        }
+    }
+}
+
+impl NotificationService for OpcUa {
+    type Config = Config;
+    type NotificationOptions = NotificationOptions;
+
+    async fn send_notification(
+        &self,
+        _options: Option<Self::NotificationOptions>,
+        config: &Self::Config,
+        service_id: &str,
+        title: &str,
+        _attachments: Vec<Attachment>,
+        content: Option<&str>,
+        _html: Option<&str>,
+        _metadata: &Metadata,
+    ) -> Result<(), crate::Error> {
+        let notification = Notification {
+            service_id,
+            title,
+            content,
+        };
+        self.send(config, &notification).await?;
+        Ok(())
+    }
+}
+
+impl OpcUa {
+    async fn send(&self, config: &Config, notification: &Notification<'_>) -> Result<(), Error> {
+        let span = info_span!("send_opcua_notification", endpoint = config.endpoint_url);
+        async {
+            info!("Sending notification...");
+            tokio::time::timeout(
+                Duration::from_secs(config.timeout_secs),
+                Self::deliver(config, notification),
+            )
+            .await
+            .map_err(|_| Error::Timeout {
+                endpoint_url: config.endpoint_url.clone(),
+                timeout_secs: config.timeout_secs,
+            })??;
+            info!("... Ok");
+            Ok(())
+        }
+        .instrument(span)
+        .await
+    }
+
+    async fn deliver(config: &Config, notification: &Notification<'_>) -> Result<(), Error> {
+        let mut client = ClientBuilder::new()
+            .application_name(APPLICATION_NAME)
+            .application_uri(APPLICATION_URI)
+            .product_uri(APPLICATION_URI)
+            .pki_dir(crate::config::resolve_path(&config.pki_dir))
+            .create_sample_keypair(true)
+            .trust_server_certs(config.trust_server_certs)
+            .session_retry_limit(0)
+            .client()
+            .map_err(|errors| Error::Client(errors.join(", ")))?;
+        let endpoint: EndpointDescription = (
+            config.endpoint_url.as_str(),
+            opcua::crypto::SecurityPolicy::from(config.security_policy).to_uri(),
+            MessageSecurityMode::from(config.security_mode),
+        )
+            .into();
+        let (session, event_loop) = client
+            .connect_to_matching_endpoint(endpoint, IdentityToken::from(&config.identity))
+            .await?;
+        let mut event_loop = event_loop.spawn();
+        tokio::select! {
+            connected = session.wait_for_connection() => {
+                if !connected {
+                    return Err(Error::Connection {
+                        endpoint_url: config.endpoint_url.clone(),
+                        status: StatusCode::BadNotConnected,
+                    });
+                }
+            }
+            status = &mut event_loop => {
+                return Err(Error::Connection {
+                    endpoint_url: config.endpoint_url.clone(),
+                    status: status.unwrap_or(StatusCode::BadUnexpectedError),
+                });
+            }
+        }
+        let result = Self::write_target(&session, &config.target, notification).await;
+        let _ = session.disconnect().await;
+        let _ = event_loop.await;
+        result
+    }
+
+    async fn write_target(
+        session: &Session,
+        target: &Target,
+        notification: &Notification<'_>,
+    ) -> Result<(), Error> {
+        match target {
+            Target::Variable { node_id: id } => {
+                let write = WriteValue {
+                    node_id: node_id(id)?,
+                    attribute_id: AttributeId::Value as u32,
+                    index_range: NumericRange::None,
+                    value: DataValue::value_only(Variant::from(notification.text())),
+                };
+                let results = session.write(&[write]).await?;
+                check(
+                    results
+                        .first()
+                        .copied()
+                        .unwrap_or(StatusCode::BadUnexpectedError),
+                )
+            }
+            Target::Method {
+                object_id,
+                method_id,
+            } => {
+                let arguments = vec![
+                    Variant::from(notification.service_id),
+                    Variant::from(notification.title),
+                    Variant::from(notification.content.unwrap_or_default()),
+                ];
+                let request = CallMethodRequest::from((
+                    node_id(object_id)?,
+                    node_id(method_id)?,
+                    Some(arguments),
+                ));
+                let result = session.call_one(request).await?;
+                check(result.status_code)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text() {
+        let notification = Notification {
+            service_id: "plc",
+            title: "Press 3 stopped",
+            content: Some("Emergency stop was triggered"),
+        };
+        assert_eq!(
+            notification.text(),
+            "Press 3 stopped: Emergency stop was triggered"
+        );
+        let notification = Notification {
+            content: None,
+            ..notification
+        };
+        assert_eq!(notification.text(), "Press 3 stopped");
+    }
+
+    #[tokio::test]
+    async fn connection_refused() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        drop(listener);
+        let pki_dir = std::env::temp_dir().join("notis-opcua-test-pki");
+        let config = Config {
+            endpoint_url: format!("opc.tcp://{address}"),
+            security_policy: SecurityPolicy::None,
+            security_mode: SecurityMode::None,
+            identity: Identity::Anonymous,
+            pki_dir: pki_dir.to_string_lossy().to_string(),
+            timeout_secs: 5,
+            ..Config::example()
+        };
+        let notification = Notification {
+            service_id: "plc",
+            title: "Press 3 stopped",
+            content: None,
+        };
+        assert!(matches!(
+            OpcUa.send(&config, &notification).await,
+            Err(Error::OpcUa(_) | Error::Connection { .. })
+        ));
+    }
+
+    /// Delivers a notification to a running server, e.g. one built with open62541, configured via
+    /// `NOTIS_OPCUA_TEST_ENDPOINT` and `NOTIS_OPCUA_TEST_NODE` (a writable String variable)
+    #[tokio::test]
+    #[ignore]
+    async fn server() {
+        let endpoint_url = std::env::var("NOTIS_OPCUA_TEST_ENDPOINT")
+            .unwrap_or("opc.tcp://localhost:4840".to_string());
+        let node_id =
+            std::env::var("NOTIS_OPCUA_TEST_NODE").unwrap_or("ns=1;s=notis.alarm".to_string());
+        let pki_dir = std::env::temp_dir().join("notis-opcua-test-pki");
+        let config = Config {
+            endpoint_url,
+            security_policy: SecurityPolicy::None,
+            security_mode: SecurityMode::None,
+            identity: Identity::Anonymous,
+            target: Target::Variable { node_id },
+            pki_dir: pki_dir.to_string_lossy().to_string(),
+            trust_server_certs: true,
+            timeout_secs: 10,
+        };
+        let notification = Notification {
+            service_id: "plc",
+            title: "Press 3 stopped",
+            content: Some("Emergency stop was triggered"),
+        };
+        OpcUa.send(&config, &notification).await.unwrap();
+    }
+}
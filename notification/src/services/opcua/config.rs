@@ -0,0 +1,145 @@
+mod patch;
+
+use crate::config::NotificationServiceConfig;
+pub use patch::ConfigPatch;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_PKI_DIR: &str = "pki";
+const DEFAULT_TIMEOUT_SECS: u64 = 10;
+
+fn default_pki_dir() -> String {
+    DEFAULT_PKI_DIR.to_string()
+}
+
+fn default_timeout_secs() -> u64 {
+    DEFAULT_TIMEOUT_SECS
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Default, Deserialize, Serialize, JsonSchema)]
+pub enum SecurityPolicy {
+    #[default]
+    None,
+    Basic256Sha256,
+    Aes128Sha256RsaOaep,
+    Aes256Sha256RsaPss,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Default, Deserialize, Serialize, JsonSchema)]
+pub enum SecurityMode {
+    #[default]
+    None,
+    Sign,
+    SignAndEncrypt,
+}
+
+#[derive(Debug, PartialEq, Clone, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(tag = "type")]
+pub enum Identity {
+    #[default]
+    Anonymous,
+    UserName {
+        user_name: String,
+        password: String,
+    },
+}
+
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(tag = "kind")]
+pub enum Target {
+    /// The notification is written as `title: content` into a variable of type String
+    Variable { node_id: String },
+    /// The method is called with the service id, title and content as String input arguments
+    Method {
+        object_id: String,
+        method_id: String,
+    },
+}
+
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct Config {
+    /// Url of the server endpoint, e.g. opc.tcp://plc.example.com:4840
+    pub endpoint_url: String,
+    #[serde(default)]
+    pub security_policy: SecurityPolicy,
+    #[serde(default)]
+    pub security_mode: SecurityMode,
+    #[serde(default)]
+    pub identity: Identity,
+    pub target: Target,
+    /// Directory of the certificate store, relative to the directory of the config file, the
+    /// application instance certificate of notis is created on first use
+    #[serde(default = "default_pki_dir")]
+    pub pki_dir: String,
+    /// Trust server certificates which are not in the trusted folder of the certificate store
+    #[serde(default)]
+    pub trust_server_certs: bool,
+    /// Seconds until connecting to the server and delivering the notification is aborted
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+impl Config {
+    pub fn example() -> Self {
+        Self {
+            endpoint_url: "opc.tcp://plc.example.com:4840".to_string(),
+            security_policy: SecurityPolicy::Basic256Sha256,
+            security_mode: SecurityMode::SignAndEncrypt,
+            identity: Identity::UserName {
+                user_name: "notis".to_string(),
+                password: "my_password".to_string(),
+            },
+            target: Target::Variable {
+                node_id: "ns=2;s=Notis.LastAlarm".to_string(),
+            },
+            pki_dir: default_pki_dir(),
+            trust_server_certs: false,
+            timeout_secs: DEFAULT_TIMEOUT_SECS,
+        }
+    }
+
+    pub fn redacted(&self) -> Self {
+        let identity = match &self.identity {
+            Identity::Anonymous => Identity::Anonymous,
+            Identity::UserName { user_name, .. } => Identity::UserName {
+                user_name: user_name.clone(),
+                password: "***".to_string(),
+            },
+        };
+        Self {
+            identity,
+            ..self.clone()
+        }
+    }
+}
+
+impl NotificationServiceConfig for Config {
+    type Patch = ConfigPatch;
+
+    fn apply_patch(&mut self, patch: ConfigPatch) {
+        if let Some(endpoint_url) = patch.endpoint_url {
+            self.endpoint_url = endpoint_url;
+        }
+        if let Some(security_policy) = patch.security_policy {
+            self.security_policy = security_policy;
+        }
+        if let Some(security_mode) = patch.security_mode {
+            self.security_mode = security_mode;
+        }
+        if let Some(identity) = patch.identity {
+            self.identity = identity;
+        }
+        if let Some(target) = patch.target {
+            self.target = target;
+        }
+        if let Some(pki_dir) = patch.pki_dir {
+            self.pki_dir = pki_dir;
+        }
+        if let Some(trust_server_certs) = patch.trust_server_certs {
+            self.trust_server_certs = trust_server_certs;
+        }
+        if let Some(timeout_secs) = patch.timeout_secs {
+            self.timeout_secs = timeout_secs;
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::services::opcua::{Identity, SecurityMode, SecurityPolicy, Target};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, JsonSchema)]
+pub struct ConfigPatch {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub endpoint_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security_policy: Option<SecurityPolicy>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security_mode: Option<SecurityMode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identity: Option<Identity>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<Target>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pki_dir: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trust_server_certs: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde() {
+        let s = r#"{}"#;
+        let p = ConfigPatch {
+            endpoint_url: None,
+            security_policy: None,
+            security_mode: None,
+            identity: None,
+            target: None,
+            pki_dir: None,
+            trust_server_certs: None,
+            timeout_secs: None,
+        };
+        assert_eq!(p, serde_json::from_str(s).unwrap());
+        assert_eq!(s, serde_json::to_string(&p).unwrap());
+
+        let s = r#"{"security_mode":"Sign","identity":{"type":"Anonymous"},"target":{"kind":"Method","object_id":"ns=2;i=5001","method_id":"ns=2;i=5002"},"trust_server_certs":true}"#;
+        let p = ConfigPatch {
+            endpoint_url: None,
+            security_policy: None,
+            security_mode: Some(SecurityMode::Sign),
+            identity: Some(Identity::Anonymous),
+            target: Some(Target::Method {
+                object_id: "ns=2;i=5001".to_string(),
+                method_id: "ns=2;i=5002".to_string(),
+            }),
+            pki_dir: None,
+            trust_server_certs: Some(true),
+            timeout_secs: None,
+        };
+        assert_eq!(p, serde_json::from_str(s).unwrap());
+        assert_eq!(s, serde_json::to_string(&p).unwrap());
+    }
+}
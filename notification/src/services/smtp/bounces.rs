@@ -0,0 +1,403 @@
+//! Polls the bounce mailboxes of smtp services for delivery status notifications, which update
+//! the statuses of the notifications and the health of the receivers
+
+pub mod dsn;
+mod imap;
+mod pop3;
+
+use super::{BounceMailbox, BounceProtocol, ConnectionType, Mailbox};
+use crate::services::{ReceiverReport, ReceiverState};
+pub use dsn::DeliveryReport;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio::task::JoinHandle;
+use tracing::{Instrument, debug, info_span, warn};
+use uuid::Uuid;
+
+/// Interval in which changes of the bounce mailboxes in the config are applied
+const CONFIG_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    IO(#[from] std::io::Error),
+    #[error(transparent)]
+    Tls(#[from] tokio_native_tls::native_tls::Error),
+    #[error("The server did not respond within {0:?}")]
+    Timeout(Duration),
+    #[error("The server closed the connection")]
+    Closed,
+    #[error("The server refused the command: {0}")]
+    Refused(String),
+}
+
+/// Message-ID of the mails sent for a notification, which is returned in delivery status
+/// notifications
+pub(super) fn message_id(notification_id: Uuid, sender: &Mailbox) -> String {
+    format!("<{notification_id}@{}>", sender.email.domain())
+}
+
+trait Stream: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> Stream for T {}
+
+/// Line based connection to the server of a bounce mailbox
+struct Connection {
+    stream: BufReader<Box<dyn Stream>>,
+    host: String,
+}
+
+async fn with_timeout<T>(
+    timeout: Duration,
+    future: impl Future<Output = Result<T, Error>>,
+) -> Result<T, Error> {
+    tokio::time::timeout(timeout, future)
+        .await
+        .map_err(|_| Error::Timeout(timeout))?
+}
+
+impl Connection {
+    /// Connects with implicit TLS for the `Tls` connection type, STARTTLS is up to the protocol
+    async fn open(mailbox: &BounceMailbox) -> Result<Self, Error> {
+        let stream = with_timeout(TIMEOUT, async {
+            Ok(TcpStream::connect((mailbox.server_url.as_str(), mailbox.port())).await?)
+        })
+        .await?;
+        let connection = Self {
+            stream: BufReader::new(Box::new(stream)),
+            host: mailbox.server_url.clone(),
+        };
+        match mailbox.connection_type {
+            ConnectionType::Tls => connection.start_tls().await,
+            ConnectionType::StartTls | ConnectionType::PlainUnsecure => Ok(connection),
+        }
+    }
+
+    async fn start_tls(self) -> Result<Self, Error> {
+        let connector = tokio_native_tls::TlsConnector::from(
+            tokio_native_tls::native_tls::TlsConnector::new()?,
+        );
+        let stream = self.stream.into_inner();
+        let stream = with_timeout(TIMEOUT, async {
+            Ok(connector.connect(&self.host, stream).await?)
+        })
+        .await?;
+        Ok(Self {
+            stream: BufReader::new(Box::new(stream)),
+            host: self.host,
+        })
+    }
+
+    /// Reads a line without the line break
+    async fn read_line_bytes(&mut self, timeout: Duration) -> Result<Vec<u8>, Error> {
+        let mut line = Vec::new();
+        let read = with_timeout(timeout, async {
+            Ok(self.stream.read_until(b'\n', &mut line).await?)
+        })
+        .await?;
+        if read == 0 {
+            return Err(Error::Closed);
+        }
+        while line
+            .last()
+            .is_some_and(|byte| matches!(byte, b'\r' | b'\n'))
+        {
+            line.pop();
+        }
+        Ok(line)
+    }
+
+    async fn read_line(&mut self, timeout: Duration) -> Result<String, Error> {
+        let line = self.read_line_bytes(timeout).await?;
+        Ok(String::from_utf8_lossy(&line).into_owned())
+    }
+
+    async fn read_exact(&mut self, length: usize) -> Result<Vec<u8>, Error> {
+        let mut data = vec![0; length];
+        with_timeout(TIMEOUT, async {
+            Ok(self.stream.read_exact(&mut data).await?)
+        })
+        .await?;
+        Ok(data)
+    }
+
+    async fn write_line(&mut self, line: &str) -> Result<(), Error> {
+        with_timeout(TIMEOUT, async {
+            let stream = self.stream.get_mut();
+            stream.write_all(format!("{line}\r\n").as_bytes()).await?;
+            Ok(stream.flush().await?)
+        })
+        .await
+    }
+}
+
+/// Source of the bounce mailboxes and target of their reports
+pub trait Reports: Send + Sync + 'static {
+    /// Bounce mailboxes of the smtp services by service id
+    fn bounce_mailboxes(&self) -> HashMap<String, BounceMailbox>;
+
+    /// Called for each delivery status notification in the bounce mailbox of the service
+    fn report(&self, service_id: &str, mailbox: &BounceMailbox, report: DeliveryReport);
+
+    /// Unique ids of the POP3 messages of the service which were processed before
+    fn seen_messages(&self, service_id: &str) -> HashSet<String>;
+
+    /// Stores the unique ids of the processed POP3 messages of the service
+    fn set_seen_messages(&self, service_id: &str, seen: &HashSet<String>);
+}
+
+/// Polls the bounce mailboxes, restarting the pollers of mailboxes whose config changed
+pub async fn run(reports: Arc<impl Reports>) {
+    let mut pollers: HashMap<String, (BounceMailbox, JoinHandle<()>)> = HashMap::new();
+    loop {
+        let mailboxes = reports.bounce_mailboxes();
+        pollers.retain(|service_id, (mailbox, poller)| {
+            let unchanged = mailboxes.get(service_id) == Some(mailbox);
+            if !unchanged {
+                poller.abort();
+            }
+            unchanged
+        });
+        for (service_id, mailbox) in mailboxes {
+            if pollers.contains_key(&service_id) {
+                continue;
+            }
+            let span = info_span!("bounce_mailbox", service_id);
+            let poller = tokio::spawn(
+                poll(service_id.clone(), mailbox.clone(), reports.clone()).instrument(span),
+            );
+            pollers.insert(service_id, (mailbox, poller));
+        }
+        tokio::time::sleep(CONFIG_CHECK_INTERVAL).await;
+    }
+}
+
+async fn poll(service_id: String, mailbox: BounceMailbox, reports: Arc<impl Reports>) {
+    let mut handle = |message: &[u8]| match dsn::parse(message) {
+        Some(report) => {
+            debug!(notification_id = ?report.notification_id, "Read delivery status notification");
+            reports.report(&service_id, &mailbox, report);
+            true
+        }
+        None => false,
+    };
+    // POP3 has no flags, so the ids of the processed messages are remembered instead
+    let mut seen = reports.seen_messages(&service_id);
+    loop {
+        let result = match mailbox.protocol {
+            BounceProtocol::Imap => imap::watch(&mailbox, &mut handle).await,
+            BounceProtocol::Pop3 => {
+                let result = pop3::poll(&mailbox, &mut seen, &mut handle).await;
+                reports.set_seen_messages(&service_id, &seen);
+                result
+            }
+        };
+        if let Err(e) = result {
+            warn!("Could not read the bounce mailbox: {e}");
+        }
+        tokio::time::sleep(mailbox.poll_interval()).await;
+    }
+}
+
+/// Unique ids of the processed POP3 messages per service id, which are stored in a file so the
+/// reports are not processed again after a restart
+pub struct SeenMessages {
+    path: PathBuf,
+    services: Mutex<HashMap<String, HashSet<String>>>,
+}
+
+impl SeenMessages {
+    /// Loads the ids from the file, which is written on the first change
+    pub fn open(path: PathBuf) -> Self {
+        Self {
+            services: Mutex::new(read(&path, "seen POP3 messages")),
+            path,
+        }
+    }
+
+    pub fn get(&self, service_id: &str) -> HashSet<String> {
+        self.services
+            .lock()
+            .unwrap()
+            .get(service_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Replaces the ids of the service, writing the file if they changed
+    pub fn set(&self, service_id: &str, seen: &HashSet<String>) -> std::io::Result<()> {
+        let mut services = self.services.lock().unwrap();
+        if services
+            .get(service_id)
+            .map_or(seen.is_empty(), |current| current == seen)
+        {
+            return Ok(());
+        }
+        services.insert(service_id.to_string(), seen.clone());
+        write(&self.path, &*services)
+    }
+}
+
+/// Reads the state stored in the file, the state is empty if the file does not exist yet
+fn read<T: DeserializeOwned + Default>(path: &Path, name: &str) -> T {
+    match std::fs::read(path) {
+        Ok(data) => serde_json::from_slice(&data).unwrap_or_else(|e| {
+            warn!("Could not parse the {name} {path:?}: {e}");
+            T::default()
+        }),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => T::default(),
+        Err(e) => {
+            warn!("Could not read the {name} {path:?}: {e}");
+            T::default()
+        }
+    }
+}
+
+/// Writes the state to a temporary file which replaces the previous file
+fn write(path: &Path, value: &impl Serialize) -> std::io::Result<()> {
+    let temporary = path.with_extension("tmp");
+    std::fs::write(&temporary, serde_json::to_vec(value)?)?;
+    std::fs::rename(&temporary, path)
+}
+
+/// Health of a receiver derived from the delivery status notifications of the mails sent to it
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ReceiverHealth {
+    pub address: String,
+    /// Failed deliveries since the last successful one
+    pub failures: u32,
+    /// Whether the failures reached the `dead_after` of the bounce mailbox
+    pub dead: bool,
+    pub last_report: ReceiverReport,
+}
+
+/// Health of the receivers per service id, which is stored in a file so the consecutive failures
+/// are still counted after a restart
+pub struct Health {
+    path: PathBuf,
+    services: Mutex<HashMap<String, BTreeMap<String, ReceiverHealth>>>,
+}
+
+impl Health {
+    /// Loads the health from the file, which is written on the first report
+    pub fn open(path: PathBuf) -> Self {
+        Self {
+            services: Mutex::new(read(&path, "receiver health")),
+            path,
+        }
+    }
+
+    /// Updates the health of the receiver, returning it if the receiver is dead since this report
+    pub fn record(
+        &self,
+        service_id: &str,
+        report: &ReceiverReport,
+        dead_after: u32,
+    ) -> Option<ReceiverHealth> {
+        let mut services = self.services.lock().unwrap();
+        let receivers = services.entry(service_id.to_string()).or_default();
+        let health = receivers
+            .entry(report.address.to_ascii_lowercase())
+            .or_insert_with(|| ReceiverHealth {
+                address: report.address.clone(),
+                failures: 0,
+                dead: false,
+                last_report: report.clone(),
+            });
+        match report.state {
+            ReceiverState::Delivered => health.failures = 0,
+            ReceiverState::Delayed => {}
+            ReceiverState::Failed => health.failures += 1,
+        }
+        health.last_report = report.clone();
+        let was_dead = health.dead;
+        health.dead = health.failures >= dead_after.max(1);
+        let dead = (health.dead && !was_dead).then(|| health.clone());
+        if let Err(e) = write(&self.path, &*services) {
+            warn!(service_id, "Could not store the receiver health: {e}");
+        }
+        dead
+    }
+
+    /// Health of the receivers of the service which were reported on, ordered by address
+    pub fn receivers(&self, service_id: &str) -> Vec<ReceiverHealth> {
+        self.services
+            .lock()
+            .unwrap()
+            .get(service_id)
+            .map(|receivers| receivers.values().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::SystemTime;
+
+    fn report(state: ReceiverState) -> ReceiverReport {
+        ReceiverReport {
+            address: "Bob@example.com".to_string(),
+            state,
+            status: "5.1.1".to_string(),
+            diagnostic: None,
+            time: SystemTime::now(),
+        }
+    }
+
+    #[test]
+    fn health() {
+        let path = std::env::temp_dir().join(format!("notis-health-test-{}.json", Uuid::new_v4()));
+        let health = Health::open(path.clone());
+        assert_eq!(
+            health.record("smtp", &report(ReceiverState::Failed), 2),
+            None
+        );
+        assert_eq!(
+            health.record("smtp", &report(ReceiverState::Delayed), 2),
+            None
+        );
+        let dead = health
+            .record("smtp", &report(ReceiverState::Failed), 2)
+            .unwrap();
+        assert_eq!((dead.failures, dead.dead), (2, true));
+        assert_eq!(
+            health.record("smtp", &report(ReceiverState::Failed), 2),
+            None
+        );
+
+        // The failures are still counted after a restart
+        let receivers = health.receivers("smtp");
+        let health = Health::open(path.clone());
+        assert_eq!(health.receivers("smtp"), receivers);
+        health.record("smtp", &report(ReceiverState::Delivered), 2);
+        let receivers = health.receivers("smtp");
+        assert_eq!(receivers.len(), 1);
+        assert_eq!((receivers[0].failures, receivers[0].dead), (0, false));
+        assert!(health.receivers("other").is_empty());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn seen_messages() {
+        let path = std::env::temp_dir().join(format!("notis-seen-test-{}.json", Uuid::new_v4()));
+        let seen = SeenMessages::open(path.clone());
+        assert!(seen.get("smtp").is_empty());
+        seen.set("smtp", &HashSet::new()).unwrap();
+        assert!(!path.exists());
+
+        let uids = HashSet::from(["report".to_string(), "hello".to_string()]);
+        seen.set("smtp", &uids).unwrap();
+        let seen = SeenMessages::open(path.clone());
+        assert_eq!(seen.get("smtp"), uids);
+        assert!(seen.get("other").is_empty());
+        std::fs::remove_file(path).unwrap();
+    }
+}
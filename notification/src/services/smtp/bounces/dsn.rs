@@ -0,0 +1,221 @@
+//! Parser of delivery status notifications (RFC 3464)
+
+use crate::services::{ReceiverReport, ReceiverState};
+use mail_parser::{MessageParser, MimeHeaders};
+use std::collections::HashMap;
+use std::time::SystemTime;
+use uuid::Uuid;
+
+/// Delivery status notification of a sent mail
+#[derive(Debug, PartialEq)]
+pub struct DeliveryReport {
+    /// Id of the notification the mail was sent for, unless the mail was not sent by notis
+    pub notification_id: Option<Uuid>,
+    pub receivers: Vec<ReceiverReport>,
+}
+
+/// Parses a mail of the bounce mailbox, returns None if it is not a delivery status notification
+pub fn parse(raw: &[u8]) -> Option<DeliveryReport> {
+    let message = MessageParser::default().parse(raw)?;
+    let mut status = None;
+    let mut message_id = None;
+    for part in &message.parts {
+        let Some(content_type) = part.content_type() else {
+            continue;
+        };
+        match (content_type.ctype(), content_type.subtype()) {
+            ("message", Some("delivery-status" | "global-delivery-status")) => {
+                status = part.text_contents();
+            }
+            // The returned mail or its headers, depending on the requested return
+            ("message", Some("rfc822" | "global")) if message_id.is_none() => {
+                message_id = part
+                    .message()
+                    .and_then(|message| message.message_id())
+                    .map(str::to_string);
+            }
+            ("text", Some("rfc822-headers")) if message_id.is_none() => {
+                message_id = MessageParser::default()
+                    .parse(part.contents())
+                    .and_then(|headers| headers.message_id().map(str::to_string));
+            }
+            _ => {}
+        }
+    }
+    let groups = field_groups(status?);
+    let (per_message, per_recipient) = groups.split_first()?;
+    let notification_id = per_message
+        .get("original-envelope-id")
+        .and_then(|envelope_id| Uuid::parse_str(envelope_id).ok())
+        .or_else(|| message_id.as_deref().and_then(notification_id));
+    let time = SystemTime::now();
+    Some(DeliveryReport {
+        notification_id,
+        receivers: per_recipient
+            .iter()
+            .filter_map(|fields| receiver_report(fields, time))
+            .collect(),
+    })
+}
+
+/// Id of the notification which the Message-ID was generated for
+fn notification_id(message_id: &str) -> Option<Uuid> {
+    let (local_part, _) = message_id.trim_matches(['<', '>']).split_once('@')?;
+    Uuid::parse_str(local_part).ok()
+}
+
+/// Groups of fields separated by blank lines, with lowercase names and unfolded values
+fn field_groups(text: &str) -> Vec<HashMap<String, String>> {
+    let mut groups = Vec::new();
+    let mut group: HashMap<String, String> = HashMap::new();
+    let mut last_name: Option<String> = None;
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            if !group.is_empty() {
+                groups.push(std::mem::take(&mut group));
+            }
+            last_name = None;
+        } else if line.starts_with([' ', '\t']) {
+            if let Some(value) = last_name.as_ref().and_then(|name| group.get_mut(name)) {
+                value.push(' ');
+                value.push_str(line.trim());
+            }
+        } else if let Some((name, value)) = line.split_once(':') {
+            let name = name.trim().to_ascii_lowercase();
+            group.insert(name.clone(), value.trim().to_string());
+            last_name = Some(name);
+        }
+    }
+    if !group.is_empty() {
+        groups.push(group);
+    }
+    groups
+}
+
+/// Value of a field which is preceded by its type, e.g. `rfc822; bob@example.com`
+fn typed_value(value: &str) -> &str {
+    value
+        .split_once(';')
+        .map_or(value, |(_, value)| value)
+        .trim()
+}
+
+fn receiver_report(fields: &HashMap<String, String>, time: SystemTime) -> Option<ReceiverReport> {
+    let recipient = fields
+        .get("final-recipient")
+        .or_else(|| fields.get("original-recipient"))?;
+    let state = match fields.get("action")?.to_ascii_lowercase().as_str() {
+        "failed" => ReceiverState::Failed,
+        "delayed" => ReceiverState::Delayed,
+        "delivered" | "relayed" | "expanded" => ReceiverState::Delivered,
+        _ => return None,
+    };
+    Some(ReceiverReport {
+        address: typed_value(recipient).to_string(),
+        state,
+        status: fields
+            .get("status")
+            .and_then(|status| status.split_whitespace().next())
+            .unwrap_or_default()
+            .to_string(),
+        diagnostic: fields
+            .get("diagnostic-code")
+            .map(|diagnostic| typed_value(diagnostic).to_string()),
+        time,
+    })
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+
+    /// Delivery status notification of a failed and a delayed receiver with the headers of the
+    /// returned mail
+    pub fn mail(envelope_id: Option<&str>, message_id: &str) -> Vec<u8> {
+        let envelope_id = envelope_id
+            .map(|envelope_id| format!("Original-Envelope-Id: {envelope_id}\r\n"))
+            .unwrap_or_default();
+        format!(
+            "From: Mail Delivery System <MAILER-DAEMON@mail.com>\r\n\
+            To: alice@mail.com\r\n\
+            Subject: Undelivered Mail Returned to Sender\r\n\
+            MIME-Version: 1.0\r\n\
+            Content-Type: multipart/report; report-type=delivery-status; boundary=\"b1\"\r\n\
+            \r\n\
+            --b1\r\n\
+            Content-Type: text/plain\r\n\
+            \r\n\
+            Your mail could not be delivered.\r\n\
+            --b1\r\n\
+            Content-Type: message/delivery-status\r\n\
+            \r\n\
+            Reporting-MTA: dns; mail.com\r\n\
+            {envelope_id}\
+            Arrival-Date: Mon, 19 Oct 2026 10:00:00 +0200\r\n\
+            \r\n\
+            Final-Recipient: rfc822; bob@example.com\r\n\
+            Original-Recipient: rfc822;bob@example.com\r\n\
+            Action: failed\r\n\
+            Status: 5.1.1\r\n\
+            Diagnostic-Code: smtp; 550 5.1.1 <bob@example.com>:\r\n \
+            Recipient address rejected: User unknown\r\n\
+            \r\n\
+            Final-Recipient: rfc822; charlie@example.com\r\n\
+            Action: delayed\r\n\
+            Status: 4.2.2 (mailbox full)\r\n\
+            \r\n\
+            --b1\r\n\
+            Content-Type: text/rfc822-headers\r\n\
+            \r\n\
+            From: Alice <alice@mail.com>\r\n\
+            Subject: Pump 3 stopped\r\n\
+            Message-ID: {message_id}\r\n\
+            \r\n\
+            --b1--\r\n"
+        )
+        .into_bytes()
+    }
+
+    #[test]
+    fn delivery_status_notification() {
+        let id = Uuid::new_v4();
+        let report = parse(&mail(Some(&id.to_string()), "<other@mail.com>")).unwrap();
+        assert_eq!(report.notification_id, Some(id));
+        let receivers: Vec<_> = report
+            .receivers
+            .iter()
+            .map(|receiver| {
+                (
+                    receiver.address.as_str(),
+                    receiver.state,
+                    receiver.status.as_str(),
+                    receiver.diagnostic.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            receivers,
+            [
+                (
+                    "bob@example.com",
+                    ReceiverState::Failed,
+                    "5.1.1",
+                    Some("550 5.1.1 <bob@example.com>: Recipient address rejected: User unknown")
+                ),
+                ("charlie@example.com", ReceiverState::Delayed, "4.2.2", None),
+            ]
+        );
+
+        // Servers which do not support envelope ids still return the Message-ID
+        let report = parse(&mail(None, &format!("<{id}@mail.com>"))).unwrap();
+        assert_eq!(report.notification_id, Some(id));
+        let report = parse(&mail(None, "<other@mail.com>")).unwrap();
+        assert_eq!(report.notification_id, None);
+        assert_eq!(report.receivers.len(), 2);
+
+        assert_eq!(
+            parse(b"From: bob@example.com\r\nSubject: Re: Pump 3 stopped\r\n\r\nThanks"),
+            None
+        );
+    }
+}
@@ -0,0 +1,291 @@
+//! Minimal IMAP client (RFC 9051) which reads the unseen messages of a folder and waits for new
+//! ones with IDLE (RFC 2177)
+
+use super::{BounceMailbox, Connection, ConnectionType, Error, TIMEOUT};
+
+/// Untagged response with the literals it contains, e.g. a fetched message
+struct Response {
+    line: String,
+    literals: Vec<Vec<u8>>,
+}
+
+struct Session {
+    connection: Connection,
+    tag: u32,
+}
+
+/// Length of the literal announced at the end of the line, e.g. `{1234}`
+fn literal_length(line: &str) -> Option<usize> {
+    let start = line.rfind('{')?;
+    line.strip_suffix('}')?[start + 1..].parse().ok()
+}
+
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+impl Session {
+    async fn response(&mut self) -> Result<Response, Error> {
+        let mut line = self.connection.read_line(TIMEOUT).await?;
+        let mut literals = Vec::new();
+        while let Some(length) = literal_length(&line) {
+            literals.push(self.connection.read_exact(length).await?);
+            line.push_str(&self.connection.read_line(TIMEOUT).await?);
+        }
+        Ok(Response { line, literals })
+    }
+
+    async fn send(&mut self, command: &str) -> Result<String, Error> {
+        self.tag += 1;
+        let tag = format!("a{}", self.tag);
+        self.connection
+            .write_line(&format!("{tag} {command}"))
+            .await?;
+        Ok(tag)
+    }
+
+    /// Reads the untagged responses until the tagged response, which must be OK
+    async fn responses(&mut self, tag: &str) -> Result<Vec<Response>, Error> {
+        let mut responses = Vec::new();
+        loop {
+            let response = self.response().await?;
+            let Some(status) = response
+                .line
+                .strip_prefix(tag)
+                .and_then(|status| status.strip_prefix(' '))
+            else {
+                responses.push(response);
+                continue;
+            };
+            if !status.starts_with("OK") {
+                return Err(Error::Refused(status.to_string()));
+            }
+            return Ok(responses);
+        }
+    }
+
+    async fn command(&mut self, command: &str) -> Result<Vec<Response>, Error> {
+        let tag = self.send(command).await?;
+        self.responses(&tag).await
+    }
+
+    async fn login(mailbox: &BounceMailbox) -> Result<Self, Error> {
+        let mut session = Self {
+            connection: Connection::open(mailbox).await?,
+            tag: 0,
+        };
+        let greeting = session.connection.read_line(TIMEOUT).await?;
+        if !greeting.starts_with("* OK") && !greeting.starts_with("* PREAUTH") {
+            return Err(Error::Refused(greeting));
+        }
+        if mailbox.connection_type == ConnectionType::StartTls {
+            session.command("STARTTLS").await?;
+            session.connection = session.connection.start_tls().await?;
+        }
+        if greeting.starts_with("* OK") {
+            session
+                .command(&format!(
+                    "LOGIN {} {}",
+                    quote(&mailbox.user),
+                    quote(&mailbox.password)
+                ))
+                .await?;
+        }
+        session
+            .command(&format!("SELECT {}", quote(&mailbox.folder)))
+            .await?;
+        Ok(session)
+    }
+
+    async fn supports_idle(&mut self) -> Result<bool, Error> {
+        Ok(self.command("CAPABILITY").await?.iter().any(|response| {
+            response.line.starts_with("* CAPABILITY")
+                && response
+                    .line
+                    .split_whitespace()
+                    .any(|capability| capability.eq_ignore_ascii_case("IDLE"))
+        }))
+    }
+
+    /// Passes the unseen messages to the handler, marking them as seen or deleting the reports
+    async fn read_unseen(
+        &mut self,
+        delete_reports: bool,
+        handle: &mut impl FnMut(&[u8]) -> bool,
+    ) -> Result<(), Error> {
+        let uids: Vec<u32> = self
+            .command("UID SEARCH UNSEEN")
+            .await?
+            .iter()
+            .filter_map(|response| response.line.strip_prefix("* SEARCH"))
+            .flat_map(|uids| uids.split_whitespace().filter_map(|uid| uid.parse().ok()))
+            .collect();
+        let mut deleted = false;
+        for uid in uids {
+            let responses = self
+                .command(&format!("UID FETCH {uid} BODY.PEEK[]"))
+                .await?;
+            let Some(message) = responses
+                .iter()
+                .find_map(|response| response.literals.first())
+            else {
+                continue;
+            };
+            let flag = if handle(message) && delete_reports {
+                deleted = true;
+                "\\Deleted"
+            } else {
+                "\\Seen"
+            };
+            self.command(&format!("UID STORE {uid} +FLAGS.SILENT ({flag})"))
+                .await?;
+        }
+        if deleted {
+            self.command("EXPUNGE").await?;
+        }
+        Ok(())
+    }
+
+    /// Waits until the server announces a new message or the timeout elapsed
+    async fn idle(&mut self, timeout: std::time::Duration) -> Result<(), Error> {
+        let tag = self.send("IDLE").await?;
+        let continuation = self.response().await?;
+        if !continuation.line.starts_with('+') {
+            return Err(Error::Refused(continuation.line));
+        }
+        loop {
+            match self.connection.read_line(timeout).await {
+                Ok(line) if line.ends_with("EXISTS") => break,
+                Ok(_) => {}
+                Err(Error::Timeout(_)) => break,
+                Err(e) => return Err(e),
+            }
+        }
+        self.connection.write_line("DONE").await?;
+        self.responses(&tag).await?;
+        Ok(())
+    }
+}
+
+/// Reads the reports of the folder until an error occurs, new reports are read as soon as the
+/// server announces them if it supports IDLE
+pub(super) async fn watch(
+    mailbox: &BounceMailbox,
+    handle: &mut impl FnMut(&[u8]) -> bool,
+) -> Result<(), Error> {
+    let mut session = Session::login(mailbox).await?;
+    let idle = session.supports_idle().await?;
+    loop {
+        session.read_unseen(mailbox.delete_reports, handle).await?;
+        if idle {
+            session.idle(mailbox.poll_interval()).await?;
+        } else {
+            tokio::time::sleep(mailbox.poll_interval()).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::dsn;
+    use super::*;
+    use crate::services::smtp::BounceProtocol;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    /// Serves a report and another message, announces a third message while idling and closes
+    /// the connection on the next IDLE, returning the received commands without tags
+    async fn fake_server(listener: tokio::net::TcpListener, report: Vec<u8>) -> Vec<String> {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut stream = BufReader::new(stream);
+        stream.write_all(b"* OK IMAP ready\r\n").await.unwrap();
+        let mut commands = Vec::new();
+        let mut idle_tag = None;
+        let mut line = String::new();
+        while stream.read_line(&mut line).await.unwrap() > 0 {
+            let line = std::mem::take(&mut line);
+            let (tag, command) = line.trim_end().split_once(' ').unwrap_or(("", "DONE"));
+            commands.push(command.to_string());
+            let mut response = match command {
+                "CAPABILITY" => b"* CAPABILITY IMAP4rev2 IDLE\r\n".to_vec(),
+                "UID SEARCH UNSEEN" if idle_tag.is_some() => b"* SEARCH 3\r\n".to_vec(),
+                "UID SEARCH UNSEEN" => b"* SEARCH 1 2\r\n".to_vec(),
+                "UID FETCH 1 BODY.PEEK[]" => {
+                    let mut response =
+                        format!("* 1 FETCH (UID 1 BODY[] {{{}}}\r\n", report.len()).into_bytes();
+                    response.extend_from_slice(&report);
+                    response.extend_from_slice(b")\r\n");
+                    response
+                }
+                "UID FETCH 2 BODY.PEEK[]" | "UID FETCH 3 BODY.PEEK[]" => {
+                    b"* 2 FETCH (UID 2 BODY[] {5}\r\nHello)\r\n".to_vec()
+                }
+                "IDLE" if idle_tag.is_some() => break,
+                "IDLE" => {
+                    idle_tag = Some(tag.to_string());
+                    stream
+                        .write_all(b"+ idling\r\n* 3 EXISTS\r\n")
+                        .await
+                        .unwrap();
+                    continue;
+                }
+                _ => Vec::new(),
+            };
+            let tag = match command {
+                "DONE" => idle_tag.as_deref().unwrap(),
+                _ => tag,
+            };
+            response.extend_from_slice(format!("{tag} OK done\r\n").as_bytes());
+            stream.write_all(&response).await.unwrap();
+        }
+        commands
+    }
+
+    #[tokio::test]
+    async fn reads_reports() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let mailbox = BounceMailbox {
+            protocol: BounceProtocol::Imap,
+            server_url: "127.0.0.1".to_string(),
+            port: Some(listener.local_addr().unwrap().port()),
+            connection_type: ConnectionType::PlainUnsecure,
+            user: "alice".to_string(),
+            password: "secret \"pw\"".to_string(),
+            folder: "INBOX".to_string(),
+            poll_interval_secs: 60,
+            delete_reports: true,
+            dead_after: 3,
+        };
+        let server = tokio::spawn(fake_server(listener, dsn::tests::mail(None, "<a@b>")));
+        let mut messages = Vec::new();
+        let result = watch(&mailbox, &mut |message: &[u8]| {
+            messages.push(message.to_vec());
+            dsn::parse(message).is_some()
+        })
+        .await;
+        assert!(matches!(result, Err(Error::Closed)));
+        assert_eq!(messages.len(), 3);
+        assert_eq!(messages[1], b"Hello");
+
+        let commands = server.await.unwrap();
+        assert_eq!(
+            commands,
+            [
+                "LOGIN \"alice\" \"secret \\\"pw\\\"\"",
+                "SELECT \"INBOX\"",
+                "CAPABILITY",
+                "UID SEARCH UNSEEN",
+                "UID FETCH 1 BODY.PEEK[]",
+                "UID STORE 1 +FLAGS.SILENT (\\Deleted)",
+                "UID FETCH 2 BODY.PEEK[]",
+                "UID STORE 2 +FLAGS.SILENT (\\Seen)",
+                "EXPUNGE",
+                "IDLE",
+                "DONE",
+                "UID SEARCH UNSEEN",
+                "UID FETCH 3 BODY.PEEK[]",
+                "UID STORE 3 +FLAGS.SILENT (\\Seen)",
+                "IDLE",
+            ]
+        );
+    }
+}
@@ -0,0 +1,180 @@
+//! Minimal POP3 client (RFC 1939) which reads the messages of the mailbox not read before
+
+use super::{BounceMailbox, Connection, ConnectionType, Error, TIMEOUT};
+use std::collections::HashSet;
+
+struct Session {
+    connection: Connection,
+}
+
+impl Session {
+    /// Reads a status line, which must be positive
+    async fn status(&mut self) -> Result<String, Error> {
+        let line = self.connection.read_line(TIMEOUT).await?;
+        match line.strip_prefix("+OK") {
+            Some(text) => Ok(text.trim().to_string()),
+            None => Err(Error::Refused(line)),
+        }
+    }
+
+    async fn command(&mut self, command: &str) -> Result<String, Error> {
+        self.connection.write_line(command).await?;
+        self.status().await
+    }
+
+    /// Reads the lines of a multi-line response up to the terminating dot, removing the dot
+    /// stuffing
+    async fn lines(&mut self) -> Result<Vec<Vec<u8>>, Error> {
+        let mut lines = Vec::new();
+        loop {
+            let line = self.connection.read_line_bytes(TIMEOUT).await?;
+            match line.strip_prefix(b".") {
+                Some([]) => return Ok(lines),
+                Some(unstuffed) => lines.push(unstuffed.to_vec()),
+                None => lines.push(line),
+            }
+        }
+    }
+
+    async fn login(mailbox: &BounceMailbox) -> Result<Self, Error> {
+        let mut session = Self {
+            connection: Connection::open(mailbox).await?,
+        };
+        session.status().await?;
+        if mailbox.connection_type == ConnectionType::StartTls {
+            session.command("STLS").await?;
+            session.connection = session.connection.start_tls().await?;
+        }
+        session.command(&format!("USER {}", mailbox.user)).await?;
+        session
+            .command(&format!("PASS {}", mailbox.password))
+            .await?;
+        Ok(session)
+    }
+
+    /// Message numbers and unique ids of the messages in the mailbox
+    async fn unique_ids(&mut self) -> Result<Vec<(u32, String)>, Error> {
+        self.command("UIDL").await?;
+        Ok(self
+            .lines()
+            .await?
+            .iter()
+            .filter_map(|line| {
+                let line = String::from_utf8_lossy(line);
+                let (number, uid) = line.trim().split_once(' ')?;
+                Some((number.parse().ok()?, uid.to_string()))
+            })
+            .collect())
+    }
+
+    async fn retrieve(&mut self, number: u32) -> Result<Vec<u8>, Error> {
+        self.command(&format!("RETR {number}")).await?;
+        Ok(self.lines().await?.join(&b"\r\n"[..]))
+    }
+}
+
+/// Passes the messages which are not in `seen` to the handler and deletes the reports if
+/// configured, `seen` is updated to the unique ids of the remaining messages
+pub(super) async fn poll(
+    mailbox: &BounceMailbox,
+    seen: &mut HashSet<String>,
+    handle: &mut impl FnMut(&[u8]) -> bool,
+) -> Result<(), Error> {
+    let mut session = Session::login(mailbox).await?;
+    let messages = session.unique_ids().await?;
+    for (number, uid) in &messages {
+        if seen.contains(uid) {
+            continue;
+        }
+        let message = session.retrieve(*number).await?;
+        if handle(&message) && mailbox.delete_reports {
+            session.command(&format!("DELE {number}")).await?;
+        }
+        seen.insert(uid.clone());
+    }
+    seen.retain(|uid| messages.iter().any(|(_, message_uid)| message_uid == uid));
+    // Deletions are only applied once the session is closed with QUIT
+    session.command("QUIT").await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::dsn;
+    use super::*;
+    use crate::services::smtp::BounceProtocol;
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    /// Serves a report and a message with a dot stuffed line, returning the received commands
+    async fn fake_server(listener: tokio::net::TcpListener, report: Vec<u8>) -> Vec<String> {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut stream = BufReader::new(stream);
+        stream.write_all(b"+OK POP3 ready\r\n").await.unwrap();
+        let mut commands = Vec::new();
+        let mut line = String::new();
+        while stream.read_line(&mut line).await.unwrap() > 0 {
+            let command = std::mem::take(&mut line).trim_end().to_string();
+            let response = match command.as_str() {
+                "UIDL" => b"+OK\r\n1 report\r\n2 hello\r\n.\r\n".to_vec(),
+                "RETR 1" => {
+                    let mut response = b"+OK\r\n".to_vec();
+                    response.extend_from_slice(&report);
+                    response.extend_from_slice(b".\r\n");
+                    response
+                }
+                "RETR 2" => b"+OK\r\nHello\r\n..\r\n.\r\n".to_vec(),
+                _ => b"+OK\r\n".to_vec(),
+            };
+            stream.write_all(&response).await.unwrap();
+            commands.push(command);
+        }
+        commands
+    }
+
+    #[tokio::test]
+    async fn reads_reports() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let mailbox = BounceMailbox {
+            protocol: BounceProtocol::Pop3,
+            server_url: "127.0.0.1".to_string(),
+            port: Some(listener.local_addr().unwrap().port()),
+            connection_type: ConnectionType::PlainUnsecure,
+            user: "alice".to_string(),
+            password: "secret".to_string(),
+            folder: "INBOX".to_string(),
+            poll_interval_secs: 60,
+            delete_reports: true,
+            dead_after: 3,
+        };
+        let server = tokio::spawn(fake_server(listener, dsn::tests::mail(None, "<a@b>")));
+        let mut seen = HashSet::from(["gone".to_string()]);
+        let mut messages = Vec::new();
+        poll(&mailbox, &mut seen, &mut |message: &[u8]| {
+            messages.push(message.to_vec());
+            dsn::parse(message).is_some()
+        })
+        .await
+        .unwrap();
+        assert_eq!(messages.len(), 2);
+        assert!(dsn::parse(&messages[0]).is_some());
+        assert_eq!(messages[1], b"Hello\r\n.");
+        assert_eq!(
+            seen,
+            HashSet::from(["report".to_string(), "hello".to_string()])
+        );
+
+        let commands = server.await.unwrap();
+        assert_eq!(
+            commands,
+            [
+                "USER alice",
+                "PASS secret",
+                "UIDL",
+                "RETR 1",
+                "DELE 1",
+                "RETR 2",
+                "QUIT"
+            ]
+        );
+    }
+}
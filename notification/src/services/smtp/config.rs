@@ -6,20 +6,339 @@ pub use patch::ConfigPatch;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const DEFAULT_CONNECT_TIMEOUT_SECS: u64 = 10;
+const DEFAULT_COMMAND_TIMEOUT_SECS: u64 = 60;
+const DEFAULT_MAX_CONNECTIONS: usize = 2;
+const DEFAULT_IDLE_TIMEOUT_SECS: u64 = 30;
+const DEFAULT_BOUNCE_FOLDER: &str = "INBOX";
+const DEFAULT_POLL_INTERVAL_SECS: u64 = 300;
+const DEFAULT_DEAD_AFTER: u32 = 3;
+
+fn default_connect_timeout_secs() -> u64 {
+    DEFAULT_CONNECT_TIMEOUT_SECS
+}
+
+fn default_command_timeout_secs() -> u64 {
+    DEFAULT_COMMAND_TIMEOUT_SECS
+}
+
+fn default_max_connections() -> usize {
+    DEFAULT_MAX_CONNECTIONS
+}
+
+fn default_idle_timeout_secs() -> u64 {
+    DEFAULT_IDLE_TIMEOUT_SECS
+}
+
+fn default_bounce_folder() -> String {
+    DEFAULT_BOUNCE_FOLDER.to_string()
+}
+
+fn default_poll_interval_secs() -> u64 {
+    DEFAULT_POLL_INTERVAL_SECS
+}
+
+fn default_dead_after() -> u32 {
+    DEFAULT_DEAD_AFTER
+}
 
 #[derive(Debug, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
 pub enum ConnectionType {
+    /// Implicit TLS, by default on port 465
+    #[serde(alias = "ImplicitTls")]
     Tls,
+    /// Upgrade to TLS via STARTTLS, by default on port 587
     StartTls,
+    /// Without TLS, by default on port 25
     PlainUnsecure,
 }
 
+impl ConnectionType {
+    pub fn default_port(&self) -> u16 {
+        match self {
+            Self::Tls => lettre::transport::smtp::SUBMISSIONS_PORT,
+            Self::StartTls => lettre::transport::smtp::SUBMISSION_PORT,
+            Self::PlainUnsecure => lettre::transport::smtp::SMTP_PORT,
+        }
+    }
+}
+
+/// Minimum TLS version, TLS 1.3 cannot be required with the native TLS implementation
+#[derive(Debug, PartialEq, Clone, Copy, Default, Deserialize, Serialize, JsonSchema)]
+pub enum TlsVersion {
+    Tls10,
+    Tls11,
+    #[default]
+    Tls12,
+}
+
+/// PEM encoded data, given inline or as the path of a file
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(untagged, deny_unknown_fields)]
+pub enum Pem {
+    Inline { pem: String },
+    File { path: PathBuf },
+}
+
+impl Pem {
+    /// Inline data is replaced, paths are kept
+    fn redacted(&self) -> Self {
+        match self {
+            Self::Inline { .. } => Self::Inline {
+                pem: "***".to_string(),
+            },
+            Self::File { .. } => self.clone(),
+        }
+    }
+
+    pub fn read(&self) -> std::io::Result<Vec<u8>> {
+        match self {
+            Self::Inline { pem } => Ok(pem.as_bytes().to_vec()),
+            Self::File { path } => std::fs::read(path),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ClientCertificate {
+    pub certificate: Pem,
+    /// Private key in PKCS #8 format
+    pub key: Pem,
+}
+
+#[derive(Debug, Default, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TlsConfig {
+    /// CA certificates trusted besides the system roots, e.g. of an internal PKI
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ca_certificates: Vec<Pem>,
+    /// SHA-256 fingerprint of the server certificate as hex, colons are optional. The server is
+    /// trusted if its certificate has the fingerprint, regardless of the CA certificates
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub certificate_fingerprint: Option<String>,
+    /// Certificate and key presented to the server for mutual TLS
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_certificate: Option<ClientCertificate>,
+    #[serde(default)]
+    pub min_version: TlsVersion,
+    /// Accept certificates issued for other hostnames, only meant for lab setups
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub accept_invalid_hostnames: bool,
+}
+
+impl TlsConfig {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+pub enum DkimAlgorithm {
+    Rsa,
+    Ed25519,
+}
+
+/// Signs mails with DKIM, so receivers can verify that they were sent on behalf of the domain
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Dkim {
+    /// Selector of the public key in DNS, published at `<selector>._domainkey.<domain>`
+    pub selector: String,
+    pub domain: String,
+    pub algorithm: DkimAlgorithm,
+    /// RSA key in PKCS #1 PEM format or the base64 encoded 32 byte Ed25519 key
+    pub private_key: Pem,
+}
+
+/// Signs mails with S/MIME and optionally encrypts them, both as `application/pkcs7-mime`
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Smime {
+    /// Certificate of the sender, which has to be issued for the address of the sender
+    pub certificate: Pem,
+    /// Private key of the certificate
+    pub key: Pem,
+    /// Certificates of the CAs between the certificate and the root, included in the signature
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub intermediate_certificates: Vec<Pem>,
+    /// Encrypts mails to the certificates of the receivers, mails are not sent if a receiver
+    /// has no certificate
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub encrypt: bool,
+}
+
+/// How receivers without an OpenPGP key are handled when mails are encrypted
+#[derive(Debug, PartialEq, Clone, Copy, Default, Deserialize, Serialize, JsonSchema)]
+pub enum MissingKeyPolicy {
+    /// The mail is not sent to them
+    #[default]
+    Skip,
+    /// They get a separate mail, which is signed if there is a signing key but not encrypted
+    Unencrypted,
+}
+
+/// Signs and encrypts mails with OpenPGP/MIME (RFC 3156)
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct OpenPgp {
+    /// Armored secret key of the sender, mails are signed if it is given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signing_key: Option<Pem>,
+    /// Passphrase of the signing key
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub passphrase: Option<String>,
+    /// Encrypts mails to the public keys of the receivers
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub encrypt: bool,
+    /// Directory with the armored public keys of receivers without `public_key`, named after
+    /// their address, e.g. `bob@example.com.asc`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keyring: Option<PathBuf>,
+    #[serde(default)]
+    pub missing_key: MissingKeyPolicy,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+pub enum BounceProtocol {
+    /// New reports are pushed via IDLE if the server supports it, by default on port 993 with
+    /// implicit TLS and on port 143 otherwise
+    Imap,
+    /// By default on port 995 with implicit TLS and on port 110 otherwise
+    Pop3,
+}
+
+/// Mailbox which receives the delivery status notifications (RFC 3464) of the sent mails,
+/// usually the mailbox of the sender
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BounceMailbox {
+    pub protocol: BounceProtocol,
+    pub server_url: String,
+    /// Port of the server, defaults to the port of the protocol and the connection type
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    pub connection_type: ConnectionType,
+    pub user: String,
+    pub password: String,
+    /// Folder which is searched for reports, only used by IMAP
+    #[serde(default = "default_bounce_folder")]
+    pub folder: String,
+    /// Seconds between checks for new reports, IMAP servers supporting IDLE are checked at least
+    /// this often
+    #[serde(default = "default_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+    /// Deletes processed reports from the mailbox, otherwise IMAP marks them as seen and POP3
+    /// skips them until notis is restarted
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub delete_reports: bool,
+    /// Number of failed deliveries in a row after which a receiver is considered dead
+    #[serde(default = "default_dead_after")]
+    pub dead_after: u32,
+}
+
+impl BounceMailbox {
+    pub fn port(&self) -> u16 {
+        self.port
+            .unwrap_or(match (self.protocol, &self.connection_type) {
+                (BounceProtocol::Imap, ConnectionType::Tls) => 993,
+                (BounceProtocol::Imap, _) => 143,
+                (BounceProtocol::Pop3, ConnectionType::Tls) => 995,
+                (BounceProtocol::Pop3, _) => 110,
+            })
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_secs(self.poll_interval_secs.max(1))
+    }
+}
+
+/// How access tokens for XOAUTH2 are obtained
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(tag = "type")]
+pub enum Grant {
+    /// Tokens of the client itself, e.g. of an app registration in Microsoft Entra ID
+    ClientCredentials,
+    /// Tokens of a user who authorized the client, the refresh token is updated in the config if
+    /// the token endpoint issues a new one
+    RefreshToken { refresh_token: String },
+}
+
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct OAuth2 {
+    /// Url of the token endpoint, e.g. https://oauth2.googleapis.com/token
+    pub token_url: String,
+    pub client_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scopes: Vec<String>,
+    pub grant: Grant,
+}
+
+/// Connections kept open to send subsequent notifications without a new handshake and login
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PoolConfig {
+    /// Maximum number of connections opened at the same time, further notifications wait for a
+    /// free connection
+    #[serde(default = "default_max_connections")]
+    pub max_connections: usize,
+    /// Seconds after which idle connections are closed, 0 closes connections after each mail
+    #[serde(default = "default_idle_timeout_secs")]
+    pub idle_timeout_secs: u64,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            max_connections: DEFAULT_MAX_CONNECTIONS,
+            idle_timeout_secs: DEFAULT_IDLE_TIMEOUT_SECS,
+        }
+    }
+}
+
+impl PoolConfig {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn idle_timeout(&self) -> Duration {
+        Duration::from_secs(self.idle_timeout_secs)
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct Config {
     pub server_url: String,
+    /// Port of the server, defaults to the port of the connection type
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    /// Hostname sent with EHLO, defaults to the hostname of this machine
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hello_name: Option<String>,
+    /// Seconds to wait for the connection, including the TLS handshake and the greeting
+    #[serde(default = "default_connect_timeout_secs")]
+    pub connect_timeout_secs: u64,
+    /// Seconds to wait for the response to each command and to the transfer of the mail
+    #[serde(default = "default_command_timeout_secs")]
+    pub command_timeout_secs: u64,
+    #[serde(default, skip_serializing_if = "PoolConfig::is_default")]
+    pub pool: PoolConfig,
     #[schemars(with = "schema::Credentials")]
     pub credentials: lettre::transport::smtp::authentication::Credentials,
+    /// Authenticates via XOAUTH2 as the user of the credentials with access tokens obtained from
+    /// the token endpoint instead of the secret of the credentials
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oauth2: Option<OAuth2>,
     pub connection_type: ConnectionType,
+    /// Settings of the `Tls` and `StartTls` connections
+    #[serde(default, skip_serializing_if = "TlsConfig::is_default")]
+    pub tls: TlsConfig,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[schemars(with = "Option<schema::Mechanism>")]
     pub auth_mechanism: Option<lettre::transport::smtp::authentication::Mechanism>,
@@ -27,6 +346,23 @@ pub struct Config {
     pub receivers: Vec<Mailbox>,
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub receiver_groups: HashMap<String, Vec<Mailbox>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cc: Vec<Mailbox>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bcc: Vec<Mailbox>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reply_to: Vec<Mailbox>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dkim: Option<Dkim>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub smime: Option<Smime>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub openpgp: Option<OpenPgp>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bounce_mailbox: Option<BounceMailbox>,
+    /// Custom headers of the mails, e.g. `X-Machine-Id`
+    #[serde(default, skip_serializing_if = "Headers::is_empty")]
+    pub headers: Headers,
     pub total_attachment_size_limit: Option<usize>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub encryption_password: Option<String>,
@@ -37,6 +373,86 @@ pub struct Mailbox {
     pub name: Option<String>,
     #[schemars(email, with = "String")]
     pub email: lettre::Address,
+    /// S/MIME certificate of the receiver, which mails are encrypted to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub certificate: Option<Pem>,
+    /// Armored OpenPGP public key of the receiver, which mails are encrypted to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<Pem>,
+}
+
+/// Headers which are set from the notification and the config
+const RESERVED_HEADERS: [&str; 13] = [
+    "From",
+    "Sender",
+    "To",
+    "Cc",
+    "Bcc",
+    "Reply-To",
+    "Subject",
+    "Date",
+    "Message-ID",
+    "MIME-Version",
+    "Content-Type",
+    "Content-Transfer-Encoding",
+    "Content-Disposition",
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum HeaderError {
+    #[error("Invalid header name {0:?}")]
+    Name(String),
+    #[error("The header {0} cannot be customized")]
+    Reserved(String),
+    #[error("The value of the header {0} contains control characters")]
+    Value(String),
+}
+
+/// Custom mail headers, names and values are checked so they cannot inject further headers
+#[derive(Debug, Default, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(try_from = "HashMap<String, String>", into = "HashMap<String, String>")]
+pub struct Headers(HashMap<String, String>);
+
+impl TryFrom<HashMap<String, String>> for Headers {
+    type Error = HeaderError;
+
+    fn try_from(headers: HashMap<String, String>) -> Result<Self, HeaderError> {
+        for (name, value) in &headers {
+            // Printable ascii without colon as of RFC 5322, lettre limits names to 76 characters
+            if name.is_empty()
+                || name.len() > 76
+                || !name.bytes().all(|b| b.is_ascii_graphic() && b != b':')
+            {
+                return Err(HeaderError::Name(name.clone()));
+            }
+            if RESERVED_HEADERS
+                .iter()
+                .any(|reserved| reserved.eq_ignore_ascii_case(name))
+            {
+                return Err(HeaderError::Reserved(name.clone()));
+            }
+            if value.chars().any(|c| c.is_control() && c != '\t') {
+                return Err(HeaderError::Value(name.clone()));
+            }
+        }
+        Ok(Self(headers))
+    }
+}
+
+impl From<Headers> for HashMap<String, String> {
+    fn from(value: Headers) -> Self {
+        value.0
+    }
+}
+
+impl Headers {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.0.iter()
+    }
 }
 
 impl From<Mailbox> for lettre::message::Mailbox {
@@ -49,24 +465,37 @@ impl Config {
     pub fn example() -> Self {
         Self {
             server_url: "smtp.example.com".to_string(),
+            port: None,
+            hello_name: None,
+            connect_timeout_secs: DEFAULT_CONNECT_TIMEOUT_SECS,
+            command_timeout_secs: DEFAULT_COMMAND_TIMEOUT_SECS,
+            pool: PoolConfig::default(),
             credentials: lettre::transport::smtp::authentication::Credentials::new(
                 "my_user".to_string(),
                 "my_password".to_string(),
             ),
+            oauth2: None,
             auth_mechanism: Some(lettre::transport::smtp::authentication::Mechanism::Login),
             connection_type: ConnectionType::Tls,
+            tls: TlsConfig::default(),
             sender: Mailbox {
                 name: Some("Alice".to_string()),
                 email: lettre::Address::new("alice", "mail.com").unwrap(),
+                certificate: None,
+                public_key: None,
             },
             receivers: vec![
                 Mailbox {
                     name: Some("Bob".to_string()),
                     email: lettre::Address::new("bob", "bob-self-hosting.com").unwrap(),
+                    certificate: None,
+                    public_key: None,
                 },
                 Mailbox {
                     name: Some("Charlie".to_string()),
                     email: lettre::Address::new("charlie", "mail.ca").unwrap(),
+                    certificate: None,
+                    public_key: None,
                 },
             ],
             receiver_groups: HashMap::from([
@@ -76,10 +505,14 @@ impl Config {
                         Mailbox {
                             name: Some("Dave".to_string()),
                             email: lettre::Address::new("dave", "mail.nl").unwrap(),
+                            certificate: None,
+                            public_key: None,
                         },
                         Mailbox {
                             name: Some("Eric".to_string()),
                             email: lettre::Address::new("eric", "mail.es").unwrap(),
+                            certificate: None,
+                            public_key: None,
                         },
                     ],
                 ),
@@ -89,23 +522,63 @@ impl Config {
                         Mailbox {
                             name: Some("Fiona".to_string()),
                             email: lettre::Address::new("fiona", "mail.fr").unwrap(),
+                            certificate: None,
+                            public_key: None,
                         },
                         Mailbox {
                             name: Some("Gina".to_string()),
                             email: lettre::Address::new("gina", "mail.es").unwrap(),
+                            certificate: None,
+                            public_key: None,
                         },
                         Mailbox {
                             name: Some("Hera".to_string()),
                             email: lettre::Address::new("hera", "mail.de").unwrap(),
+                            certificate: None,
+                            public_key: None,
                         },
                     ],
                 ),
             ]),
+            cc: Vec::new(),
+            bcc: Vec::new(),
+            reply_to: Vec::new(),
+            dkim: None,
+            smime: None,
+            openpgp: None,
+            bounce_mailbox: None,
+            headers: Headers::default(),
             total_attachment_size_limit: Some(1024 * 1024 * 100),
             encryption_password: Some("my_encryption_pw".to_string()),
         }
     }
 
+    pub fn port(&self) -> u16 {
+        self.port
+            .unwrap_or_else(|| self.connection_type.default_port())
+    }
+
+    pub fn connect_timeout(&self) -> Duration {
+        Duration::from_secs(self.connect_timeout_secs.max(1))
+    }
+
+    pub fn command_timeout(&self) -> Duration {
+        Duration::from_secs(self.command_timeout_secs.max(1))
+    }
+
+    /// User of the credentials
+    pub fn authentication_identity(&self) -> String {
+        serde_json::to_value(&self.credentials)
+            .ok()
+            .and_then(|credentials| {
+                credentials
+                    .get("authentication_identity")?
+                    .as_str()
+                    .map(str::to_string)
+            })
+            .unwrap_or_default()
+    }
+
     pub fn redacted(&self) -> Self {
         let credentials_json: serde_json::Value =
             serde_json::from_str(&serde_json::to_string(&self.credentials).unwrap()).unwrap();
@@ -121,6 +594,47 @@ impl Config {
                 "***".to_string(),
             ),
             encryption_password: self.encryption_password.as_ref().map(|_| "***".to_string()),
+            oauth2: self.oauth2.clone().map(|oauth2| OAuth2 {
+                client_secret: oauth2.client_secret.map(|_| "***".to_string()),
+                grant: match oauth2.grant {
+                    Grant::RefreshToken { .. } => Grant::RefreshToken {
+                        refresh_token: "***".to_string(),
+                    },
+                    grant => grant,
+                },
+                ..oauth2
+            }),
+            dkim: self.dkim.clone().map(|dkim| Dkim {
+                private_key: dkim.private_key.redacted(),
+                ..dkim
+            }),
+            smime: self.smime.clone().map(|smime| Smime {
+                key: smime.key.redacted(),
+                ..smime
+            }),
+            openpgp: self.openpgp.clone().map(|openpgp| OpenPgp {
+                signing_key: openpgp.signing_key.as_ref().map(Pem::redacted),
+                passphrase: openpgp.passphrase.map(|_| "***".to_string()),
+                ..openpgp
+            }),
+            bounce_mailbox: self
+                .bounce_mailbox
+                .clone()
+                .map(|bounce_mailbox| BounceMailbox {
+                    password: "***".to_string(),
+                    ..bounce_mailbox
+                }),
+            tls: TlsConfig {
+                client_certificate: self
+                    .tls
+                    .client_certificate
+                    .clone()
+                    .map(|client_certificate| ClientCertificate {
+                        key: client_certificate.key.redacted(),
+                        ..client_certificate
+                    }),
+                ..self.tls.clone()
+            },
             ..self.clone()
         }
     }
@@ -133,9 +647,30 @@ impl NotificationServiceConfig for Config {
         if let Some(server_url) = patch.server_url {
             self.server_url = server_url;
         }
+        if let Some(port) = patch.port {
+            self.port = port;
+        }
+        if let Some(hello_name) = patch.hello_name {
+            self.hello_name = hello_name;
+        }
+        if let Some(connect_timeout_secs) = patch.connect_timeout_secs {
+            self.connect_timeout_secs = connect_timeout_secs;
+        }
+        if let Some(command_timeout_secs) = patch.command_timeout_secs {
+            self.command_timeout_secs = command_timeout_secs;
+        }
+        if let Some(pool) = patch.pool {
+            self.pool = pool;
+        }
         if let Some(credentials) = patch.credentials {
             self.credentials = credentials;
         }
+        if let Some(oauth2) = patch.oauth2 {
+            self.oauth2 = oauth2;
+        }
+        if let Some(tls) = patch.tls {
+            self.tls = tls;
+        }
         if let Some(auth_mechanism) = patch.auth_mechanism {
             self.auth_mechanism = auth_mechanism;
         }
@@ -148,6 +683,30 @@ impl NotificationServiceConfig for Config {
         if let Some(receivers) = patch.receivers {
             self.receivers = receivers;
         }
+        if let Some(cc) = patch.cc {
+            self.cc = cc;
+        }
+        if let Some(bcc) = patch.bcc {
+            self.bcc = bcc;
+        }
+        if let Some(reply_to) = patch.reply_to {
+            self.reply_to = reply_to;
+        }
+        if let Some(dkim) = patch.dkim {
+            self.dkim = dkim;
+        }
+        if let Some(smime) = patch.smime {
+            self.smime = smime;
+        }
+        if let Some(openpgp) = patch.openpgp {
+            self.openpgp = openpgp;
+        }
+        if let Some(bounce_mailbox) = patch.bounce_mailbox {
+            self.bounce_mailbox = bounce_mailbox;
+        }
+        if let Some(headers) = patch.headers {
+            self.headers = headers;
+        }
         if let Some(total_attachment_size_limit) = patch.total_attachment_size_limit {
             self.total_attachment_size_limit = total_attachment_size_limit;
         }
@@ -1,4 +1,7 @@
-use crate::services::smtp::{ConnectionType, Mailbox};
+use crate::services::smtp::{
+    BounceMailbox, ConnectionType, Dkim, Headers, Mailbox, OAuth2, OpenPgp, PoolConfig, Smime,
+    TlsConfig,
+};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -7,11 +10,40 @@ use std::collections::HashMap;
 pub struct ConfigPatch {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub server_url: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "::serde_with::rust::double_option"
+    )]
+    #[schemars(with = "Option<Option<u16>>")]
+    pub port: Option<Option<u16>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "::serde_with::rust::double_option"
+    )]
+    #[schemars(with = "Option<Option<String>>")]
+    pub hello_name: Option<Option<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connect_timeout_secs: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command_timeout_secs: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pool: Option<PoolConfig>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[schemars(with = "super::schema::Credentials")]
     pub credentials: Option<lettre::transport::smtp::authentication::Credentials>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "::serde_with::rust::double_option"
+    )]
+    #[schemars(with = "Option<Option<OAuth2>>")]
+    pub oauth2: Option<Option<OAuth2>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub connection_type: Option<ConnectionType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<TlsConfig>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -25,6 +57,42 @@ pub struct ConfigPatch {
     pub receivers: Option<Vec<Mailbox>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub receiver_groups: Option<HashMap<String, Vec<Mailbox>>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cc: Option<Vec<Mailbox>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bcc: Option<Vec<Mailbox>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply_to: Option<Vec<Mailbox>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "::serde_with::rust::double_option"
+    )]
+    #[schemars(with = "Option<Option<Dkim>>")]
+    pub dkim: Option<Option<Dkim>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "::serde_with::rust::double_option"
+    )]
+    #[schemars(with = "Option<Option<Smime>>")]
+    pub smime: Option<Option<Smime>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "::serde_with::rust::double_option"
+    )]
+    #[schemars(with = "Option<Option<OpenPgp>>")]
+    pub openpgp: Option<Option<OpenPgp>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "::serde_with::rust::double_option"
+    )]
+    #[schemars(with = "Option<Option<BounceMailbox>>")]
+    pub bounce_mailbox: Option<Option<BounceMailbox>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub headers: Option<Headers>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -44,6 +112,9 @@ pub struct ConfigPatch {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::services::smtp::{
+        BounceProtocol, DkimAlgorithm, Grant, MissingKeyPolicy, Pem, TlsVersion,
+    };
     use lettre::transport::smtp::authentication::Mechanism;
 
     #[test]
@@ -51,12 +122,27 @@ mod tests {
         let s = r#"{}"#;
         let p = ConfigPatch {
             server_url: None,
+            port: None,
+            hello_name: None,
+            connect_timeout_secs: None,
+            command_timeout_secs: None,
+            pool: None,
             credentials: None,
+            oauth2: None,
             connection_type: None,
+            tls: None,
             auth_mechanism: None,
             sender: None,
             receivers: None,
             receiver_groups: None,
+            cc: None,
+            bcc: None,
+            reply_to: None,
+            dkim: None,
+            smime: None,
+            openpgp: None,
+            bounce_mailbox: None,
+            headers: None,
             total_attachment_size_limit: None,
             encryption_password: None,
         };
@@ -64,15 +150,30 @@ mod tests {
         assert_eq!(s, serde_json::to_string(&p).unwrap());
 
         // Unset Value
-        let s = r#"{"auth_mechanism":null,"receiver_groups":{},"total_attachment_size_limit":null,"encryption_password":null}"#;
+        let s = r#"{"port":null,"hello_name":null,"oauth2":null,"auth_mechanism":null,"receiver_groups":{},"cc":[],"smime":null,"openpgp":null,"bounce_mailbox":null,"headers":{},"total_attachment_size_limit":null,"encryption_password":null}"#;
         let p = ConfigPatch {
             server_url: None,
+            port: Some(None),
+            hello_name: Some(None),
+            connect_timeout_secs: None,
+            command_timeout_secs: None,
+            pool: None,
             credentials: None,
+            oauth2: Some(None),
             connection_type: None,
+            tls: None,
             auth_mechanism: Some(None),
             sender: None,
             receivers: None,
             receiver_groups: Some(HashMap::new()),
+            cc: Some(Vec::new()),
+            bcc: None,
+            reply_to: None,
+            dkim: None,
+            smime: Some(None),
+            openpgp: Some(None),
+            bounce_mailbox: Some(None),
+            headers: Some(Headers::default()),
             total_attachment_size_limit: Some(None),
             encryption_password: Some(None),
         };
@@ -80,11 +181,37 @@ mod tests {
         assert_eq!(s, serde_json::to_string(&p).unwrap());
 
         // Existing Value
-        let s = r#"{"auth_mechanism":"Login","receiver_groups":{"gods":[{"name":"Zeus","email":"godfather@olympus.gr"},{"name":"Hera","email":"moon@olympus.gr"}]},"total_attachment_size_limit":100,"encryption_password":"some_pw"}"#;
+        let s = r#"{"port":2525,"hello_name":"notis.example.com","connect_timeout_secs":30,"command_timeout_secs":120,"pool":{"max_connections":4,"idle_timeout_secs":0},"oauth2":{"token_url":"https://oauth2.googleapis.com/token","client_id":"notis","scopes":["https://mail.google.com/"],"grant":{"type":"RefreshToken","refresh_token":"1//token"}},"tls":{"ca_certificates":[{"path":"/etc/ssl/plant-ca.pem"}],"min_version":"Tls12","accept_invalid_hostnames":true},"auth_mechanism":"Login","receiver_groups":{"gods":[{"name":"Zeus","email":"godfather@olympus.gr"},{"name":"Hera","email":"moon@olympus.gr"}]},"bcc":[{"name":null,"email":"archive@olympus.gr"}],"reply_to":[{"name":"Tickets","email":"tickets@olympus.gr","certificate":{"path":"/etc/notis/tickets.pem"},"public_key":{"path":"/etc/notis/tickets.asc"}}],"dkim":{"selector":"notis","domain":"olympus.gr","algorithm":"Ed25519","private_key":{"path":"/etc/notis/dkim.key"}},"smime":{"certificate":{"path":"/etc/notis/smime.pem"},"key":{"path":"/etc/notis/smime.key"},"encrypt":true},"openpgp":{"signing_key":{"path":"/etc/notis/openpgp.asc"},"encrypt":true,"keyring":"/etc/notis/keyring","missing_key":"Unencrypted"},"bounce_mailbox":{"protocol":"Imap","server_url":"imap.olympus.gr","connection_type":"Tls","user":"notis","password":"some_pw","folder":"Bounces","poll_interval_secs":60,"delete_reports":true,"dead_after":5},"headers":{"X-Machine-Id":"press-3"},"total_attachment_size_limit":100,"encryption_password":"some_pw"}"#;
         let p = ConfigPatch {
             server_url: None,
+            port: Some(Some(2525)),
+            hello_name: Some(Some("notis.example.com".to_string())),
+            connect_timeout_secs: Some(30),
+            command_timeout_secs: Some(120),
+            pool: Some(PoolConfig {
+                max_connections: 4,
+                idle_timeout_secs: 0,
+            }),
             credentials: None,
+            oauth2: Some(Some(OAuth2 {
+                token_url: "https://oauth2.googleapis.com/token".to_string(),
+                client_id: "notis".to_string(),
+                client_secret: None,
+                scopes: vec!["https://mail.google.com/".to_string()],
+                grant: Grant::RefreshToken {
+                    refresh_token: "1//token".to_string(),
+                },
+            })),
             connection_type: None,
+            tls: Some(TlsConfig {
+                ca_certificates: vec![Pem::File {
+                    path: "/etc/ssl/plant-ca.pem".into(),
+                }],
+                certificate_fingerprint: None,
+                client_certificate: None,
+                min_version: TlsVersion::Tls12,
+                accept_invalid_hostnames: true,
+            }),
             auth_mechanism: Some(Some(Mechanism::Login)),
             sender: None,
             receivers: None,
@@ -94,17 +221,93 @@ mod tests {
                     Mailbox {
                         name: Some("Zeus".to_string()),
                         email: lettre::Address::new("godfather", "olympus.gr").unwrap(),
+                        certificate: None,
+                        public_key: None,
                     },
                     Mailbox {
                         name: Some("Hera".to_string()),
                         email: lettre::Address::new("moon", "olympus.gr").unwrap(),
+                        certificate: None,
+                        public_key: None,
                     },
                 ],
             )])),
+            cc: None,
+            bcc: Some(vec![Mailbox {
+                name: None,
+                email: lettre::Address::new("archive", "olympus.gr").unwrap(),
+                certificate: None,
+                public_key: None,
+            }]),
+            reply_to: Some(vec![Mailbox {
+                name: Some("Tickets".to_string()),
+                email: lettre::Address::new("tickets", "olympus.gr").unwrap(),
+                certificate: Some(Pem::File {
+                    path: "/etc/notis/tickets.pem".into(),
+                }),
+                public_key: Some(Pem::File {
+                    path: "/etc/notis/tickets.asc".into(),
+                }),
+            }]),
+            dkim: Some(Some(Dkim {
+                selector: "notis".to_string(),
+                domain: "olympus.gr".to_string(),
+                algorithm: DkimAlgorithm::Ed25519,
+                private_key: Pem::File {
+                    path: "/etc/notis/dkim.key".into(),
+                },
+            })),
+            smime: Some(Some(Smime {
+                certificate: Pem::File {
+                    path: "/etc/notis/smime.pem".into(),
+                },
+                key: Pem::File {
+                    path: "/etc/notis/smime.key".into(),
+                },
+                intermediate_certificates: Vec::new(),
+                encrypt: true,
+            })),
+            openpgp: Some(Some(OpenPgp {
+                signing_key: Some(Pem::File {
+                    path: "/etc/notis/openpgp.asc".into(),
+                }),
+                passphrase: None,
+                encrypt: true,
+                keyring: Some("/etc/notis/keyring".into()),
+                missing_key: MissingKeyPolicy::Unencrypted,
+            })),
+            bounce_mailbox: Some(Some(BounceMailbox {
+                protocol: BounceProtocol::Imap,
+                server_url: "imap.olympus.gr".to_string(),
+                port: None,
+                connection_type: ConnectionType::Tls,
+                user: "notis".to_string(),
+                password: "some_pw".to_string(),
+                folder: "Bounces".to_string(),
+                poll_interval_secs: 60,
+                delete_reports: true,
+                dead_after: 5,
+            })),
+            headers: Some(
+                Headers::try_from(HashMap::from([(
+                    "X-Machine-Id".to_string(),
+                    "press-3".to_string(),
+                )]))
+                .unwrap(),
+            ),
             total_attachment_size_limit: Some(Some(100)),
             encryption_password: Some(Some("some_pw".to_string())),
         };
         assert_eq!(p, serde_json::from_str(s).unwrap());
         assert_eq!(s, serde_json::to_string(&p).unwrap());
+
+        // Header injection
+        for s in [
+            r#"{"headers":{"X-Machine-Id":"press-3\r\nBcc: eve@evil.com"}}"#,
+            r#"{"headers":{"X-Machine-Id\r\nBcc":"eve@evil.com"}}"#,
+            r#"{"headers":{"bcc":"eve@evil.com"}}"#,
+        ] {
+            assert!(serde_json::from_str::<ConfigPatch>(s).is_err());
+        }
     }
 }
@@ -0,0 +1,264 @@
+//! Access tokens for XOAUTH2, which are cached per service and refreshed before they expire
+
+use super::{Grant, OAuth2};
+use crate::services::run_blocking;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+use tracing::info;
+
+/// Tokens are refreshed when they expire within this margin
+const REFRESH_MARGIN: Duration = Duration::from_secs(300);
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Tokens per service id
+static TOKENS: LazyLock<Mutex<HashMap<String, Tokens>>> = LazyLock::new(Default::default);
+/// Refresh tokens issued in exchange for the refresh token of the config per service id
+static ROTATED: LazyLock<Mutex<HashMap<String, String>>> = LazyLock::new(Default::default);
+/// Serializes requests to token endpoints, so a refresh token is not redeemed twice
+static REQUESTS: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Http(#[from] ureq::Error),
+    #[error("The token endpoint rejected the request with status {status}: {message}")]
+    Rejected { status: u16, message: String },
+}
+
+impl Error {
+    /// Errors which will not resolve by retrying, e.g. a revoked refresh token
+    pub fn is_permanent(&self) -> bool {
+        match self {
+            Self::Rejected { status, .. } => {
+                (400..500).contains(status) && !matches!(status, 408 | 429)
+            }
+            Self::Http(_) => false,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: Option<u64>,
+    refresh_token: Option<String>,
+}
+
+struct Tokens {
+    /// Settings the tokens were issued for, without the refresh token
+    settings: OAuth2,
+    access_token: String,
+    expires_at: Option<Instant>,
+    /// Latest refresh token and the refresh token it replaced
+    refresh_token: Option<String>,
+    replaced: Option<String>,
+}
+
+/// Settings without the refresh token, which changes when it is rotated
+fn settings(oauth2: &OAuth2) -> OAuth2 {
+    OAuth2 {
+        grant: match oauth2.grant {
+            Grant::RefreshToken { .. } => Grant::RefreshToken {
+                refresh_token: String::new(),
+            },
+            Grant::ClientCredentials => Grant::ClientCredentials,
+        },
+        ..oauth2.clone()
+    }
+}
+
+impl Tokens {
+    /// Whether the tokens were issued for the settings, the refresh token of the config may still
+    /// be the replaced one until the rotated one is persisted
+    fn issued_for(&self, oauth2: &OAuth2) -> bool {
+        self.settings == settings(oauth2)
+            && match &oauth2.grant {
+                Grant::RefreshToken { refresh_token } => {
+                    self.refresh_token.as_ref() == Some(refresh_token)
+                        || self.replaced.as_ref() == Some(refresh_token)
+                }
+                Grant::ClientCredentials => true,
+            }
+    }
+
+    fn is_fresh(&self) -> bool {
+        self.expires_at
+            .is_none_or(|expires_at| Instant::now() + REFRESH_MARGIN < expires_at)
+    }
+}
+
+fn request(oauth2: &OAuth2, refresh_token: Option<&str>) -> Result<TokenResponse, Error> {
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(TIMEOUT))
+        .http_status_as_error(false)
+        .build()
+        .into();
+    let scope = oauth2.scopes.join(" ");
+    let mut form = vec![("client_id", oauth2.client_id.as_str())];
+    match refresh_token {
+        Some(refresh_token) => {
+            form.push(("grant_type", "refresh_token"));
+            form.push(("refresh_token", refresh_token));
+        }
+        None => form.push(("grant_type", "client_credentials")),
+    }
+    if let Some(client_secret) = &oauth2.client_secret {
+        form.push(("client_secret", client_secret));
+    }
+    if !scope.is_empty() {
+        form.push(("scope", &scope));
+    }
+    let mut response = agent.post(&oauth2.token_url).send_form(form)?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(Error::Rejected {
+            status: status.as_u16(),
+            message: response.body_mut().read_to_string().unwrap_or_default(),
+        });
+    }
+    Ok(response.body_mut().read_json()?)
+}
+
+/// Access token of the service, which is requested if there is no token which is still valid
+pub async fn access_token(service_id: &str, oauth2: &OAuth2) -> Result<String, Error> {
+    let _request = REQUESTS.lock().await;
+    let refresh_token = {
+        let tokens = TOKENS.lock().unwrap();
+        match tokens.get(service_id) {
+            Some(tokens) if tokens.issued_for(oauth2) => {
+                if tokens.is_fresh() {
+                    return Ok(tokens.access_token.clone());
+                }
+                tokens.refresh_token.clone()
+            }
+            _ => match &oauth2.grant {
+                Grant::RefreshToken { refresh_token } => Some(refresh_token.clone()),
+                Grant::ClientCredentials => None,
+            },
+        }
+    };
+    info!("Requesting OAuth2 access token...");
+    let response = {
+        let oauth2 = oauth2.clone();
+        let refresh_token = refresh_token.clone();
+        run_blocking(move || request(&oauth2, refresh_token.as_deref())).await?
+    };
+    info!("... Ok");
+    let rotated = response
+        .refresh_token
+        .filter(|issued| refresh_token.is_some() && refresh_token.as_ref() != Some(issued));
+    if let Some(rotated) = &rotated {
+        ROTATED
+            .lock()
+            .unwrap()
+            .insert(service_id.to_string(), rotated.clone());
+    }
+    let tokens = Tokens {
+        settings: settings(oauth2),
+        access_token: response.access_token.clone(),
+        expires_at: response
+            .expires_in
+            .map(|expires_in| Instant::now() + Duration::from_secs(expires_in)),
+        replaced: rotated.as_ref().and(refresh_token.clone()),
+        refresh_token: rotated.or(refresh_token),
+    };
+    TOKENS
+        .lock()
+        .unwrap()
+        .insert(service_id.to_string(), tokens);
+    Ok(response.access_token)
+}
+
+/// Drops the access token of the service, e.g. after the server rejected it
+pub fn forget(service_id: &str) {
+    if let Some(tokens) = TOKENS.lock().unwrap().get_mut(service_id) {
+        tokens.expires_at = Some(Instant::now());
+    }
+}
+
+/// Refresh tokens which replaced the refresh tokens of the config of services, the config has to
+/// be updated as the replaced refresh tokens may be revoked
+pub fn take_rotated_refresh_tokens() -> HashMap<String, String> {
+    std::mem::take(&mut *ROTATED.lock().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+
+    /// Answers each request with the next response and returns the request bodies
+    fn token_endpoint(
+        responses: Vec<&'static str>,
+    ) -> (String, std::thread::JoinHandle<Vec<String>>) {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/token", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let mut bodies = Vec::new();
+            for response in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some((name, value)) = line.split_once(':')
+                        && name.eq_ignore_ascii_case("content-length")
+                    {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+                bodies.push(String::from_utf8(body).unwrap());
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                    response.len()
+                )
+                .unwrap();
+            }
+            bodies
+        });
+        (url, handle)
+    }
+
+    #[tokio::test]
+    async fn refresh_token_rotation() {
+        let (token_url, endpoint) = token_endpoint(vec![
+            r#"{"access_token":"a1","expires_in":60,"refresh_token":"r2"}"#,
+            r#"{"access_token":"a2","expires_in":3600}"#,
+        ]);
+        let mut oauth2 = OAuth2 {
+            token_url,
+            client_id: "notis".to_string(),
+            client_secret: Some("secret".to_string()),
+            scopes: vec!["https://outlook.office.com/SMTP.Send".to_string()],
+            grant: Grant::RefreshToken {
+                refresh_token: "r1".to_string(),
+            },
+        };
+        let service_id = "oauth2-rotation";
+        assert_eq!(access_token(service_id, &oauth2).await.unwrap(), "a1");
+        let rotated = take_rotated_refresh_tokens().remove(service_id);
+        assert_eq!(rotated.as_deref(), Some("r2"));
+        // a1 expires within the refresh margin and is refreshed with the rotated refresh token,
+        // even though the config was not updated yet
+        assert_eq!(access_token(service_id, &oauth2).await.unwrap(), "a2");
+        oauth2.grant = Grant::RefreshToken {
+            refresh_token: "r2".to_string(),
+        };
+        assert_eq!(access_token(service_id, &oauth2).await.unwrap(), "a2");
+
+        let bodies = endpoint.join().unwrap();
+        assert!(bodies[0].contains("grant_type=refresh_token"));
+        assert!(bodies[0].contains("refresh_token=r1"));
+        assert!(bodies[0].contains("scope=https%3A%2F%2Foutlook.office.com%2FSMTP.Send"));
+        assert!(bodies[1].contains("refresh_token=r2"));
+    }
+}
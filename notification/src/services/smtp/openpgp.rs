@@ -0,0 +1,428 @@
+//! OpenPGP/MIME (RFC 3156), mails are signed as `multipart/signed` and encrypted, including the
+//! signature, as `multipart/encrypted`
+
+use super::{Mailbox, MissingKeyPolicy, OpenPgp, Recipients};
+use lettre::message::header::{ContentDisposition, ContentType};
+use lettre::message::{MultiPart, SinglePart};
+use pgp::composed::{
+    ArmorOptions, Deserializable, DetachedSignature, MessageBuilder, SignedPublicKey,
+    SignedSecretKey,
+};
+use pgp::crypto::hash::HashAlgorithm;
+use pgp::crypto::sym::SymmetricKeyAlgorithm;
+use pgp::types::{KeyDetails, Password, SigningKey};
+use rand_core::OsRng;
+use tracing::warn;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Pgp(#[from] pgp::errors::Error),
+    #[error(transparent)]
+    IO(#[from] std::io::Error),
+    #[error("The OpenPGP key of {0} cannot be used for encryption")]
+    EncryptionKey(lettre::Address),
+    #[error("None of the receivers has an OpenPGP key")]
+    NoReceiverKeys,
+}
+
+impl Error {
+    /// Errors which will not resolve by retrying
+    pub fn is_permanent(&self) -> bool {
+        match self {
+            Self::Pgp(_) | Self::EncryptionKey(_) | Self::NoReceiverKeys => true,
+            Self::IO(_) => false,
+        }
+    }
+}
+
+fn content_type(content_type: &str) -> ContentType {
+    ContentType::parse(content_type).expect("The content type is valid")
+}
+
+/// Public key of the mailbox, or the key named after its address in the keyring
+fn public_key(openpgp: &OpenPgp, mailbox: &Mailbox) -> Result<Option<SignedPublicKey>, Error> {
+    let armored = match (&mailbox.public_key, &openpgp.keyring) {
+        (Some(public_key), _) => public_key.read()?,
+        (None, Some(keyring)) => {
+            match std::fs::read(keyring.join(format!("{}.asc", mailbox.email))) {
+                Ok(armored) => armored,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+                Err(e) => return Err(e.into()),
+            }
+        }
+        (None, None) => return Ok(None),
+    };
+    Ok(Some(SignedPublicKey::from_armor_single(&armored[..])?.0))
+}
+
+/// Subkey flagged for signing, or the primary key
+fn signing_key(key: &SignedSecretKey) -> &dyn SigningKey {
+    key.secret_subkeys
+        .iter()
+        .find(|subkey| {
+            subkey
+                .signatures
+                .iter()
+                .any(|signature| signature.key_flags().sign())
+        })
+        .map_or(&key.primary_key, |subkey| &subkey.key)
+}
+
+struct Signer {
+    key: SignedSecretKey,
+    passphrase: Option<String>,
+}
+
+impl Signer {
+    fn new(openpgp: &OpenPgp) -> Result<Option<Self>, Error> {
+        let Some(signing_key) = &openpgp.signing_key else {
+            return Ok(None);
+        };
+        Ok(Some(Self {
+            key: SignedSecretKey::from_armor_single(&signing_key.read()?[..])?.0,
+            passphrase: openpgp.passphrase.clone(),
+        }))
+    }
+
+    fn passphrase(&self) -> Password {
+        self.passphrase
+            .as_deref()
+            .map_or_else(Password::empty, Password::from)
+    }
+
+    /// Entity with a detached signature over it as `multipart/signed`
+    fn sign(&self, entity: MultiPart) -> Result<MultiPart, Error> {
+        // The line break before the boundary which follows the entity is not signed
+        let formatted = entity.formatted();
+        let signed = formatted.strip_suffix(b"\r\n").unwrap_or(&formatted);
+        let signature = DetachedSignature::sign_binary_data(
+            OsRng,
+            &Box::new(signing_key(&self.key)),
+            &self.passphrase(),
+            HashAlgorithm::Sha256,
+            signed,
+        )?;
+        Ok(MultiPart::signed(
+            "application/pgp-signature".to_string(),
+            "pgp-sha256".to_string(),
+        )
+        .multipart(entity)
+        .singlepart(
+            SinglePart::builder()
+                .header(content_type(
+                    "application/pgp-signature; name=\"signature.asc\"",
+                ))
+                .header(ContentDisposition::attachment("signature.asc"))
+                .body(signature.to_armored_string(ArmorOptions::default())?),
+        ))
+    }
+}
+
+/// Entity encrypted to the keys as `multipart/encrypted`, the signature is part of the encrypted
+/// message
+fn encrypt(
+    entity: &MultiPart,
+    keys: &[(&Mailbox, SignedPublicKey)],
+    signer: Option<&Signer>,
+) -> Result<MultiPart, Error> {
+    let mut builder = MessageBuilder::from_bytes("", entity.formatted())
+        .seipd_v1(OsRng, SymmetricKeyAlgorithm::AES256);
+    for (mailbox, key) in keys {
+        let subkey = key.public_subkeys.iter().find(|subkey| {
+            subkey.signatures.iter().any(|signature| {
+                let flags = signature.key_flags();
+                flags.encrypt_comms() || flags.encrypt_storage()
+            })
+        });
+        match subkey {
+            Some(subkey) => builder.encrypt_to_key(OsRng, subkey)?,
+            None if key.primary_key.algorithm().can_encrypt() => {
+                builder.encrypt_to_key(OsRng, key)?
+            }
+            None => return Err(Error::EncryptionKey(mailbox.email.clone())),
+        };
+    }
+    if let Some(signer) = signer {
+        builder.sign(
+            signing_key(&signer.key),
+            signer.passphrase(),
+            HashAlgorithm::Sha256,
+        );
+    }
+    let encrypted = builder.to_armored_string(OsRng, ArmorOptions::default())?;
+    Ok(
+        MultiPart::encrypted("application/pgp-encrypted".to_string())
+            .singlepart(
+                SinglePart::builder()
+                    .header(content_type("application/pgp-encrypted"))
+                    .body("Version: 1".to_string()),
+            )
+            .singlepart(
+                SinglePart::builder()
+                    .header(content_type(
+                        "application/octet-stream; name=\"encrypted.asc\"",
+                    ))
+                    .header(ContentDisposition::inline_with_name("encrypted.asc"))
+                    .body(encrypted),
+            ),
+    )
+}
+
+/// Bodies of the mail with the receivers they are sent to, receivers without key get a separate
+/// unencrypted body or none, depending on the policy. Each Bcc receiver gets a message encrypted
+/// to their key only, so the key ids of the message do not reveal them to the other receivers.
+pub(super) fn protect<'a>(
+    openpgp: &OpenPgp,
+    entity: MultiPart,
+    recipients: &'a Recipients,
+) -> Result<Vec<(MultiPart, Vec<&'a Mailbox>)>, Error> {
+    let signer = Signer::new(openpgp)?;
+    let sign = |entity| match &signer {
+        Some(signer) => signer.sign(entity),
+        None => Ok(entity),
+    };
+    if !openpgp.encrypt {
+        return Ok(vec![(sign(entity)?, recipients.all().collect())]);
+    }
+    let groups = std::iter::once(recipients.to.iter().chain(&recipients.cc).collect())
+        .chain(recipients.bcc.iter().map(|mailbox| vec![mailbox]));
+    let mut bodies = Vec::new();
+    let mut without_key = Vec::new();
+    for group in groups {
+        let mut keys = Vec::new();
+        for mailbox in group {
+            match public_key(openpgp, mailbox)? {
+                Some(key) => keys.push((mailbox, key)),
+                None => without_key.push(mailbox),
+            }
+        }
+        if !keys.is_empty() {
+            let encrypted = encrypt(&entity, &keys, signer.as_ref())?;
+            bodies.push((
+                encrypted,
+                keys.into_iter().map(|(mailbox, _)| mailbox).collect(),
+            ));
+        }
+    }
+    if !without_key.is_empty() {
+        match openpgp.missing_key {
+            MissingKeyPolicy::Skip => {
+                for mailbox in &without_key {
+                    warn!("Skipping {}, who has no OpenPGP key", mailbox.email);
+                }
+            }
+            MissingKeyPolicy::Unencrypted => bodies.push((sign(entity)?, without_key)),
+        }
+    }
+    if bodies.is_empty() {
+        return Err(Error::NoReceiverKeys);
+    }
+    Ok(bodies)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::smtp::{Config, Pem};
+    use pgp::composed::{
+        EncryptionCaps, KeyType, Message, SecretKeyParamsBuilder, SubkeyParamsBuilder,
+    };
+    use pgp::crypto::ecc_curve::ECCCurve;
+
+    /// Secret key with a subkey for encryption and its armored public key
+    fn key(user_id: &str) -> (SignedSecretKey, Pem) {
+        let params = SecretKeyParamsBuilder::default()
+            .key_type(KeyType::Ed25519Legacy)
+            .can_certify(true)
+            .can_sign(true)
+            .primary_user_id(user_id.into())
+            .subkeys(vec![
+                SubkeyParamsBuilder::default()
+                    .key_type(KeyType::ECDH(ECCCurve::Curve25519Legacy))
+                    .can_encrypt(EncryptionCaps::All)
+                    .build()
+                    .unwrap(),
+            ])
+            .build()
+            .unwrap();
+        let key = params.generate(OsRng).unwrap();
+        let public_key = key
+            .to_public_key()
+            .to_armored_string(ArmorOptions::default())
+            .unwrap();
+        (key, Pem::Inline { pem: public_key })
+    }
+
+    fn entity() -> MultiPart {
+        MultiPart::mixed().singlepart(SinglePart::plain("Pressure dropped".to_string()))
+    }
+
+    /// Armored content of the second part of the multipart
+    fn armored(multipart: &MultiPart) -> String {
+        let formatted = String::from_utf8(multipart.formatted()).unwrap();
+        let start = formatted.find("-----BEGIN").unwrap();
+        let end = formatted.rfind("-----\r\n").unwrap() + 5;
+        formatted[start..end].to_string()
+    }
+
+    #[test]
+    fn signing_and_encryption() {
+        let (alice, _) = key("Alice <alice@mail.com>");
+        let (bob, bob_public_key) = key("Bob <bob@bob-self-hosting.com>");
+        let config = Config::example();
+        let mut bob_mailbox = config.receivers[0].clone();
+        bob_mailbox.public_key = Some(bob_public_key);
+        let charlie_mailbox = config.receivers[1].clone();
+        let mut openpgp = OpenPgp {
+            signing_key: Some(Pem::Inline {
+                pem: alice.to_armored_string(ArmorOptions::default()).unwrap(),
+            }),
+            passphrase: None,
+            encrypt: false,
+            keyring: None,
+            missing_key: MissingKeyPolicy::Skip,
+        };
+
+        let recipients = |to: &[&Mailbox]| Recipients {
+            to: to.iter().map(|mailbox| (*mailbox).clone()).collect(),
+            ..Default::default()
+        };
+        let to_bob = recipients(&[&bob_mailbox]);
+        let bodies = protect(&openpgp, entity(), &to_bob).unwrap();
+        let formatted = String::from_utf8(bodies[0].0.formatted()).unwrap();
+        assert!(formatted.contains("multipart/signed"));
+        assert!(formatted.contains("protocol=\"application/pgp-signature\""));
+        // The signature covers the entity as it is embedded between the boundaries
+        let boundary = bodies[0].0.boundary();
+        let signed = formatted
+            .split(&format!("--{boundary}\r\n"))
+            .nth(1)
+            .unwrap()
+            .strip_suffix("\r\n")
+            .unwrap();
+        let (signature, _) = DetachedSignature::from_string(&armored(&bodies[0].0)).unwrap();
+        signature
+            .verify(&alice.to_public_key(), signed.as_bytes())
+            .unwrap();
+
+        openpgp.encrypt = true;
+        let bob_and_charlie = recipients(&[&bob_mailbox, &charlie_mailbox]);
+        let bodies = protect(&openpgp, entity(), &bob_and_charlie).unwrap();
+        assert_eq!(bodies.len(), 1);
+        assert_eq!(bodies[0].1, vec![&bob_mailbox]);
+        let formatted = String::from_utf8(bodies[0].0.formatted()).unwrap();
+        assert!(formatted.contains("multipart/encrypted"));
+        assert!(formatted.contains("Version: 1"));
+        assert!(!formatted.contains("Pressure dropped"));
+        let encrypted = armored(&bodies[0].0);
+        let (message, _) = Message::from_string(&encrypted).unwrap();
+        let mut decrypted = message.decrypt(&Password::empty(), &bob).unwrap();
+        let content = decrypted.as_data_string().unwrap();
+        assert!(content.contains("Pressure dropped"));
+        decrypted.verify(&alice.to_public_key()).unwrap();
+
+        // Charlie gets a signed mail instead
+        openpgp.missing_key = MissingKeyPolicy::Unencrypted;
+        let bob_and_charlie = recipients(&[&bob_mailbox, &charlie_mailbox]);
+        let bodies = protect(&openpgp, entity(), &bob_and_charlie).unwrap();
+        assert_eq!(bodies.len(), 2);
+        assert_eq!(bodies[1].1, vec![&charlie_mailbox]);
+        let formatted = String::from_utf8(bodies[1].0.formatted()).unwrap();
+        assert!(formatted.contains("multipart/signed"));
+        assert!(formatted.contains("Pressure dropped"));
+
+        openpgp.missing_key = MissingKeyPolicy::Skip;
+        assert!(matches!(
+            protect(&openpgp, entity(), &recipients(&[&charlie_mailbox])),
+            Err(Error::NoReceiverKeys)
+        ));
+    }
+
+    #[test]
+    fn bcc_is_encrypted_separately() {
+        let openpgp = OpenPgp {
+            signing_key: None,
+            passphrase: None,
+            encrypt: true,
+            keyring: None,
+            missing_key: MissingKeyPolicy::Skip,
+        };
+        let receiver = |email: &str| {
+            let (key, public_key) = key(&format!("<{email}>"));
+            let mailbox = Mailbox {
+                name: None,
+                email: email.parse().unwrap(),
+                certificate: None,
+                public_key: Some(public_key),
+            };
+            (mailbox, move |body: &MultiPart| {
+                let armored = armored(body);
+                let (message, _) = Message::from_string(&armored).unwrap();
+                message.decrypt(&Password::empty(), &key).is_ok()
+            })
+        };
+        let (bob, bob_decrypts) = receiver("bob@mail.com");
+        let (carol, carol_decrypts) = receiver("carol@mail.com");
+        let (dave, dave_decrypts) = receiver("dave@mail.com");
+        let recipients = Recipients {
+            to: vec![bob.clone()],
+            bcc: vec![carol.clone(), dave.clone()],
+            ..Default::default()
+        };
+        let bodies = protect(&openpgp, entity(), &recipients).unwrap();
+        let receivers: Vec<_> = bodies
+            .iter()
+            .map(|(_, receivers)| receivers.clone())
+            .collect();
+        assert_eq!(receivers, [vec![&bob], vec![&carol], vec![&dave]]);
+        let decrypts: Vec<_> = bodies
+            .iter()
+            .map(|(body, _)| {
+                [
+                    bob_decrypts(body),
+                    carol_decrypts(body),
+                    dave_decrypts(body),
+                ]
+            })
+            .collect();
+        assert_eq!(
+            decrypts,
+            [
+                [true, false, false],
+                [false, true, false],
+                [false, false, true]
+            ]
+        );
+    }
+
+    #[test]
+    fn keyring() {
+        let (_, bob_public_key) = key("Bob <bob@bob-self-hosting.com>");
+        let keyring = std::env::temp_dir().join(format!("notis-keyring-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir(&keyring).unwrap();
+        let Pem::Inline { pem } = bob_public_key else {
+            unreachable!()
+        };
+        std::fs::write(keyring.join("bob@bob-self-hosting.com.asc"), pem).unwrap();
+        let openpgp = OpenPgp {
+            signing_key: None,
+            passphrase: None,
+            encrypt: true,
+            keyring: Some(keyring.clone()),
+            missing_key: MissingKeyPolicy::Skip,
+        };
+        let config = Config::example();
+        assert!(
+            public_key(&openpgp, &config.receivers[0])
+                .unwrap()
+                .is_some()
+        );
+        assert!(
+            public_key(&openpgp, &config.receivers[1])
+                .unwrap()
+                .is_none()
+        );
+        std::fs::remove_dir_all(keyring).unwrap();
+    }
+}
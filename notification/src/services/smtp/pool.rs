@@ -0,0 +1,181 @@
+//! Connections of smtp services which are kept open between notifications
+
+use super::{Config, Error, MailServer, with_timeout};
+use lettre::transport::smtp::client::AsyncSmtpConnection;
+use lettre::transport::smtp::commands::{Noop, Rset};
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::{debug, warn};
+
+/// Interval in which connections idle for longer than the idle timeout are closed
+const IDLE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Pools per service id
+static POOLS: LazyLock<Mutex<HashMap<String, Pool>>> = LazyLock::new(Default::default);
+
+/// Authenticated connection
+pub(super) struct Connection {
+    pub smtp: AsyncSmtpConnection,
+    /// Whether the server supports delivery status notifications
+    pub dsn: bool,
+    idle_since: Instant,
+}
+
+impl Connection {
+    pub fn new(smtp: AsyncSmtpConnection, dsn: bool) -> Self {
+        Self {
+            smtp,
+            dsn,
+            idle_since: Instant::now(),
+        }
+    }
+}
+
+struct Pool {
+    /// Config the connections were opened with
+    config: Config,
+    idle: Vec<Connection>,
+    /// Limits the number of open connections
+    permits: Arc<Semaphore>,
+}
+
+impl Pool {
+    fn new(config: &Config) -> Self {
+        Self {
+            config: config.clone(),
+            idle: Vec::new(),
+            permits: Arc::new(Semaphore::new(config.pool.max_connections.max(1))),
+        }
+    }
+
+    /// Closes the connections idle for longer than the idle timeout
+    fn close_expired(&mut self) {
+        let idle_timeout = self.config.pool.idle_timeout();
+        let (idle, expired) = std::mem::take(&mut self.idle)
+            .into_iter()
+            .partition(|connection| connection.idle_since.elapsed() < idle_timeout);
+        self.idle = idle;
+        close(expired);
+    }
+}
+
+/// Connection taken from the pool of a service, which has to be released after the mail is sent
+pub(super) struct Lease {
+    service_id: String,
+    config: Config,
+    pub connection: Connection,
+    _permit: OwnedSemaphorePermit,
+}
+
+/// Closes the connections in the background
+fn close(connections: Vec<Connection>) {
+    for mut connection in connections {
+        tokio::spawn(async move {
+            let _ = connection.smtp.quit().await;
+        });
+    }
+}
+
+/// Permits of the pool of the service, the pool is emptied if the config of the service changed
+fn permits(service_id: &str, config: &Config) -> Arc<Semaphore> {
+    let mut pools = POOLS.lock().unwrap();
+    let pool = pools
+        .entry(service_id.to_string())
+        .or_insert_with(|| Pool::new(config));
+    if pool.config != *config {
+        close(std::mem::replace(pool, Pool::new(config)).idle);
+    }
+    pool.permits.clone()
+}
+
+/// Takes the most recently used connection, connections idle for longer than the idle timeout
+/// are closed
+fn take_idle(service_id: &str, config: &Config) -> Option<Connection> {
+    let mut pools = POOLS.lock().unwrap();
+    let pool = pools.get_mut(service_id)?;
+    if pool.config != *config {
+        return None;
+    }
+    pool.close_expired();
+    pool.idle.pop()
+}
+
+/// Removes the pool of a service which was deleted or changed, closing its idle connections.
+/// Connections in use are closed once they are released.
+pub fn evict(service_id: &str) {
+    if let Some(pool) = POOLS.lock().unwrap().remove(service_id) {
+        close(pool.idle);
+    }
+}
+
+/// Closes the connections of all pools once they are idle for longer than their idle timeout,
+/// also if the service sends no further notifications
+pub async fn run() {
+    loop {
+        tokio::time::sleep(IDLE_CHECK_INTERVAL).await;
+        close_expired();
+    }
+}
+
+fn close_expired() {
+    for pool in POOLS.lock().unwrap().values_mut() {
+        pool.close_expired();
+    }
+}
+
+/// Reuses an idle connection of the service which passes a health check, or opens a new one
+pub(super) async fn acquire(service_id: &str, config: &Config) -> Result<Lease, Error> {
+    let permit = permits(service_id, config)
+        .acquire_owned()
+        .await
+        .expect("The semaphore of a pool is never closed");
+    let lease = |connection| Lease {
+        service_id: service_id.to_string(),
+        config: config.clone(),
+        connection,
+        _permit: permit,
+    };
+    while let Some(mut connection) = take_idle(service_id, config) {
+        match with_timeout(config.command_timeout(), connection.smtp.command(Noop)).await {
+            Ok(_) => {
+                debug!("Reusing smtp connection");
+                return Ok(lease(connection));
+            }
+            Err(e) => debug!("Discarding idle smtp connection: {e}"),
+        }
+    }
+    Ok(lease(MailServer::open(service_id, config).await?))
+}
+
+impl Lease {
+    /// Resets the connection and returns it into the pool, unless the config changed meanwhile
+    pub async fn release(mut self) {
+        if self.config.pool.idle_timeout_secs == 0 {
+            // Connections are not reused
+            let _ = self.connection.smtp.quit().await;
+            return;
+        }
+        if let Err(e) = with_timeout(
+            self.config.command_timeout(),
+            self.connection.smtp.command(Rset),
+        )
+        .await
+        {
+            warn!("Discarding smtp connection which could not be reset: {e}");
+            return;
+        }
+        self.connection.idle_since = Instant::now();
+        let mut pools = POOLS.lock().unwrap();
+        match pools.get_mut(&self.service_id) {
+            Some(pool) if pool.config == self.config => pool.idle.push(self.connection),
+            _ => close(vec![self.connection]),
+        }
+    }
+
+    /// Closes the connection after a failed delivery, which may have left it in an unknown state
+    pub fn discard(self) {
+        drop(self.connection);
+    }
+}
@@ -0,0 +1,875 @@
+mod ber;
+mod config;
+mod usm;
+
+use crate::services::{Attachment, Metadata, NotificationService, run_blocking};
+pub use config::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+use std::sync::LazyLock;
+use std::time::{Duration, Instant};
+use tracing::{debug, info, info_span, warn};
+
+const DEFAULT_PORT: u16 = 162;
+const MAX_MESSAGE_SIZE: i64 = 65507;
+const SYS_UP_TIME: &str = "1.3.6.1.2.1.1.3.0";
+const SNMP_TRAP_OID: &str = "1.3.6.1.6.3.1.1.4.1.0";
+const USM_STATS_NOT_IN_TIME_WINDOWS: &str = "1.3.6.1.6.3.15.1.1.2.0";
+/// usmStatsUnsupportedSecLevels, usmStatsUnknownUserNames, usmStatsWrongDigests and
+/// usmStatsDecryptionErrors, which report a config not matching the one of the manager
+const PERMANENT_REPORTS: [&str; 4] = [
+    "1.3.6.1.6.3.15.1.1.1.0",
+    "1.3.6.1.6.3.15.1.1.3.0",
+    "1.3.6.1.6.3.15.1.1.5.0",
+    "1.3.6.1.6.3.15.1.1.6.0",
+];
+/// snmpEngineID of notis, enterprise specific text format below the documentation enterprise number
+const LOCAL_ENGINE_ID: &[u8] = &[0x80, 0x00, 0x7e, 0xd9, 0x04, b'n', b'o', b't', b'i', b's'];
+
+const VERSION_2C: i64 = 1;
+const VERSION_3: i64 = 3;
+const USM_SECURITY_MODEL: i64 = 3;
+const FLAG_AUTH: u8 = 0x01;
+const FLAG_PRIV: u8 = 0x02;
+const FLAG_REPORTABLE: u8 = 0x04;
+/// Security flags of the informs, which their responses must have as well
+const FLAGS_AUTH_PRIV: u8 = FLAG_AUTH | FLAG_PRIV;
+
+const GET_REQUEST: u8 = 0xa0;
+const RESPONSE: u8 = 0xa2;
+const INFORM_REQUEST: u8 = 0xa6;
+const SNMPV2_TRAP: u8 = 0xa7;
+const REPORT: u8 = 0xa8;
+
+static START: LazyLock<Instant> = LazyLock::new(Instant::now);
+
+#[derive(Default)]
+pub struct Snmp;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    IO(#[from] std::io::Error),
+    #[error("Malformed snmp message: {0}")]
+    Ber(#[from] ber::Error),
+    #[error("Unsupported snmp version {0}")]
+    Version(i64),
+    #[error("Could not resolve snmp manager {manager}")]
+    UnknownManager { manager: String },
+    #[error("The inform to {manager} was not acknowledged")]
+    NotAcknowledged { manager: String },
+    #[error("The snmp manager {manager} reported {oid}")]
+    Report { manager: String, oid: String },
+    #[error("The snmp manager {manager} answered with error status {status}")]
+    ErrorStatus { manager: String, status: i64 },
+    #[error("The response of snmp manager {manager} failed authentication")]
+    Authentication { manager: String },
+    #[error("{}", failures(.0))]
+    Failed(Vec<(String, Error)>),
+    #[error("{}, the other managers received the notification", failures(.0))]
+    PartiallyFailed(Vec<(String, Error)>),
+}
+
+fn failures(failures: &[(String, Error)]) -> String {
+    failures
+        .iter()
+        .map(|(manager, error)| format!("{manager}: {error}"))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+impl Error {
+    /// Errors which will not resolve by retrying, e.g. a user or security level unknown to the
+    /// manager
+    pub fn is_permanent(&self) -> bool {
+        match self {
+            Self::Report { oid, .. } => PERMANENT_REPORTS.contains(&oid.as_str()),
+            Self::Failed(failures) | Self::PartiallyFailed(failures) => {
+                failures.iter().all(|(_, error)| error.is_permanent())
+            }
+            Self::IO(_)
+            | Self::Ber(_)
+            | Self::Version(_)
+            | Self::UnknownManager { .. }
+            | Self::NotAcknowledged { .. }
+            | Self::ErrorStatus { .. }
+            | Self::Authentication { .. } => false,
+        }
+    }
+
+    /// The options with the managers restricted to those which failed transiently, so a retry
+    /// does not send the notification twice to the managers which received it
+    pub fn retry_options(&self, options: Option<&serde_json::Value>) -> Option<serde_json::Value> {
+        let (Self::Failed(failures) | Self::PartiallyFailed(failures)) = self else {
+            return None;
+        };
+        let retry_managers: Vec<_> = failures
+            .iter()
+            .filter(|(_, error)| !error.is_permanent())
+            .map(|(manager, _)| manager.clone())
+            .collect();
+        let mut options = options.cloned().unwrap_or_else(|| serde_json::json!({}));
+        options
+            .as_object_mut()?
+            .insert("retry_managers".to_string(), retry_managers.into());
+        Some(options)
+    }
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+pub struct NotificationOptions {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    severity: Option<Severity>,
+    /// Managers the notification is sent to. Set by the delivery queue when retrying the managers
+    /// which failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    retry_managers: Option<Vec<String>>,
+}
+
+struct Notification<'a> {
+    service_id: &'a str,
+    title: &'a str,
+    content: &'a str,
+    severity: Severity,
+}
+
+impl Notification<'_> {
+    fn variable_bindings(&self, enterprise_oid: &[u32]) -> Vec<u8> {
+        let object = |id: u32| -> Vec<u8> {
+            let mut oid = enterprise_oid.to_vec();
+            oid.extend_from_slice(&[1, id, 0]);
+            ber::object_identifier(&oid)
+        };
+        let mut notification_oid = enterprise_oid.to_vec();
+        notification_oid.extend_from_slice(&[0, 1]);
+        let up_time = START.elapsed().as_millis() / 10;
+        ber::sequence(&[
+            ber::sequence(&[
+                ber::object_identifier(&ber::parse_oid(SYS_UP_TIME).unwrap()),
+                ber::unsigned(ber::TIME_TICKS, up_time as u32),
+            ]),
+            ber::sequence(&[
+                ber::object_identifier(&ber::parse_oid(SNMP_TRAP_OID).unwrap()),
+                ber::object_identifier(&notification_oid),
+            ]),
+            ber::sequence(&[object(1), ber::octet_string(self.service_id.as_bytes())]),
+            ber::sequence(&[object(2), ber::octet_string(self.title.as_bytes())]),
+            ber::sequence(&[object(3), ber::octet_string(self.content.as_bytes())]),
+            ber::sequence(&[object(4), ber::integer(self.severity as i64)]),
+        ])
+    }
+}
+
+fn pdu(tag: u8, request_id: i32, variable_bindings: Vec<u8>) -> Vec<u8> {
+    ber::constructed(
+        tag,
+        &[
+            ber::integer(request_id as i64),
+            ber::integer(0),
+            ber::integer(0),
+            variable_bindings,
+        ],
+    )
+}
+
+fn resolve(manager: &str) -> Result<SocketAddr, Error> {
+    manager
+        .to_socket_addrs()
+        .or_else(|_| (manager, DEFAULT_PORT).to_socket_addrs())
+        .ok()
+        .and_then(|mut addresses| addresses.next())
+        .ok_or_else(|| Error::UnknownManager {
+            manager: manager.to_string(),
+        })
+}
+
+fn connect(address: SocketAddr, timeout: Duration) -> Result<UdpSocket, Error> {
+    let socket = if address.is_ipv4() {
+        UdpSocket::bind("0.0.0.0:0")?
+    } else {
+        UdpSocket::bind("[::]:0")?
+    };
+    socket.connect(address)?;
+    socket.set_read_timeout(Some(timeout))?;
+    Ok(socket)
+}
+
+fn offset_in(outer: &[u8], inner: &[u8]) -> usize {
+    inner.as_ptr() as usize - outer.as_ptr() as usize
+}
+
+/// Engine parameters of the authoritative engine of a manager, i.e. the receiver of informs
+struct RemoteEngine {
+    engine_id: Vec<u8>,
+    boots: u32,
+    time: u32,
+    discovered: Instant,
+}
+
+impl RemoteEngine {
+    fn time(&self) -> u32 {
+        self.time
+            .saturating_add(self.discovered.elapsed().as_secs() as u32)
+    }
+}
+
+/// Decoded SNMPv3 message with the position of the authentication parameters in the raw message
+struct V3Message<'a> {
+    message_id: i64,
+    flags: u8,
+    engine_id: &'a [u8],
+    boots: u32,
+    time: u32,
+    auth_params: &'a [u8],
+    auth_params_offset: usize,
+    priv_params: &'a [u8],
+    data: ber::Element<'a>,
+}
+
+impl<'a> V3Message<'a> {
+    fn decode(raw: &'a [u8]) -> Result<Self, Error> {
+        let mut message = ber::Reader::new(raw).expect(ber::SEQUENCE)?.children();
+        let version = message.expect(ber::INTEGER)?.as_integer()?;
+        if version != VERSION_3 {
+            return Err(Error::Version(version));
+        }
+        let mut header = message.expect(ber::SEQUENCE)?.children();
+        let message_id = header.expect(ber::INTEGER)?.as_integer()?;
+        header.expect(ber::INTEGER)?;
+        let flags = header
+            .expect(ber::OCTET_STRING)?
+            .value
+            .first()
+            .copied()
+            .unwrap_or_default();
+        let security_parameters = message.expect(ber::OCTET_STRING)?;
+        let mut usm = ber::Reader::new(security_parameters.value)
+            .expect(ber::SEQUENCE)?
+            .children();
+        let engine_id = usm.expect(ber::OCTET_STRING)?.value;
+        let boots = usm.expect(ber::INTEGER)?.as_integer()? as u32;
+        let time = usm.expect(ber::INTEGER)?.as_integer()? as u32;
+        usm.expect(ber::OCTET_STRING)?;
+        let auth_params = usm.expect(ber::OCTET_STRING)?.value;
+        let priv_params = usm.expect(ber::OCTET_STRING)?.value;
+        let data = message.next_element()?;
+        Ok(Self {
+            message_id,
+            flags,
+            engine_id,
+            boots,
+            time,
+            auth_params,
+            auth_params_offset: offset_in(raw, auth_params),
+            priv_params,
+            data,
+        })
+    }
+}
+
+/// Decoded PDU with the OID of the first variable binding, which identifies the cause of reports
+struct Pdu {
+    tag: u8,
+    request_id: i64,
+    error_status: i64,
+    first_oid: Option<String>,
+}
+
+impl Pdu {
+    fn decode_scoped(scoped_pdu: &[u8]) -> Result<Self, Error> {
+        let mut scoped_pdu = ber::Reader::new(scoped_pdu)
+            .expect(ber::SEQUENCE)?
+            .children();
+        scoped_pdu.expect(ber::OCTET_STRING)?;
+        scoped_pdu.expect(ber::OCTET_STRING)?;
+        let pdu = scoped_pdu.next_element()?;
+        let mut fields = pdu.children();
+        let request_id = fields.expect(ber::INTEGER)?.as_integer()?;
+        let error_status = fields.expect(ber::INTEGER)?.as_integer()?;
+        fields.expect(ber::INTEGER)?;
+        let mut variable_bindings = fields.expect(ber::SEQUENCE)?.children();
+        let first_oid = if variable_bindings.is_empty() {
+            None
+        } else {
+            let oid = variable_bindings
+                .expect(ber::SEQUENCE)?
+                .children()
+                .expect(ber::OBJECT_IDENTIFIER)?
+                .as_oid()?;
+            Some(oid.iter().map(u32::to_string).collect::<Vec<_>>().join("."))
+        };
+        Ok(Self {
+            tag: pdu.tag,
+            request_id,
+            error_status,
+            first_oid,
+        })
+    }
+}
+
+struct UserSecurity<'a> {
+    user_name: &'a str,
+    auth_protocol: AuthProtocol,
+    auth_key: Vec<u8>,
+    priv_protocol: PrivProtocol,
+    priv_key: Vec<u8>,
+}
+
+impl<'a> UserSecurity<'a> {
+    fn new(security: &'a Security, engine_id: &[u8]) -> Option<Self> {
+        match security {
+            Security::V2c { .. } => None,
+            Security::V3 {
+                user_name,
+                auth_protocol,
+                auth_password,
+                priv_protocol,
+                priv_password,
+            } => Some(Self {
+                user_name,
+                auth_protocol: *auth_protocol,
+                auth_key: auth_protocol.localized_key(auth_password, engine_id),
+                priv_protocol: *priv_protocol,
+                priv_key: auth_protocol.localized_key(priv_password, engine_id),
+            }),
+        }
+    }
+
+    fn encode(
+        &self,
+        engine: &RemoteEngine,
+        message_id: i32,
+        scoped_pdu: &[u8],
+    ) -> Result<Vec<u8>, Error> {
+        let boots = engine.boots;
+        let time = engine.time();
+        let salt: [u8; 8] = rand::random();
+        let encrypted = self
+            .priv_protocol
+            .encrypt(&self.priv_key, boots, time, &salt, scoped_pdu);
+        let mut message = v3_message(
+            message_id,
+            FLAGS_AUTH_PRIV | FLAG_REPORTABLE,
+            &engine.engine_id,
+            boots,
+            time,
+            self.user_name,
+            &vec![0; self.auth_protocol.mac_length()],
+            &salt,
+            ber::octet_string(&encrypted),
+        );
+        let decoded = V3Message::decode(&message)?;
+        let offset = decoded.auth_params_offset;
+        let length = decoded.auth_params.len();
+        let mac = self.auth_protocol.sign(&self.auth_key, &message);
+        message[offset..offset + length].copy_from_slice(&mac);
+        Ok(message)
+    }
+
+    fn authenticate(&self, raw: &[u8], message: &V3Message) -> bool {
+        let offset = message.auth_params_offset;
+        let length = message.auth_params.len();
+        let mut zeroed = raw.to_vec();
+        zeroed[offset..offset + length].fill(0);
+        self.auth_protocol.sign(&self.auth_key, &zeroed) == message.auth_params
+    }
+
+    fn decrypt(&self, message: &V3Message) -> Option<Vec<u8>> {
+        let salt: [u8; 8] = message.priv_params.try_into().ok()?;
+        Some(self.priv_protocol.decrypt(
+            &self.priv_key,
+            message.boots,
+            message.time,
+            &salt,
+            message.data.value,
+        ))
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn v3_message(
+    message_id: i32,
+    flags: u8,
+    engine_id: &[u8],
+    boots: u32,
+    time: u32,
+    user_name: &str,
+    auth_params: &[u8],
+    priv_params: &[u8],
+    data: Vec<u8>,
+) -> Vec<u8> {
+    let security_parameters = ber::sequence(&[
+        ber::octet_string(engine_id),
+        ber::integer(boots as i64),
+        ber::integer(time as i64),
+        ber::octet_string(user_name.as_bytes()),
+        ber::octet_string(auth_params),
+        ber::octet_string(priv_params),
+    ]);
+    ber::sequence(&[
+        ber::integer(VERSION_3),
+        ber::sequence(&[
+            ber::integer(message_id as i64),
+            ber::integer(MAX_MESSAGE_SIZE),
+            ber::octet_string(&[flags]),
+            ber::integer(USM_SECURITY_MODEL),
+        ]),
+        ber::octet_string(&security_parameters),
+        data,
+    ])
+}
+
+fn scoped_pdu(context_engine_id: &[u8], pdu: Vec<u8>) -> Vec<u8> {
+    ber::sequence(&[
+        ber::octet_string(context_engine_id),
+        ber::octet_string(&[]),
+        pdu,
+    ])
+}
+
+fn random_id() -> i32 {
+    rand::random::<i32>() & i32::MAX
+}
+
+impl NotificationService for Snmp {
+    type Config = Config;
+    type NotificationOptions = NotificationOptions;
+
+    async fn send_notification(
+        &self,
+        options: Option<Self::NotificationOptions>,
+        config: &Self::Config,
+        service_id: &str,
+        title: &str,
+        _attachments: Vec<Attachment>,
+        content: Option<&str>,
+        _html: Option<&str>,
+        metadata: &Metadata,
+    ) -> Result<(), crate::Error> {
+        let (severity, retry_managers) = match options {
+            Some(options) => (options.severity, options.retry_managers),
+            None => (None, None),
+        };
+        let severity = severity
+            .or(metadata.severity.map(Severity::from))
+            .unwrap_or(config.default_severity);
+        let mut config = config.clone();
+        if let Some(retry_managers) = retry_managers {
+            config
+                .managers
+                .retain(|manager| retry_managers.contains(manager));
+        }
+        let service_id = service_id.to_string();
+        let title = title.to_string();
+        let content = content.unwrap_or_default().to_string();
+        run_blocking(move || {
+            let notification = Notification {
+                service_id: &service_id,
+                title: &title,
+                content: &content,
+                severity,
+            };
+            Snmp.send(&config, &notification)
+        })
+        .await?;
+        Ok(())
+    }
+}
+
+impl Snmp {
+    /// Sends the notification to every manager, even if some of them fail
+    fn send(&self, config: &Config, notification: &Notification) -> Result<(), Error> {
+        let enterprise_oid = ber::parse_oid(&config.enterprise_oid)?;
+        let variable_bindings = notification.variable_bindings(&enterprise_oid);
+        let mut failures = Vec::new();
+        for manager in &config.managers {
+            let _span = info_span!("send_snmp_notification", manager).entered();
+            match Self::send_to(manager, config, &variable_bindings) {
+                Ok(()) => info!("... Ok"),
+                Err(e) => failures.push((manager.clone(), e)),
+            }
+        }
+        match failures.len() {
+            0 => Ok(()),
+            failed if failed < config.managers.len() => Err(Error::PartiallyFailed(failures)),
+            1 => Err(failures.remove(0).1),
+            _ => Err(Error::Failed(failures)),
+        }
+    }
+
+    fn send_to(manager: &str, config: &Config, variable_bindings: &[u8]) -> Result<(), Error> {
+        let timeout = Duration::from_secs(config.timeout_secs.max(1));
+        let socket = connect(resolve(manager)?, timeout)?;
+        match &config.security {
+            Security::V2c { community } => {
+                info!("Sending trap...");
+                Self::send_trap(&socket, community, variable_bindings.to_vec())
+            }
+            Security::V3 { .. } => {
+                info!("Sending inform...");
+                Self::send_inform(&socket, manager, config, variable_bindings.to_vec())
+            }
+        }
+    }
+
+    fn send_trap(
+        socket: &UdpSocket,
+        community: &str,
+        variable_bindings: Vec<u8>,
+    ) -> Result<(), Error> {
+        let message = ber::sequence(&[
+            ber::integer(VERSION_2C),
+            ber::octet_string(community.as_bytes()),
+            pdu(SNMPV2_TRAP, random_id(), variable_bindings),
+        ]);
+        socket.send(&message)?;
+        Ok(())
+    }
+
+    /// Waits for a datagram for which `handle` returns a result, other datagrams are ignored
+    fn receive<T>(
+        socket: &UdpSocket,
+        mut handle: impl FnMut(&[u8]) -> Option<Result<T, Error>>,
+    ) -> Option<Result<T, Error>> {
+        let mut buffer = vec![0u8; MAX_MESSAGE_SIZE as usize];
+        loop {
+            match socket.recv(&mut buffer) {
+                Ok(length) => {
+                    if let Some(result) = handle(&buffer[..length]) {
+                        return Some(result);
+                    }
+                }
+                Err(e)
+                    if matches!(
+                        e.kind(),
+                        std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
+                    ) =>
+                {
+                    return None;
+                }
+                Err(e) => return Some(Err(e.into())),
+            }
+        }
+    }
+
+    /// Discovers the engine id, boots and time of the manager (RFC 3414 4)
+    fn discover(socket: &UdpSocket, manager: &str, retries: u32) -> Result<RemoteEngine, Error> {
+        for _ in 0..=retries {
+            let message_id = random_id();
+            let request = v3_message(
+                message_id,
+                FLAG_REPORTABLE,
+                &[],
+                0,
+                0,
+                "",
+                &[],
+                &[],
+                scoped_pdu(&[], pdu(GET_REQUEST, random_id(), ber::sequence(&[]))),
+            );
+            socket.send(&request)?;
+            let engine = Self::receive(socket, |raw| {
+                let message = V3Message::decode(raw).ok()?;
+                (message.message_id == message_id as i64 && !message.engine_id.is_empty()).then(
+                    || {
+                        Ok(RemoteEngine {
+                            engine_id: message.engine_id.to_vec(),
+                            boots: message.boots,
+                            time: message.time,
+                            discovered: Instant::now(),
+                        })
+                    },
+                )
+            });
+            if let Some(engine) = engine {
+                return engine;
+            }
+        }
+        Err(Error::NotAcknowledged {
+            manager: manager.to_string(),
+        })
+    }
+
+    fn send_inform(
+        socket: &UdpSocket,
+        manager: &str,
+        config: &Config,
+        variable_bindings: Vec<u8>,
+    ) -> Result<(), Error> {
+        let mut engine = Self::discover(socket, manager, config.retries)?;
+        debug!(
+            "Discovered engine {:02x?} of {manager}, boots={}, time={}",
+            engine.engine_id, engine.boots, engine.time
+        );
+        let security = UserSecurity::new(&config.security, &engine.engine_id)
+            .expect("inform is only sent for snmpv3");
+        let request_id = random_id();
+        let scoped_pdu = scoped_pdu(
+            LOCAL_ENGINE_ID,
+            pdu(INFORM_REQUEST, request_id, variable_bindings),
+        );
+        let mut time_synchronized = false;
+        let mut attempt = 0;
+        while attempt <= config.retries {
+            attempt += 1;
+            let message_id = random_id();
+            socket.send(&security.encode(&engine, message_id, &scoped_pdu)?)?;
+            let result = Self::receive(socket, |raw| {
+                let message = V3Message::decode(raw).ok()?;
+                if message.message_id != message_id as i64 {
+                    return None;
+                }
+                if message.flags & FLAG_AUTH != 0 && !security.authenticate(raw, &message) {
+                    return Some(Err(Error::Authentication {
+                        manager: manager.to_string(),
+                    }));
+                }
+                let scoped_pdu = if message.flags & FLAG_PRIV != 0 {
+                    security.decrypt(&message)?
+                } else {
+                    ber::tlv(message.data.tag, message.data.value)
+                };
+                let pdu = match Pdu::decode_scoped(&scoped_pdu) {
+                    Ok(pdu) => pdu,
+                    Err(e) => return Some(Err(e)),
+                };
+                Some(Ok((message.flags, message.boots, message.time, pdu)))
+            });
+            let (flags, boots, time, pdu) = match result {
+                None => {
+                    warn!(
+                        "Inform was not acknowledged within {}s",
+                        config.timeout_secs.max(1)
+                    );
+                    continue;
+                }
+                Some(result) => result?,
+            };
+            match pdu.tag {
+                // Unauthenticated responses could be spoofed by anyone who saw the message id
+                RESPONSE
+                    if pdu.request_id == request_id as i64
+                        && flags & FLAGS_AUTH_PRIV != FLAGS_AUTH_PRIV =>
+                {
+                    return Err(Error::Authentication {
+                        manager: manager.to_string(),
+                    });
+                }
+                RESPONSE if pdu.request_id == request_id as i64 => {
+                    return if pdu.error_status == 0 {
+                        Ok(())
+                    } else {
+                        Err(Error::ErrorStatus {
+                            manager: manager.to_string(),
+                            status: pdu.error_status,
+                        })
+                    };
+                }
+                // The manager authenticates these reports (RFC 3414 3.2 7b)
+                REPORT
+                    if pdu.first_oid.as_deref() == Some(USM_STATS_NOT_IN_TIME_WINDOWS)
+                        && flags & FLAG_AUTH != 0
+                        && !time_synchronized =>
+                {
+                    debug!("Resynchronizing engine time of {manager}");
+                    engine.boots = boots;
+                    engine.time = time;
+                    engine.discovered = Instant::now();
+                    time_synchronized = true;
+                    attempt -= 1;
+                }
+                REPORT => {
+                    return Err(Error::Report {
+                        manager: manager.to_string(),
+                        oid: pdu.first_oid.unwrap_or_default(),
+                    });
+                }
+                _ => {}
+            }
+        }
+        Err(Error::NotAcknowledged {
+            manager: manager.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trap() {
+        let manager = UdpSocket::bind("127.0.0.1:0").unwrap();
+        manager
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        let config = Config {
+            managers: vec![manager.local_addr().unwrap().to_string()],
+            security: Security::V2c {
+                community: "public".to_string(),
+            },
+            ..Config::example()
+        };
+        let notification = Notification {
+            service_id: "nms",
+            title: "Press 3 stopped",
+            content: "Emergency stop was triggered",
+            severity: Severity::Critical,
+        };
+        Snmp.send(&config, &notification).unwrap();
+
+        let mut buffer = [0u8; 2048];
+        let length = manager.recv(&mut buffer).unwrap();
+        let mut message = ber::Reader::new(&buffer[..length])
+            .expect(ber::SEQUENCE)
+            .unwrap()
+            .children();
+        assert_eq!(
+            message.expect(ber::INTEGER).unwrap().as_integer().unwrap(),
+            VERSION_2C
+        );
+        assert_eq!(message.expect(ber::OCTET_STRING).unwrap().value, b"public");
+        let mut pdu = message.expect(SNMPV2_TRAP).unwrap().children();
+        pdu.expect(ber::INTEGER).unwrap();
+        pdu.expect(ber::INTEGER).unwrap();
+        pdu.expect(ber::INTEGER).unwrap();
+        let mut variable_bindings = pdu.expect(ber::SEQUENCE).unwrap().children();
+        let mut values = Vec::new();
+        while !variable_bindings.is_empty() {
+            let mut binding = variable_bindings.expect(ber::SEQUENCE).unwrap().children();
+            let oid = binding
+                .expect(ber::OBJECT_IDENTIFIER)
+                .unwrap()
+                .as_oid()
+                .unwrap();
+            values.push((oid, binding.next_element().unwrap()));
+        }
+        let base = ber::parse_oid(DEFAULT_ENTERPRISE_OID).unwrap();
+        let oid = |suffix: &[u32]| [base.as_slice(), suffix].concat();
+        assert_eq!(values.len(), 6);
+        assert_eq!(values[1].1.as_oid().unwrap(), oid(&[0, 1]));
+        assert_eq!(values[2].0, oid(&[1, 1, 0]));
+        assert_eq!(values[2].1.value, b"nms");
+        assert_eq!(values[3].1.value, b"Press 3 stopped");
+        assert_eq!(values[4].1.value, b"Emergency stop was triggered");
+        assert_eq!(values[5].0, oid(&[1, 4, 0]));
+        assert_eq!(values[5].1.as_integer().unwrap(), 1);
+    }
+
+    #[test]
+    fn failed_managers_are_retried() {
+        let manager = UdpSocket::bind("127.0.0.1:0").unwrap();
+        manager
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        let address = manager.local_addr().unwrap().to_string();
+        let config = Config {
+            managers: vec!["unknown.invalid".to_string(), address.clone()],
+            security: Security::V2c {
+                community: "public".to_string(),
+            },
+            ..Config::example()
+        };
+        let notification = Notification {
+            service_id: "nms",
+            title: "Press 3 stopped",
+            content: "Emergency stop was triggered",
+            severity: Severity::Critical,
+        };
+        // The manager after the unknown one still receives the trap
+        let error = Snmp.send(&config, &notification).unwrap_err();
+        let mut buffer = [0u8; 2048];
+        assert!(manager.recv(&mut buffer).unwrap() > 0);
+        assert!(matches!(&error, Error::PartiallyFailed(failures) if failures.len() == 1));
+        assert!(!error.is_permanent());
+        assert_eq!(
+            error.retry_options(Some(&serde_json::json!({"severity": "Critical"}))),
+            Some(serde_json::json!({
+                "severity": "Critical",
+                "retry_managers": ["unknown.invalid"],
+            }))
+        );
+        let error = crate::Error::from(error);
+        assert_eq!(error.partially_delivered_by("nms"), Some("nms"));
+
+        let config = Config {
+            managers: vec!["unknown.invalid".to_string()],
+            ..config
+        };
+        assert!(matches!(
+            Snmp.send(&config, &notification),
+            Err(Error::UnknownManager { .. })
+        ));
+    }
+
+    #[test]
+    fn unauthenticated_response_is_rejected() {
+        let manager = UdpSocket::bind("127.0.0.1:0").unwrap();
+        manager
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        let config = Config {
+            managers: vec![manager.local_addr().unwrap().to_string()],
+            security: Security::V3 {
+                user_name: "notis".to_string(),
+                auth_protocol: AuthProtocol::Sha256,
+                auth_password: "auth_password".to_string(),
+                priv_protocol: PrivProtocol::Aes,
+                priv_password: "priv_password".to_string(),
+            },
+            retries: 0,
+            ..Config::example()
+        };
+        let security = config.security.clone();
+        let engine_id = b"manager".to_vec();
+        let manager = std::thread::spawn(move || {
+            let mut buffer = [0u8; 2048];
+            // Discovery
+            let (length, sender) = manager.recv_from(&mut buffer).unwrap();
+            let message_id = V3Message::decode(&buffer[..length]).unwrap().message_id;
+            let report = pdu(REPORT, 0, ber::sequence(&[]));
+            let reply = v3_message(
+                message_id as i32,
+                0,
+                &engine_id,
+                1,
+                100,
+                "",
+                &[],
+                &[],
+                scoped_pdu(&engine_id, report),
+            );
+            manager.send_to(&reply, sender).unwrap();
+            // Inform, answered with a response lacking authentication and encryption
+            let (length, sender) = manager.recv_from(&mut buffer).unwrap();
+            let message = V3Message::decode(&buffer[..length]).unwrap();
+            let security = UserSecurity::new(&security, &engine_id).unwrap();
+            assert!(security.authenticate(&buffer[..length], &message));
+            let request = Pdu::decode_scoped(&security.decrypt(&message).unwrap()).unwrap();
+            assert_eq!(request.tag, INFORM_REQUEST);
+            let response = pdu(RESPONSE, request.request_id as i32, ber::sequence(&[]));
+            let reply = v3_message(
+                message.message_id as i32,
+                0,
+                &engine_id,
+                1,
+                100,
+                "notis",
+                &[],
+                &[],
+                scoped_pdu(&engine_id, response),
+            );
+            manager.send_to(&reply, sender).unwrap();
+        });
+        let notification = Notification {
+            service_id: "nms",
+            title: "Press 3 stopped",
+            content: "Emergency stop was triggered",
+            severity: Severity::Critical,
+        };
+        let result = Snmp.send(&config, &notification);
+        manager.join().unwrap();
+        assert!(matches!(result, Err(Error::Authentication { .. })));
+    }
+}
@@ -0,0 +1,289 @@
+//! Minimal BER encoding and decoding of the ASN.1 subset used by SNMP messages
+
+pub const INTEGER: u8 = 0x02;
+pub const OCTET_STRING: u8 = 0x04;
+pub const OBJECT_IDENTIFIER: u8 = 0x06;
+pub const SEQUENCE: u8 = 0x30;
+pub const TIME_TICKS: u8 = 0x43;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum Error {
+    #[error("Unexpected end of BER data")]
+    Truncated,
+    #[error("Expected BER tag {expected:#04x}, found {found:#04x}")]
+    UnexpectedTag { expected: u8, found: u8 },
+    #[error("Unsupported BER length encoding")]
+    UnsupportedLength,
+    #[error("BER integer does not fit into 64 bits")]
+    IntegerOverflow,
+    #[error("Invalid object identifier {0}")]
+    InvalidOid(String),
+}
+
+fn encode_length(length: usize, out: &mut Vec<u8>) {
+    if length < 0x80 {
+        out.push(length as u8);
+    } else {
+        let bytes = length.to_be_bytes();
+        let skip = bytes.iter().take_while(|b| **b == 0).count();
+        out.push(0x80 | (bytes.len() - skip) as u8);
+        out.extend_from_slice(&bytes[skip..]);
+    }
+}
+
+pub fn tlv(tag: u8, value: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(value.len() + 6);
+    out.push(tag);
+    encode_length(value.len(), &mut out);
+    out.extend_from_slice(value);
+    out
+}
+
+pub fn constructed(tag: u8, elements: &[Vec<u8>]) -> Vec<u8> {
+    tlv(tag, &elements.concat())
+}
+
+pub fn sequence(elements: &[Vec<u8>]) -> Vec<u8> {
+    constructed(SEQUENCE, elements)
+}
+
+pub fn integer(value: i64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let mut start = 0;
+    while start < bytes.len() - 1
+        && ((bytes[start] == 0x00 && bytes[start + 1] & 0x80 == 0)
+            || (bytes[start] == 0xff && bytes[start + 1] & 0x80 != 0))
+    {
+        start += 1;
+    }
+    tlv(INTEGER, &bytes[start..])
+}
+
+pub fn unsigned(tag: u8, value: u32) -> Vec<u8> {
+    let bytes = (value as u64).to_be_bytes();
+    let mut start = 0;
+    while start < bytes.len() - 1 && bytes[start] == 0x00 && bytes[start + 1] & 0x80 == 0 {
+        start += 1;
+    }
+    tlv(tag, &bytes[start..])
+}
+
+pub fn octet_string(value: &[u8]) -> Vec<u8> {
+    tlv(OCTET_STRING, value)
+}
+
+pub fn parse_oid(oid: &str) -> Result<Vec<u32>, Error> {
+    let arcs = oid
+        .trim_start_matches('.')
+        .split('.')
+        .map(str::parse)
+        .collect::<Result<Vec<u32>, _>>()
+        .map_err(|_| Error::InvalidOid(oid.to_string()))?;
+    if arcs.len() < 2 || arcs[0] > 2 || (arcs[0] < 2 && arcs[1] >= 40) {
+        return Err(Error::InvalidOid(oid.to_string()));
+    }
+    Ok(arcs)
+}
+
+pub fn object_identifier(arcs: &[u32]) -> Vec<u8> {
+    let mut value = Vec::new();
+    let first = arcs[0] * 40 + arcs[1];
+    for arc in std::iter::once(first).chain(arcs[2..].iter().copied()) {
+        let mut chunk = vec![(arc & 0x7f) as u8];
+        let mut rest = arc >> 7;
+        while rest > 0 {
+            chunk.push(0x80 | (rest & 0x7f) as u8);
+            rest >>= 7;
+        }
+        value.extend(chunk.iter().rev());
+    }
+    tlv(OBJECT_IDENTIFIER, &value)
+}
+
+/// A decoded BER element borrowing its value from the decoded message
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Element<'a> {
+    pub tag: u8,
+    pub value: &'a [u8],
+}
+
+impl<'a> Element<'a> {
+    pub fn expect(self, tag: u8) -> Result<Self, Error> {
+        if self.tag == tag {
+            Ok(self)
+        } else {
+            Err(Error::UnexpectedTag {
+                expected: tag,
+                found: self.tag,
+            })
+        }
+    }
+
+    pub fn children(self) -> Reader<'a> {
+        Reader::new(self.value)
+    }
+
+    pub fn as_integer(self) -> Result<i64, Error> {
+        if self.value.is_empty() {
+            return Err(Error::Truncated);
+        }
+        if self.value.len() > 8 {
+            return Err(Error::IntegerOverflow);
+        }
+        let negative = self.value[0] & 0x80 != 0;
+        Ok(self
+            .value
+            .iter()
+            .fold(if negative { -1i64 } else { 0 }, |acc, byte| {
+                (acc << 8) | *byte as i64
+            }))
+    }
+
+    pub fn as_oid(self) -> Result<Vec<u32>, Error> {
+        let mut arcs = Vec::new();
+        let mut current: u32 = 0;
+        for byte in self.value {
+            current = current
+                .checked_mul(128)
+                .ok_or(Error::IntegerOverflow)?
+                .wrapping_add((byte & 0x7f) as u32);
+            if byte & 0x80 == 0 {
+                if arcs.is_empty() {
+                    let first = (current / 40).min(2);
+                    arcs.push(first);
+                    arcs.push(current - first * 40);
+                } else {
+                    arcs.push(current);
+                }
+                current = 0;
+            }
+        }
+        Ok(arcs)
+    }
+}
+
+/// Sequential reader over concatenated BER elements
+pub struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.position >= self.data.len()
+    }
+
+    fn byte(&mut self) -> Result<u8, Error> {
+        let byte = *self.data.get(self.position).ok_or(Error::Truncated)?;
+        self.position += 1;
+        Ok(byte)
+    }
+
+    pub fn next_element(&mut self) -> Result<Element<'a>, Error> {
+        let tag = self.byte()?;
+        let first = self.byte()?;
+        let length = if first & 0x80 == 0 {
+            first as usize
+        } else {
+            let count = (first & 0x7f) as usize;
+            if count == 0 || count > std::mem::size_of::<usize>() {
+                return Err(Error::UnsupportedLength);
+            }
+            let mut length = 0usize;
+            for _ in 0..count {
+                length = (length << 8) | self.byte()? as usize;
+            }
+            length
+        };
+        let end = self
+            .position
+            .checked_add(length)
+            .filter(|end| *end <= self.data.len())
+            .ok_or(Error::Truncated)?;
+        let value = &self.data[self.position..end];
+        self.position = end;
+        Ok(Element { tag, value })
+    }
+
+    pub fn expect(&mut self, tag: u8) -> Result<Element<'a>, Error> {
+        self.next_element()?.expect(tag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers() {
+        assert_eq!(integer(0), [0x02, 0x01, 0x00]);
+        assert_eq!(integer(127), [0x02, 0x01, 0x7f]);
+        assert_eq!(integer(128), [0x02, 0x02, 0x00, 0x80]);
+        assert_eq!(integer(-129), [0x02, 0x02, 0xff, 0x7f]);
+        assert_eq!(unsigned(TIME_TICKS, 0xffff_ffff)[..3], [0x43, 0x05, 0x00]);
+        for value in [0, 1, -1, 127, 128, -128, -129, 65535, i32::MAX as i64] {
+            let encoded = integer(value);
+            let element = Reader::new(&encoded).expect(INTEGER).unwrap();
+            assert_eq!(element.as_integer().unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn object_identifiers() {
+        let oid = parse_oid("1.3.6.1.6.3.1.1.4.1.0").unwrap();
+        let encoded = object_identifier(&oid);
+        assert_eq!(
+            encoded,
+            [
+                0x06, 0x0a, 0x2b, 0x06, 0x01, 0x06, 0x03, 0x01, 0x01, 0x04, 0x01, 0x00
+            ]
+        );
+        let element = Reader::new(&encoded).expect(OBJECT_IDENTIFIER).unwrap();
+        assert_eq!(element.as_oid().unwrap(), oid);
+
+        let oid = parse_oid(".1.3.6.1.4.1.32473.300").unwrap();
+        let encoded = object_identifier(&oid);
+        let element = Reader::new(&encoded).next_element().unwrap();
+        assert_eq!(element.as_oid().unwrap(), oid);
+
+        assert!(parse_oid("1.3.six").is_err());
+        assert!(parse_oid("1").is_err());
+    }
+
+    #[test]
+    fn long_lengths() {
+        let value = vec![0x41; 300];
+        let encoded = octet_string(&value);
+        assert_eq!(encoded[..4], [0x04, 0x82, 0x01, 0x2c]);
+        let element = Reader::new(&encoded).expect(OCTET_STRING).unwrap();
+        assert_eq!(element.value, value.as_slice());
+    }
+
+    #[test]
+    fn nested() {
+        let encoded = sequence(&[
+            integer(3),
+            octet_string(b"public"),
+            unsigned(TIME_TICKS, 42),
+        ]);
+        let mut reader = Reader::new(&encoded).expect(SEQUENCE).unwrap().children();
+        assert_eq!(reader.expect(INTEGER).unwrap().as_integer().unwrap(), 3);
+        assert_eq!(reader.expect(OCTET_STRING).unwrap().value, b"public");
+        assert_eq!(
+            reader.expect(INTEGER),
+            Err(Error::UnexpectedTag {
+                expected: INTEGER,
+                found: TIME_TICKS
+            })
+        );
+        assert!(reader.is_empty());
+        assert_eq!(
+            Reader::new(&encoded[..5]).next_element(),
+            Err(Error::Truncated)
+        );
+    }
+}
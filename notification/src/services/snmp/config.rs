@@ -0,0 +1,155 @@
+mod patch;
+
+use crate::config::NotificationServiceConfig;
+pub use patch::ConfigPatch;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Base of the NOTIS-MIB (see mibs/NOTIS-MIB.txt), located below the documentation enterprise number
+pub const DEFAULT_ENTERPRISE_OID: &str = "1.3.6.1.4.1.32473.77";
+const DEFAULT_TIMEOUT_SECS: u64 = 5;
+const DEFAULT_RETRIES: u32 = 2;
+
+fn default_enterprise_oid() -> String {
+    DEFAULT_ENTERPRISE_OID.to_string()
+}
+
+fn default_timeout_secs() -> u64 {
+    DEFAULT_TIMEOUT_SECS
+}
+
+fn default_retries() -> u32 {
+    DEFAULT_RETRIES
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Deserialize, Serialize, JsonSchema)]
+pub enum Severity {
+    Critical = 1,
+    Error = 2,
+    Warning = 3,
+    #[default]
+    Info = 4,
+}
+
+impl From<crate::services::Severity> for Severity {
+    fn from(severity: crate::services::Severity) -> Self {
+        match severity {
+            crate::services::Severity::Critical => Self::Critical,
+            crate::services::Severity::Error => Self::Error,
+            crate::services::Severity::Warning => Self::Warning,
+            crate::services::Severity::Info => Self::Info,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+pub enum AuthProtocol {
+    Sha,
+    Sha256,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Deserialize, Serialize, JsonSchema)]
+pub enum PrivProtocol {
+    Aes,
+}
+
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(tag = "version")]
+pub enum Security {
+    /// Notifications are sent as unacknowledged SNMPv2c traps
+    V2c { community: String },
+    /// Notifications are sent as SNMPv3 informs with authPriv security and have to be acknowledged
+    V3 {
+        user_name: String,
+        auth_protocol: AuthProtocol,
+        auth_password: String,
+        priv_protocol: PrivProtocol,
+        priv_password: String,
+    },
+}
+
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct Config {
+    /// Addresses of the notification receivers as host:port, the port defaults to 162
+    pub managers: Vec<String>,
+    pub security: Security,
+    /// Base OID of the NOTIS-MIB objects
+    #[serde(default = "default_enterprise_oid")]
+    pub enterprise_oid: String,
+    #[serde(default)]
+    pub default_severity: Severity,
+    /// Seconds to wait for the acknowledgement of an inform
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+    /// Number of retransmissions of unacknowledged informs
+    #[serde(default = "default_retries")]
+    pub retries: u32,
+}
+
+impl Config {
+    pub fn example() -> Self {
+        Self {
+            managers: vec!["nms.example.com:162".to_string()],
+            security: Security::V3 {
+                user_name: "notis".to_string(),
+                auth_protocol: AuthProtocol::Sha,
+                auth_password: "my_auth_password".to_string(),
+                priv_protocol: PrivProtocol::Aes,
+                priv_password: "my_priv_password".to_string(),
+            },
+            enterprise_oid: default_enterprise_oid(),
+            default_severity: Severity::Warning,
+            timeout_secs: DEFAULT_TIMEOUT_SECS,
+            retries: DEFAULT_RETRIES,
+        }
+    }
+
+    pub fn redacted(&self) -> Self {
+        let security = match &self.security {
+            Security::V2c { .. } => Security::V2c {
+                community: "***".to_string(),
+            },
+            Security::V3 {
+                user_name,
+                auth_protocol,
+                priv_protocol,
+                ..
+            } => Security::V3 {
+                user_name: user_name.clone(),
+                auth_protocol: *auth_protocol,
+                auth_password: "***".to_string(),
+                priv_protocol: *priv_protocol,
+                priv_password: "***".to_string(),
+            },
+        };
+        Self {
+            security,
+            ..self.clone()
+        }
+    }
+}
+
+impl NotificationServiceConfig for Config {
+    type Patch = ConfigPatch;
+
+    fn apply_patch(&mut self, patch: ConfigPatch) {
+        if let Some(managers) = patch.managers {
+            self.managers = managers;
+        }
+        if let Some(security) = patch.security {
+            self.security = security;
+        }
+        if let Some(enterprise_oid) = patch.enterprise_oid {
+            self.enterprise_oid = enterprise_oid;
+        }
+        if let Some(default_severity) = patch.default_severity {
+            self.default_severity = default_severity;
+        }
+        if let Some(timeout_secs) = patch.timeout_secs {
+            self.timeout_secs = timeout_secs;
+        }
+        if let Some(retries) = patch.retries {
+            self.retries = retries;
+        }
+    }
+}
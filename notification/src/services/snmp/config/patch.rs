@@ -0,0 +1,53 @@
+use crate::services::snmp::{Security, Severity};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, JsonSchema)]
+pub struct ConfigPatch {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub managers: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security: Option<Security>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enterprise_oid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_severity: Option<Severity>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retries: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde() {
+        let s = r#"{}"#;
+        let p = ConfigPatch {
+            managers: None,
+            security: None,
+            enterprise_oid: None,
+            default_severity: None,
+            timeout_secs: None,
+            retries: None,
+        };
+        assert_eq!(p, serde_json::from_str(s).unwrap());
+        assert_eq!(s, serde_json::to_string(&p).unwrap());
+
+        let s = r#"{"managers":["10.0.0.1","nms:1162"],"security":{"version":"V2c","community":"public"},"default_severity":"Critical","retries":0}"#;
+        let p = ConfigPatch {
+            managers: Some(vec!["10.0.0.1".to_string(), "nms:1162".to_string()]),
+            security: Some(Security::V2c {
+                community: "public".to_string(),
+            }),
+            enterprise_oid: None,
+            default_severity: Some(Severity::Critical),
+            timeout_secs: None,
+            retries: Some(0),
+        };
+        assert_eq!(p, serde_json::from_str(s).unwrap());
+        assert_eq!(s, serde_json::to_string(&p).unwrap());
+    }
+}
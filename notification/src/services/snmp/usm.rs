@@ -0,0 +1,162 @@
+//! User-based security model (RFC 3414, RFC 3826, RFC 7860) for SNMPv3 authPriv messages
+
+use crate::services::snmp::{AuthProtocol, PrivProtocol};
+use aes::cipher::{AsyncStreamCipher, KeyIvInit};
+use hmac::{Hmac, Mac};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+const PASSWORD_EXPANSION_LENGTH: usize = 1024 * 1024;
+
+type Aes128CfbEnc = cfb_mode::Encryptor<aes::Aes128>;
+type Aes128CfbDec = cfb_mode::Decryptor<aes::Aes128>;
+
+fn expand_password<D: Digest>(password: &[u8]) -> Vec<u8> {
+    let mut hasher = D::new();
+    let mut buffer = [0u8; 64];
+    let mut index = 0;
+    for _ in 0..PASSWORD_EXPANSION_LENGTH / buffer.len() {
+        for byte in buffer.iter_mut() {
+            *byte = password[index % password.len()];
+            index += 1;
+        }
+        hasher.update(buffer);
+    }
+    hasher.finalize().to_vec()
+}
+
+fn localize<D: Digest>(key: &[u8], engine_id: &[u8]) -> Vec<u8> {
+    let mut hasher = D::new();
+    hasher.update(key);
+    hasher.update(engine_id);
+    hasher.update(key);
+    hasher.finalize().to_vec()
+}
+
+impl AuthProtocol {
+    /// Number of bytes of the message authentication code transmitted in the message
+    pub fn mac_length(&self) -> usize {
+        match self {
+            Self::Sha => 12,
+            Self::Sha256 => 24,
+        }
+    }
+
+    /// Derives the key localized to the given engine from a password (RFC 3414 A.2)
+    pub fn localized_key(&self, password: &str, engine_id: &[u8]) -> Vec<u8> {
+        match self {
+            Self::Sha => localize::<Sha1>(&expand_password::<Sha1>(password.as_bytes()), engine_id),
+            Self::Sha256 => {
+                localize::<Sha256>(&expand_password::<Sha256>(password.as_bytes()), engine_id)
+            }
+        }
+    }
+
+    pub fn sign(&self, key: &[u8], message: &[u8]) -> Vec<u8> {
+        let mut mac = match self {
+            Self::Sha => {
+                let mut mac = <Hmac<Sha1> as Mac>::new_from_slice(key).expect("any key length");
+                mac.update(message);
+                mac.finalize().into_bytes().to_vec()
+            }
+            Self::Sha256 => {
+                let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(key).expect("any key length");
+                mac.update(message);
+                mac.finalize().into_bytes().to_vec()
+            }
+        };
+        mac.truncate(self.mac_length());
+        mac
+    }
+}
+
+impl PrivProtocol {
+    fn iv(engine_boots: u32, engine_time: u32, salt: &[u8; 8]) -> [u8; 16] {
+        let mut iv = [0u8; 16];
+        iv[..4].copy_from_slice(&engine_boots.to_be_bytes());
+        iv[4..8].copy_from_slice(&engine_time.to_be_bytes());
+        iv[8..].copy_from_slice(salt);
+        iv
+    }
+
+    pub fn encrypt(
+        &self,
+        key: &[u8],
+        engine_boots: u32,
+        engine_time: u32,
+        salt: &[u8; 8],
+        data: &[u8],
+    ) -> Vec<u8> {
+        match self {
+            Self::Aes => {
+                let mut buffer = data.to_vec();
+                Aes128CfbEnc::new_from_slices(
+                    &key[..16],
+                    &Self::iv(engine_boots, engine_time, salt),
+                )
+                .expect("valid key and iv length")
+                .encrypt(&mut buffer);
+                buffer
+            }
+        }
+    }
+
+    pub fn decrypt(
+        &self,
+        key: &[u8],
+        engine_boots: u32,
+        engine_time: u32,
+        salt: &[u8; 8],
+        data: &[u8],
+    ) -> Vec<u8> {
+        match self {
+            Self::Aes => {
+                let mut buffer = data.to_vec();
+                Aes128CfbDec::new_from_slices(
+                    &key[..16],
+                    &Self::iv(engine_boots, engine_time, salt),
+                )
+                .expect("valid key and iv length")
+                .decrypt(&mut buffer);
+                buffer
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    // Test vectors of RFC 3414 A.3.2 and RFC 7860 for the password "maplesyrup"
+    #[test]
+    fn key_localization() {
+        let engine_id = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2];
+        assert_eq!(
+            hex(&AuthProtocol::Sha.localized_key("maplesyrup", &engine_id)),
+            "6695febc9288e36282235fc7151f128497b38f3f"
+        );
+        assert_eq!(
+            hex(&AuthProtocol::Sha256.localized_key("maplesyrup", &engine_id)),
+            "8982e0e549e866db361a6b625d84cccc11162d453ee8ce3a6445c2d6776f0f8b"
+        );
+    }
+
+    #[test]
+    fn aes_roundtrip() {
+        let key = [7u8; 20];
+        let salt = [1, 2, 3, 4, 5, 6, 7, 8];
+        let plain = b"scoped pdu with some arbitrary length";
+        let encrypted = PrivProtocol::Aes.encrypt(&key, 3, 4711, &salt, plain);
+        assert_eq!(encrypted.len(), plain.len());
+        assert_ne!(encrypted.as_slice(), plain.as_slice());
+        assert_eq!(
+            PrivProtocol::Aes.decrypt(&key, 3, 4711, &salt, &encrypted),
+            plain
+        );
+    }
+}
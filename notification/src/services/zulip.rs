@@ -0,0 +1,354 @@
+mod config;
+
+use crate::services::{Attachment, Metadata, NotificationService, run_blocking};
+use base64::Engine;
+pub use config::*;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+use tracing::{info, info_span};
+
+const MAX_TOPIC_LENGTH: usize = 60;
+/// Topic of messages whose topic template renders empty and whose title is empty
+const DEFAULT_TOPIC: &str = "Notifications";
+
+#[derive(Default)]
+pub struct Zulip;
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error(transparent)]
+    Http(#[from] ureq::Error),
+    #[error("Zulip rejected the request with status {status}: {message}")]
+    Rejected { status: u16, message: String },
+    #[error("Zulip did not return the url of the uploaded file {file_name}")]
+    MissingUploadUrl { file_name: String },
+}
+
+impl Error {
+    /// Errors which will not resolve by retrying, rate limits and timeouts excepted from the
+    /// client errors
+    pub fn is_permanent(&self) -> bool {
+        match self {
+            Self::Rejected { status, .. } => {
+                (400..500).contains(status) && !matches!(status, 408 | 429)
+            }
+            Self::Http(_) | Self::MissingUploadUrl { .. } => false,
+        }
+    }
+}
+
+#[derive(JsonSchema, Deserialize, Serialize)]
+pub struct NotificationOptions {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    stream: Option<String>,
+    /// Overrides the topic template of the service, `{title}` is replaced by the notification title
+    /// and `{tag:<name>}` by the value of the metadata tag
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    topic: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ApiResponse {
+    #[serde(default)]
+    msg: String,
+    #[serde(default)]
+    url: Option<String>,
+    #[serde(default)]
+    uri: Option<String>,
+}
+
+fn render_topic(template: &str, title: &str, tags: &HashMap<String, String>) -> String {
+    let mut topic = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find(TAG_PLACEHOLDER) {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+        topic.push_str(&rest[..start].replace(TITLE_PLACEHOLDER, title));
+        let name = &rest[start + TAG_PLACEHOLDER.len()..start + end];
+        topic.push_str(tags.get(name).map(String::as_str).unwrap_or_default());
+        rest = &rest[start + end + 1..];
+    }
+    topic.push_str(&rest.replace(TITLE_PLACEHOLDER, title));
+    // Zulip rejects empty topics, e.g. of a template consisting of missing tags
+    let topic = [topic.as_str(), title]
+        .into_iter()
+        .find(|topic| !topic.trim().is_empty())
+        .unwrap_or(DEFAULT_TOPIC);
+    topic.chars().take(MAX_TOPIC_LENGTH).collect()
+}
+
+/// The title is left out of the message if the rendered topic shows it completely
+fn render_message(
+    topic: &str,
+    title: &str,
+    content: Option<&str>,
+    uploads: &[(String, String)],
+) -> String {
+    let mut message = match content {
+        Some(content) if topic.contains(title) => content.to_string(),
+        Some(content) => format!("**{title}**\n\n{content}"),
+        None => title.to_string(),
+    };
+    for (file_name, url) in uploads {
+        message.push_str(&format!("\n[{file_name}]({url})"));
+    }
+    message
+}
+
+impl NotificationService for Zulip {
+    type Config = Config;
+    type NotificationOptions = NotificationOptions;
+
+    async fn send_notification(
+        &self,
+        options: Option<Self::NotificationOptions>,
+        config: &Self::Config,
+        _service_id: &str,
+        title: &str,
+        attachments: Vec<Attachment>,
+        content: Option<&str>,
+        _html: Option<&str>,
+        metadata: &Metadata,
+    ) -> Result<(), crate::Error> {
+        let (stream, topic) = match options {
+            Some(options) => (options.stream, options.topic),
+            None => (None, None),
+        };
+        let stream = stream.unwrap_or_else(|| config.stream.clone());
+        let topic = topic.unwrap_or_else(|| config.topic.clone());
+        let config = config.clone();
+        let title = metadata.prefixed_title(title);
+        let content = content.map(str::to_string);
+        let tags = metadata.tags.clone();
+        run_blocking(move || {
+            Zulip.send_message(
+                &config,
+                &stream,
+                &topic,
+                &title,
+                &tags,
+                content.as_deref(),
+                attachments,
+            )
+        })
+        .await?;
+        Ok(())
+    }
+
+    fn receivers(
+        &self,
+        options: Option<&Self::NotificationOptions>,
+        config: &Self::Config,
+    ) -> Vec<String> {
+        vec![
+            options
+                .and_then(|options| options.stream.clone())
+                .unwrap_or_else(|| config.stream.clone()),
+        ]
+    }
+}
+
+impl Zulip {
+    fn agent() -> ureq::Agent {
+        ureq::Agent::config_builder()
+            .timeout_global(Some(Duration::from_secs(10)))
+            .http_status_as_error(false)
+            .build()
+            .into()
+    }
+
+    fn authorization(config: &Config) -> String {
+        let credentials = base64::engine::general_purpose::STANDARD
+            .encode(format!("{}:{}", config.bot_email, config.api_key));
+        format!("Basic {credentials}")
+    }
+
+    fn endpoint(config: &Config, path: &str) -> String {
+        format!("{}/api/v1/{path}", config.server_url.trim_end_matches('/'))
+    }
+
+    fn read_response(mut response: ureq::http::Response<ureq::Body>) -> Result<ApiResponse, Error> {
+        let status = response.status();
+        let api_response = response.body_mut().read_json::<ApiResponse>();
+        match api_response {
+            Ok(api_response) if status.is_success() => Ok(api_response),
+            Ok(api_response) => Err(Error::Rejected {
+                status: status.as_u16(),
+                message: api_response.msg,
+            }),
+            Err(_) if !status.is_success() => Err(Error::Rejected {
+                status: status.as_u16(),
+                message: status.canonical_reason().unwrap_or_default().to_string(),
+            }),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn upload(
+        agent: &ureq::Agent,
+        config: &Config,
+        attachment: &Attachment,
+    ) -> Result<String, Error> {
+        let part = ureq::unversioned::multipart::Part::bytes(&attachment.file_content)
+            .file_name(&attachment.file_name)
+            .mime_str(&attachment.mime_type())?;
+        let form = ureq::unversioned::multipart::Form::new().part("file", part);
+        let response = agent
+            .post(Self::endpoint(config, "user_uploads"))
+            .header("Authorization", Self::authorization(config))
+            .send(form)?;
+        let response = Self::read_response(response)?;
+        let url = response
+            .url
+            .or(response.uri)
+            .ok_or_else(|| Error::MissingUploadUrl {
+                file_name: attachment.file_name.clone(),
+            })?;
+        Ok(url)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn send_message(
+        &self,
+        config: &Config,
+        stream: &str,
+        topic_template: &str,
+        title: &str,
+        tags: &HashMap<String, String>,
+        content: Option<&str>,
+        attachments: Vec<Attachment>,
+    ) -> Result<(), Error> {
+        let _span = info_span!("send_message", server = config.server_url, stream).entered();
+        let agent = Self::agent();
+        let mut uploads = Vec::with_capacity(attachments.len());
+        for attachment in &attachments {
+            let url = Self::upload(&agent, config, attachment)?;
+            uploads.push((attachment.file_name.clone(), url));
+        }
+        let topic = render_topic(topic_template, title, tags);
+        let message = render_message(&topic, title, content, &uploads);
+        info!("Sending zulip message...");
+        let response = agent
+            .post(Self::endpoint(config, "messages"))
+            .header("Authorization", Self::authorization(config))
+            .send_form([
+                ("type", "stream"),
+                ("to", stream),
+                ("topic", topic.as_str()),
+                ("content", message.as_str()),
+            ])?;
+        Self::read_response(response)?;
+        info!("... Ok");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn topic_from_title() {
+        let tags = HashMap::new();
+        assert_eq!(
+            render_topic("{title}", "Press 3 stopped", &tags),
+            "Press 3 stopped"
+        );
+        assert_eq!(
+            render_topic("Line 1: {title}", "Press 3 stopped", &tags),
+            "Line 1: Press 3 stopped"
+        );
+        assert_eq!(render_topic("Line 1", "Press 3 stopped", &tags), "Line 1");
+    }
+
+    #[test]
+    fn topic_from_tags() {
+        let tags = HashMap::from([
+            ("line".to_string(), "Line 1".to_string()),
+            ("machine".to_string(), "{title}".to_string()),
+        ]);
+        assert_eq!(
+            render_topic("{tag:line}: {title}", "Press 3 stopped", &tags),
+            "Line 1: Press 3 stopped"
+        );
+        assert_eq!(
+            render_topic("{tag:line}/{tag:shift}", "Press 3 stopped", &tags),
+            "Line 1/"
+        );
+        // Tag values are not templates themselves
+        assert_eq!(
+            render_topic("{tag:machine}", "Press 3 stopped", &tags),
+            "{title}"
+        );
+        assert_eq!(
+            render_topic("{tag:line", "Press 3 stopped", &tags),
+            "{tag:line"
+        );
+    }
+
+    #[test]
+    fn empty_topic() {
+        let tags = HashMap::new();
+        assert_eq!(
+            render_topic("{tag:line}", "Press 3 stopped", &tags),
+            "Press 3 stopped"
+        );
+        assert_eq!(render_topic(" {tag:line} ", "", &tags), DEFAULT_TOPIC);
+    }
+
+    #[test]
+    fn topic_is_truncated() {
+        let title = "x".repeat(100);
+        assert_eq!(
+            render_topic("{title}", &title, &HashMap::new()).len(),
+            MAX_TOPIC_LENGTH
+        );
+    }
+
+    #[test]
+    fn message_with_uploads() {
+        let uploads = [(
+            "report.pdf".to_string(),
+            "/user_uploads/1/ab/report.pdf".to_string(),
+        )];
+        assert_eq!(
+            render_message(
+                "Export ready",
+                "Export ready",
+                Some("See attached"),
+                &uploads
+            ),
+            "See attached\n[report.pdf](/user_uploads/1/ab/report.pdf)"
+        );
+        assert_eq!(
+            render_message("Exports", "Export ready", Some("See attached"), &[]),
+            "**Export ready**\n\nSee attached"
+        );
+        assert_eq!(
+            render_message("Exports", "Export ready", None, &[]),
+            "Export ready"
+        );
+        // The title is kept if it was truncated in the topic
+        let title = "x".repeat(100);
+        let topic = render_topic("{title}", &title, &HashMap::new());
+        assert_eq!(
+            render_message(&topic, &title, Some("See attached"), &[]),
+            format!("**{title}**\n\nSee attached")
+        );
+    }
+
+    #[test]
+    fn permanent_errors() {
+        let rejected = |status| Error::Rejected {
+            status,
+            message: "Invalid API key".to_string(),
+        };
+        assert!(rejected(401).is_permanent());
+        assert!(!rejected(429).is_permanent());
+        assert!(!rejected(502).is_permanent());
+        assert!(crate::Error::from(rejected(400)).is_permanent());
+    }
+}
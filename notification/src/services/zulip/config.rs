@@ -0,0 +1,68 @@
+mod patch;
+
+use crate::config::NotificationServiceConfig;
+pub use patch::ConfigPatch;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+pub const TITLE_PLACEHOLDER: &str = "{title}";
+/// Start of the placeholders of metadata tags, e.g. `{tag:line}`
+pub const TAG_PLACEHOLDER: &str = "{tag:";
+
+fn default_topic() -> String {
+    TITLE_PLACEHOLDER.to_string()
+}
+
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct Config {
+    /// Base url of the zulip organization, e.g. https://example.zulipchat.com
+    pub server_url: String,
+    pub bot_email: String,
+    pub api_key: String,
+    pub stream: String,
+    /// Template for the topic of the messages, `{title}` is replaced by the notification title and
+    /// `{tag:<name>}` by the value of the metadata tag, or nothing if the tag is missing
+    #[serde(default = "default_topic")]
+    pub topic: String,
+}
+
+impl Config {
+    pub fn example() -> Self {
+        Self {
+            server_url: "https://example.zulipchat.com".to_string(),
+            bot_email: "notis-bot@example.zulipchat.com".to_string(),
+            api_key: "my_api_key".to_string(),
+            stream: "alerts".to_string(),
+            topic: default_topic(),
+        }
+    }
+
+    pub fn redacted(&self) -> Self {
+        Self {
+            api_key: "***".to_string(),
+            ..self.clone()
+        }
+    }
+}
+
+impl NotificationServiceConfig for Config {
+    type Patch = ConfigPatch;
+
+    fn apply_patch(&mut self, patch: ConfigPatch) {
+        if let Some(server_url) = patch.server_url {
+            self.server_url = server_url;
+        }
+        if let Some(bot_email) = patch.bot_email {
+            self.bot_email = bot_email;
+        }
+        if let Some(api_key) = patch.api_key {
+            self.api_key = api_key;
+        }
+        if let Some(stream) = patch.stream {
+            self.stream = stream;
+        }
+        if let Some(topic) = patch.topic {
+            self.topic = topic;
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Deserialize, Serialize, JsonSchema)]
+pub struct ConfigPatch {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_email: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stream: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub topic: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serde() {
+        let s = r#"{}"#;
+        let p = ConfigPatch {
+            server_url: None,
+            bot_email: None,
+            api_key: None,
+            stream: None,
+            topic: None,
+        };
+        assert_eq!(p, serde_json::from_str(s).unwrap());
+        assert_eq!(s, serde_json::to_string(&p).unwrap());
+
+        let s = r#"{"api_key":"new_key","stream":"machines","topic":"Press 3: {title}"}"#;
+        let p = ConfigPatch {
+            server_url: None,
+            bot_email: None,
+            api_key: Some("new_key".to_string()),
+            stream: Some("machines".to_string()),
+            topic: Some("Press 3: {title}".to_string()),
+        };
+        assert_eq!(p, serde_json::from_str(s).unwrap());
+        assert_eq!(s, serde_json::to_string(&p).unwrap());
+    }
+}
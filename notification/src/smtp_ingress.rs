@@ -0,0 +1,271 @@
+//! Embedded smtp server which accepts notifications by email from devices that cannot use the api
+
+mod session;
+
+use crate::services::Attachment;
+use lettre::message::header::ContentType;
+use mail_parser::{MessageParser, MimeHeaders};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tracing::{Instrument, info, info_span};
+
+const DEFAULT_PORT: u16 = 25;
+const DEFAULT_DOMAIN: &str = "notis.local";
+const DEFAULT_MAX_MESSAGE_SIZE: usize = 10 * 1024 * 1024;
+const NO_SUBJECT: &str = "(no subject)";
+const DEFAULT_ATTACHMENT_NAME: &str = "attachment";
+
+fn default_port() -> u16 {
+    DEFAULT_PORT
+}
+
+fn default_domain() -> String {
+    DEFAULT_DOMAIN.to_string()
+}
+
+fn default_max_message_size() -> usize {
+    DEFAULT_MAX_MESSAGE_SIZE
+}
+
+#[derive(Debug, PartialEq, Clone, Deserialize, Serialize)]
+pub struct Config {
+    #[serde(default = "default_port")]
+    pub port: u16,
+    /// Mail domain of notis, messages to `<service-id>@<domain>` are sent via the service `<service-id>`
+    #[serde(default = "default_domain")]
+    pub domain: String,
+    /// Maximum size of accepted messages in bytes
+    #[serde(default = "default_max_message_size")]
+    pub max_message_size: usize,
+}
+
+impl Config {
+    pub fn example() -> Self {
+        Self {
+            port: DEFAULT_PORT,
+            domain: DEFAULT_DOMAIN.to_string(),
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
+        }
+    }
+}
+
+/// Notification converted from a received email
+#[derive(Clone)]
+pub struct Notification {
+    pub title: String,
+    pub content: Option<String>,
+    pub attachments: Vec<Attachment>,
+}
+
+impl Notification {
+    /// Maps the subject to the title, the text body to the content and attached MIME parts to attachments
+    pub fn from_message(raw: &[u8]) -> Option<Self> {
+        let message = MessageParser::default().parse(raw)?;
+        let title = message
+            .subject()
+            .map(str::trim)
+            .filter(|subject| !subject.is_empty())
+            .unwrap_or(NO_SUBJECT)
+            .to_string();
+        let content = message
+            .body_text(0)
+            .map(|text| text.trim().to_string())
+            .filter(|text| !text.is_empty());
+        let attachments = message
+            .attachments()
+            .map(|part| Attachment {
+                file_name: part
+                    .attachment_name()
+                    .unwrap_or(DEFAULT_ATTACHMENT_NAME)
+                    .to_string(),
+                content_type: part
+                    .content_type()
+                    .and_then(|content_type| {
+                        ContentType::parse(&format!(
+                            "{}/{}",
+                            content_type.ctype(),
+                            content_type.subtype()?
+                        ))
+                        .ok()
+                    })
+                    .unwrap_or_else(|| ContentType::parse("application/octet-stream").unwrap()),
+                file_content: part.contents().to_vec(),
+            })
+            .collect();
+        Some(Self {
+            title,
+            content,
+            attachments,
+        })
+    }
+}
+
+/// Target of the notifications received by the smtp ingress
+pub trait Dispatcher: Send + Sync + 'static {
+    fn has_service(&self, id: &str) -> bool;
+    fn dispatch(
+        &self,
+        id: &str,
+        notification: Notification,
+    ) -> impl Future<Output = Result<(), crate::Error>> + Send;
+}
+
+pub async fn serve(config: Config, dispatcher: Arc<impl Dispatcher>) -> std::io::Result<()> {
+    let listener = TcpListener::bind(("0.0.0.0", config.port)).await?;
+    info!("Smtp ingress listening on 0.0.0.0:{}", config.port);
+    accept(listener, Arc::new(config), dispatcher).await
+}
+
+async fn accept(
+    listener: TcpListener,
+    config: Arc<Config>,
+    dispatcher: Arc<impl Dispatcher>,
+) -> std::io::Result<()> {
+    loop {
+        let (stream, peer) = listener.accept().await?;
+        let span = info_span!("smtp_ingress_session", %peer);
+        tokio::spawn(session::run(stream, config.clone(), dispatcher.clone()).instrument(span));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lettre::message::{Attachment as MailAttachment, MultiPart, SinglePart};
+    use lettre::{SmtpTransport, Transport};
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct Recorder {
+        notifications: Mutex<Vec<(String, Notification)>>,
+    }
+
+    impl Dispatcher for Recorder {
+        fn has_service(&self, id: &str) -> bool {
+            id == "plc" || id == "log" || id == "broken"
+        }
+
+        async fn dispatch(&self, id: &str, notification: Notification) -> Result<(), crate::Error> {
+            if id == "broken" {
+                return Err(std::io::Error::other("queue is not writable").into());
+            }
+            self.notifications
+                .lock()
+                .unwrap()
+                .push((id.to_string(), notification));
+            Ok(())
+        }
+    }
+
+    fn message(to: &str) -> lettre::Message {
+        lettre::Message::builder()
+            .from("printer@example.com".parse().unwrap())
+            .to(to.parse().unwrap())
+            .subject("Toner low")
+            .multipart(
+                MultiPart::mixed()
+                    .singlepart(SinglePart::plain(
+                        "Replace the black toner\r\n.\r\n".to_string(),
+                    ))
+                    .singlepart(
+                        MailAttachment::new("status.csv".to_string())
+                            .body(b"level;5".to_vec(), "text/csv".parse().unwrap()),
+                    ),
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn from_message() {
+        let notification =
+            Notification::from_message(&message("plc@notis.local").formatted()).unwrap();
+        assert_eq!(notification.title, "Toner low");
+        assert_eq!(
+            notification.content.as_deref(),
+            Some("Replace the black toner\r\n.")
+        );
+        assert_eq!(notification.attachments.len(), 1);
+        assert_eq!(notification.attachments[0].file_name, "status.csv");
+        assert_eq!(notification.attachments[0].mime_type(), "text/csv");
+        assert_eq!(notification.attachments[0].file_content, b"level;5");
+
+        let notification = Notification::from_message(b"From: a@b.c\r\n\r\n").unwrap();
+        assert_eq!(notification.title, NO_SUBJECT);
+        assert_eq!(notification.content, None);
+    }
+
+    #[tokio::test]
+    async fn receive() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let recorder = Arc::new(Recorder::default());
+        tokio::spawn(accept(
+            listener,
+            Arc::new(Config {
+                max_message_size: 4096,
+                ..Config::example()
+            }),
+            recorder.clone(),
+        ));
+
+        let results = tokio::task::spawn_blocking(move || {
+            let transport = SmtpTransport::builder_dangerous("127.0.0.1")
+                .port(port)
+                .build();
+            let multiple = lettre::Message::builder()
+                .from("printer@example.com".parse().unwrap())
+                .to("plc@notis.local".parse().unwrap())
+                .to("log@Notis.Local".parse().unwrap())
+                .subject("Toner low")
+                .body("Replace the black toner".to_string())
+                .unwrap();
+            let too_large = lettre::Message::builder()
+                .from("printer@example.com".parse().unwrap())
+                .to("plc@notis.local".parse().unwrap())
+                .subject("Scan")
+                .body("x".repeat(8192))
+                .unwrap();
+            let partially_broken = lettre::Message::builder()
+                .from("printer@example.com".parse().unwrap())
+                .to("broken@notis.local".parse().unwrap())
+                .to("log@notis.local".parse().unwrap())
+                .subject("Toner low")
+                .body("Replace the black toner".to_string())
+                .unwrap();
+            (
+                transport.send(&multiple),
+                transport.send(&message("plc@notis.local")),
+                transport.send(&message("unknown@notis.local")),
+                transport.send(&message("plc@example.com")),
+                transport.send(&too_large),
+                transport.send(&partially_broken),
+                transport.send(&message("broken@notis.local")),
+            )
+        })
+        .await
+        .unwrap();
+        results.0.unwrap();
+        results.1.unwrap();
+        assert!(results.2.unwrap_err().is_permanent());
+        assert!(results.3.unwrap_err().is_permanent());
+        assert!(results.4.unwrap_err().is_permanent());
+        // The message is accepted if at least one service queued it
+        results.5.unwrap();
+        assert!(results.6.unwrap_err().is_permanent());
+
+        let notifications = recorder.notifications.lock().unwrap();
+        let ids: Vec<_> = notifications.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, ["plc", "log", "plc", "log"]);
+        assert_eq!(notifications[0].1.title, "Toner low");
+        assert_eq!(
+            notifications[0].1.content.as_deref(),
+            Some("Replace the black toner")
+        );
+        assert_eq!(
+            notifications[2].1.content.as_deref(),
+            Some("Replace the black toner\r\n.")
+        );
+        assert_eq!(notifications[2].1.attachments[0].file_name, "status.csv");
+    }
+}
@@ -0,0 +1,362 @@
+//! Server side of the smtp protocol (RFC 5321) restricted to the delivery of local messages
+
+use crate::smtp_ingress::{Config, Dispatcher, Notification};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tracing::{debug, info, warn};
+
+/// Maximum length of command lines (RFC 5321 4.5.3.1.4) and text lines (4.5.3.1.6)
+const MAX_LINE_LENGTH: u64 = 1000;
+const MAX_RECIPIENTS: usize = 100;
+/// Timeout for commands and data lines (RFC 5321 4.5.3.2)
+const TIMEOUT: Duration = Duration::from_secs(300);
+
+enum Line {
+    Complete(Vec<u8>),
+    TooLong,
+    Closed,
+}
+
+struct Session<S, D> {
+    stream: BufReader<S>,
+    config: Arc<Config>,
+    dispatcher: Arc<D>,
+    greeted: bool,
+    sender: Option<String>,
+    recipients: Vec<String>,
+}
+
+pub async fn run<S, D>(stream: S, config: Arc<Config>, dispatcher: Arc<D>)
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+    D: Dispatcher,
+{
+    let mut session = Session {
+        stream: BufReader::new(stream),
+        config,
+        dispatcher,
+        greeted: false,
+        sender: None,
+        recipients: Vec::new(),
+    };
+    match session.serve().await {
+        Ok(()) => debug!("Session closed"),
+        Err(e) => warn!("Session aborted: {e}"),
+    }
+}
+
+/// Extracts the address of a `FROM:<address>` or `TO:<address>` argument
+fn path<'a>(arguments: &'a str, prefix: &str) -> Option<&'a str> {
+    let arguments = arguments.trim_start();
+    if !arguments.get(..prefix.len())?.eq_ignore_ascii_case(prefix) {
+        return None;
+    }
+    let rest = arguments[prefix.len()..].trim_start();
+    let rest = rest.strip_prefix('<')?;
+    let end = rest.find('>')?;
+    Some(&rest[..end])
+}
+
+impl<S, D> Session<S, D>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+    D: Dispatcher,
+{
+    async fn reply(&mut self, reply: &str) -> std::io::Result<()> {
+        self.stream.write_all(reply.as_bytes()).await?;
+        self.stream.write_all(b"\r\n").await?;
+        self.stream.flush().await
+    }
+
+    async fn read_line(&mut self) -> std::io::Result<Line> {
+        let mut line = Vec::new();
+        let read = tokio::time::timeout(
+            TIMEOUT,
+            (&mut self.stream)
+                .take(MAX_LINE_LENGTH)
+                .read_until(b'\n', &mut line),
+        )
+        .await
+        .map_err(|_| std::io::Error::from(std::io::ErrorKind::TimedOut))??;
+        if read == 0 {
+            return Ok(Line::Closed);
+        }
+        if !line.ends_with(b"\n") {
+            if read as u64 == MAX_LINE_LENGTH && self.discard_line().await? {
+                return Ok(Line::TooLong);
+            }
+            return Ok(Line::Closed);
+        }
+        while line.last().is_some_and(|b| *b == b'\n' || *b == b'\r') {
+            line.pop();
+        }
+        Ok(Line::Complete(line))
+    }
+
+    /// Discards the rest of a line without buffering it, returns false if the connection was
+    /// closed before the end of the line
+    async fn discard_line(&mut self) -> std::io::Result<bool> {
+        tokio::time::timeout(TIMEOUT, async {
+            loop {
+                let buffer = self.stream.fill_buf().await?;
+                if buffer.is_empty() {
+                    return Ok(false);
+                }
+                match buffer.iter().position(|b| *b == b'\n') {
+                    Some(end) => {
+                        self.stream.consume(end + 1);
+                        return Ok(true);
+                    }
+                    None => {
+                        let length = buffer.len();
+                        self.stream.consume(length);
+                    }
+                }
+            }
+        })
+        .await
+        .map_err(|_| std::io::Error::from(std::io::ErrorKind::TimedOut))?
+    }
+
+    fn reset(&mut self) {
+        self.sender = None;
+        self.recipients.clear();
+    }
+
+    async fn serve(&mut self) -> std::io::Result<()> {
+        self.reply(&format!("220 {} ESMTP notis", self.config.domain))
+            .await?;
+        loop {
+            let line = match self.read_line().await? {
+                Line::Complete(line) => String::from_utf8_lossy(&line).into_owned(),
+                Line::TooLong => {
+                    self.reply("500 5.5.6 Line too long").await?;
+                    continue;
+                }
+                Line::Closed => return Ok(()),
+            };
+            let (command, arguments) = line.split_once(' ').unwrap_or((&line, ""));
+            match command.to_ascii_uppercase().as_str() {
+                "EHLO" => {
+                    self.reset();
+                    self.greeted = true;
+                    let reply = format!(
+                        "250-{}\r\n250-8BITMIME\r\n250-PIPELINING\r\n250 SIZE {}",
+                        self.config.domain, self.config.max_message_size
+                    );
+                    self.reply(&reply).await?;
+                }
+                "HELO" => {
+                    self.reset();
+                    self.greeted = true;
+                    let reply = format!("250 {}", self.config.domain);
+                    self.reply(&reply).await?;
+                }
+                "MAIL" => self.mail(arguments).await?,
+                "RCPT" => self.recipient(arguments).await?,
+                "DATA" => self.data().await?,
+                "RSET" => {
+                    self.reset();
+                    self.reply("250 2.0.0 OK").await?;
+                }
+                "NOOP" => self.reply("250 2.0.0 OK").await?,
+                "VRFY" => self.reply("252 2.1.5 Cannot verify user").await?,
+                "QUIT" => {
+                    self.reply("221 2.0.0 Bye").await?;
+                    return Ok(());
+                }
+                _ => self.reply("500 5.5.1 Command unrecognized").await?,
+            }
+        }
+    }
+
+    async fn mail(&mut self, arguments: &str) -> std::io::Result<()> {
+        if !self.greeted {
+            return self.reply("503 5.5.1 Send EHLO or HELO first").await;
+        }
+        if self.sender.is_some() {
+            return self.reply("503 5.5.1 Sender already specified").await;
+        }
+        let Some(sender) = path(arguments, "FROM:") else {
+            return self.reply("501 5.5.4 Syntax: MAIL FROM:<address>").await;
+        };
+        let size = arguments
+            .split_whitespace()
+            .filter_map(|parameter| parameter.split_once('='))
+            .find(|(key, _)| key.eq_ignore_ascii_case("SIZE"))
+            .and_then(|(_, value)| value.parse::<usize>().ok());
+        if size.is_some_and(|size| size > self.config.max_message_size) {
+            return self
+                .reply("552 5.3.4 Message size exceeds fixed maximum message size")
+                .await;
+        }
+        self.sender = Some(sender.to_string());
+        self.reply("250 2.1.0 OK").await
+    }
+
+    async fn recipient(&mut self, arguments: &str) -> std::io::Result<()> {
+        if self.sender.is_none() {
+            return self.reply("503 5.5.1 Send MAIL first").await;
+        }
+        let Some(recipient) = path(arguments, "TO:") else {
+            return self.reply("501 5.5.4 Syntax: RCPT TO:<address>").await;
+        };
+        let Some((service_id, domain)) = recipient.rsplit_once('@') else {
+            return self.reply("550 5.1.3 Invalid recipient address").await;
+        };
+        if !domain.eq_ignore_ascii_case(&self.config.domain) {
+            return self.reply("550 5.7.1 Relaying denied").await;
+        }
+        if !self.dispatcher.has_service(service_id) {
+            return self.reply("550 5.1.1 Unknown notification service").await;
+        }
+        if self.recipients.len() >= MAX_RECIPIENTS {
+            return self.reply("452 4.5.3 Too many recipients").await;
+        }
+        self.recipients.push(service_id.to_string());
+        self.reply("250 2.1.5 OK").await
+    }
+
+    /// Reads the message until the terminating `.` line and removes dot stuffing (RFC 5321 4.5.2)
+    async fn read_message(&mut self) -> std::io::Result<Option<Vec<u8>>> {
+        let mut message = Vec::new();
+        let mut too_large = false;
+        loop {
+            let line = match self.read_line().await? {
+                Line::Complete(line) => line,
+                Line::TooLong => {
+                    too_large = true;
+                    continue;
+                }
+                Line::Closed => {
+                    return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof));
+                }
+            };
+            if line == b"." {
+                return Ok((!too_large).then_some(message));
+            }
+            let line = line.strip_prefix(b".").unwrap_or(&line);
+            if message.len() + line.len() + 2 > self.config.max_message_size {
+                too_large = true;
+            }
+            if !too_large {
+                message.extend_from_slice(line);
+                message.extend_from_slice(b"\r\n");
+            }
+        }
+    }
+
+    async fn data(&mut self) -> std::io::Result<()> {
+        if self.sender.is_none() {
+            return self.reply("503 5.5.1 Send MAIL first").await;
+        }
+        if self.recipients.is_empty() {
+            return self.reply("554 5.5.1 No valid recipients").await;
+        }
+        self.reply("354 Start mail input; end with <CRLF>.<CRLF>")
+            .await?;
+        let message = self.read_message().await?;
+        let recipients = std::mem::take(&mut self.recipients);
+        self.reset();
+        let Some(message) = message else {
+            return self
+                .reply("552 5.3.4 Message size exceeds fixed maximum message size")
+                .await;
+        };
+        let Some(notification) = Notification::from_message(&message) else {
+            return self.reply("554 5.6.0 Malformed message").await;
+        };
+        let mut errors = Vec::new();
+        let dispatches = recipients.len();
+        for service_id in recipients {
+            info!(
+                "Dispatching notification '{}' to {service_id}",
+                notification.title
+            );
+            let result = self
+                .dispatcher
+                .dispatch(&service_id, notification.clone())
+                .await;
+            if let Err(e) = result {
+                warn!("Could not send notification via {service_id}: {e}");
+                errors.push(format!("{service_id}: {e}"));
+            }
+        }
+        // A failure reply would make the client resend the message to the services which queued it,
+        // so only failures of every service are reported
+        if errors.len() < dispatches {
+            self.reply("250 2.0.0 OK").await
+        } else {
+            let reply = format!(
+                "554 5.3.0 Sending the notification failed ({})",
+                errors.join("; ").replace(['\r', '\n'], " ")
+            );
+            self.reply(&reply).await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paths() {
+        assert_eq!(path("FROM:<a@b.c>", "FROM:"), Some("a@b.c"));
+        assert_eq!(path("from: <a@b.c> SIZE=10", "FROM:"), Some("a@b.c"));
+        assert_eq!(path("FROM:<>", "FROM:"), Some(""));
+        assert_eq!(path("TO:a@b.c", "TO:"), None);
+        assert_eq!(path("TO", "TO:"), None);
+    }
+
+    struct NoServices;
+
+    impl Dispatcher for NoServices {
+        fn has_service(&self, _id: &str) -> bool {
+            false
+        }
+
+        async fn dispatch(
+            &self,
+            _id: &str,
+            _notification: Notification,
+        ) -> Result<(), crate::Error> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn long_lines_are_discarded() {
+        let (client, server) = tokio::io::duplex(64);
+        let session = tokio::spawn(run(
+            server,
+            Arc::new(Config::example()),
+            Arc::new(NoServices),
+        ));
+        let (reader, mut writer) = tokio::io::split(client);
+        let writer = tokio::spawn(async move {
+            writer.write_all(b"NOOP ").await.unwrap();
+            for _ in 0..100 {
+                writer.write_all(&[b'x'; 1000]).await.unwrap();
+            }
+            writer.write_all(b"\r\nNOOP\r\nQUIT\r\n").await.unwrap();
+        });
+        let mut replies = Vec::new();
+        let mut lines = BufReader::new(reader).lines();
+        while let Some(line) = lines.next_line().await.unwrap() {
+            replies.push(line);
+        }
+        writer.await.unwrap();
+        session.await.unwrap();
+        assert_eq!(
+            replies,
+            [
+                "220 notis.local ESMTP notis",
+                "500 5.5.6 Line too long",
+                "250 2.0.0 OK",
+                "221 2.0.0 Bye"
+            ]
+        );
+    }
+}
@@ -0,0 +1,391 @@
+//! Named templates rendering the title, content and html of notifications from variables
+
+use crate::config::Config;
+use crate::services::{Metadata, types};
+use chrono::{DateTime, Utc};
+use minijinja::{AutoEscape, Environment};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+const SUBJECT: &str = "subject";
+const BODY: &str = "body";
+const HTML: &str = "html";
+/// Variable holding the fields of the notification
+const NOTIFICATION: &str = "notification";
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Unknown template {0}")]
+    UnknownTemplate(String),
+    #[error("Unknown service type {0}")]
+    UnknownServiceType(String),
+    #[error("Invalid {field} template{}: {error}", of_service_type(.service_type))]
+    Syntax {
+        field: &'static str,
+        service_type: Option<String>,
+        error: minijinja::Error,
+    },
+    #[error("Failed to render {field} template: {error}")]
+    Render {
+        field: &'static str,
+        error: minijinja::Error,
+    },
+}
+
+fn of_service_type(service_type: &Option<String>) -> String {
+    service_type
+        .as_ref()
+        .map(|service_type| format!(" of {service_type}"))
+        .unwrap_or_default()
+}
+
+/// Jinja templates of the fields of a notification, a field without template is sent as it was
+/// supplied
+#[derive(Debug, Default, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Template {
+    /// Template of the title, e.g. the subject of mails
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// Template of the content
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// Template of html content, which services supporting html send besides the content
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+    /// Templates for notifications sent via a service of the type, e.g. `smtp`, which take precedence
+    /// over the templates above
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub service_types: HashMap<String, Variant>,
+}
+
+#[derive(Debug, Default, PartialEq, Clone, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Variant {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+}
+
+/// Notification a template is rendered for
+pub struct Notification<'a> {
+    pub title: &'a str,
+    pub content: Option<&'a str>,
+    pub html: Option<&'a str>,
+    /// Whether the content is Markdown, which is rendered into the html if there is none
+    pub markdown: bool,
+    pub metadata: &'a Metadata,
+    /// Variables supplied with the notification
+    pub variables: &'a Map<String, Value>,
+}
+
+/// Fields of a notification which are sent
+#[derive(Debug, PartialEq, Clone)]
+pub struct Message {
+    pub title: String,
+    pub content: Option<String>,
+    pub html: Option<String>,
+}
+
+/// Renders Markdown with tables and strikethrough into html, raw html is escaped so the
+/// content cannot inject markup
+fn markdown_to_html(markdown: &str) -> String {
+    let options =
+        pulldown_cmark::Options::ENABLE_TABLES | pulldown_cmark::Options::ENABLE_STRIKETHROUGH;
+    let mut html = String::new();
+    pulldown_cmark::html::push_html(
+        &mut html,
+        pulldown_cmark::Parser::new_ext(markdown, options).map(|event| match event {
+            pulldown_cmark::Event::Html(html) | pulldown_cmark::Event::InlineHtml(html) => {
+                pulldown_cmark::Event::Text(html)
+            }
+            event => event,
+        }),
+    );
+    html
+}
+
+/// Html is escaped in values inserted into the html template
+fn environment() -> Environment<'static> {
+    let mut environment = Environment::new();
+    environment.set_auto_escape_callback(|name| match name {
+        HTML => AutoEscape::Html,
+        _ => AutoEscape::None,
+    });
+    environment
+}
+
+impl Variant {
+    fn fields(&self) -> [(&'static str, Option<&String>); 3] {
+        [
+            (SUBJECT, self.subject.as_ref()),
+            (BODY, self.body.as_ref()),
+            (HTML, self.html.as_ref()),
+        ]
+    }
+}
+
+impl Notification<'_> {
+    /// The variables and the fields of the notification as `notification`
+    fn context(&self) -> Value {
+        let mut context = self.variables.clone();
+        context.insert(
+            NOTIFICATION.to_string(),
+            serde_json::json!({
+                "title": self.title,
+                "content": self.content,
+                "severity": self.metadata.severity,
+                "tags": self.metadata.tags,
+                "source": self.metadata.source,
+                "timestamp": self
+                    .metadata
+                    .timestamp
+                    .map(|timestamp| DateTime::<Utc>::from(timestamp).to_rfc3339()),
+            }),
+        );
+        Value::Object(context)
+    }
+
+    /// Sends the fields as they were supplied
+    pub fn message(&self) -> Message {
+        let mut message = Message {
+            title: self.title.to_string(),
+            content: self.content.map(str::to_string),
+            html: self.html.map(str::to_string),
+        };
+        message.render_markdown(self.markdown);
+        message
+    }
+}
+
+impl Message {
+    fn render_markdown(&mut self, markdown: bool) {
+        if markdown && self.html.is_none() {
+            self.html = self.content.as_deref().map(markdown_to_html);
+        }
+    }
+}
+
+impl Template {
+    /// Templates for the service type, falling back to the templates of all service types
+    fn variant(&self, service_type: &str) -> Variant {
+        let variant = self.service_types.get(service_type);
+        let field = |default: &Option<String>, field: fn(&Variant) -> &Option<String>| {
+            variant
+                .and_then(|variant| field(variant).clone())
+                .or_else(|| default.clone())
+        };
+        Variant {
+            subject: field(&self.subject, |variant| &variant.subject),
+            body: field(&self.body, |variant| &variant.body),
+            html: field(&self.html, |variant| &variant.html),
+        }
+    }
+
+    /// Checks the syntax of the templates and that the service types exist
+    pub fn validate(&self) -> Result<(), Error> {
+        if let Some(service_type) = self
+            .service_types
+            .keys()
+            .find(|service_type| !types::ALL.contains(&service_type.as_str()))
+        {
+            return Err(Error::UnknownServiceType(service_type.clone()));
+        }
+        let default = Variant {
+            subject: self.subject.clone(),
+            body: self.body.clone(),
+            html: self.html.clone(),
+        };
+        let variants = std::iter::once((None, &default)).chain(
+            self.service_types
+                .iter()
+                .map(|(service_type, variant)| (Some(service_type), variant)),
+        );
+        for (service_type, variant) in variants {
+            let mut environment = environment();
+            for (field, source) in variant.fields() {
+                if let Some(source) = source {
+                    environment
+                        .add_template(field, source)
+                        .map_err(|error| Error::Syntax {
+                            field,
+                            service_type: service_type.cloned(),
+                            error,
+                        })?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Renders the notification for a service of the type, fields without template are kept
+    pub fn render(
+        &self,
+        service_type: &str,
+        notification: &Notification,
+    ) -> Result<Message, Error> {
+        let variant = self.variant(service_type);
+        let context = notification.context();
+        let environment = environment();
+        let render = |field, source: Option<&String>| {
+            source
+                .map(|source| {
+                    environment
+                        .template_from_named_str(field, source)?
+                        .render(&context)
+                })
+                .transpose()
+                .map_err(|error| Error::Render { field, error })
+        };
+        let mut message = Message {
+            title: notification.title.to_string(),
+            content: notification.content.map(str::to_string),
+            html: notification.html.map(str::to_string),
+        };
+        if let Some(title) = render(SUBJECT, variant.subject.as_ref())? {
+            message.title = title;
+        }
+        if let Some(content) = render(BODY, variant.body.as_ref())? {
+            message.content = Some(content);
+        }
+        if let Some(html) = render(HTML, variant.html.as_ref())? {
+            message.html = Some(html);
+        }
+        message.render_markdown(notification.markdown);
+        Ok(message)
+    }
+}
+
+/// Renders the notification with the template of the config if there is one, and otherwise sends
+/// it as it was supplied
+pub fn message(
+    config: &Config,
+    template: Option<&str>,
+    service_type: &str,
+    notification: &Notification,
+) -> Result<Message, Error> {
+    match template {
+        Some(id) => config
+            .templates
+            .get(id)
+            .ok_or_else(|| Error::UnknownTemplate(id.to_string()))?
+            .render(service_type, notification),
+        None => Ok(notification.message()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::Severity;
+
+    fn template() -> Template {
+        Template {
+            subject: Some("{{ machine }}: {{ notification.title }}".to_string()),
+            body: Some("Temperature is {{ temperature }} °C".to_string()),
+            html: None,
+            service_types: HashMap::from([(
+                "smtp".to_string(),
+                Variant {
+                    subject: None,
+                    body: None,
+                    html: Some("<b>{{ temperature }}</b> °C at {{ machine }}".to_string()),
+                },
+            )]),
+        }
+    }
+
+    #[test]
+    fn rendering() {
+        let metadata = Metadata {
+            severity: Some(Severity::Warning),
+            ..Default::default()
+        };
+        let variables = serde_json::json!({"machine": "<Press 3>", "temperature": 92});
+        let notification = Notification {
+            title: "Overheating",
+            content: None,
+            html: None,
+            markdown: false,
+            metadata: &metadata,
+            variables: variables.as_object().unwrap(),
+        };
+        assert_eq!(
+            template().render("log", &notification).unwrap(),
+            Message {
+                title: "<Press 3>: Overheating".to_string(),
+                content: Some("Temperature is 92 °C".to_string()),
+                html: None,
+            }
+        );
+        assert_eq!(
+            template().render("smtp", &notification).unwrap().html,
+            Some("<b>92</b> °C at &lt;Press 3&gt;".to_string())
+        );
+    }
+
+    #[test]
+    fn markdown() {
+        let metadata = Metadata::default();
+        let variables = Map::new();
+        let mut notification = Notification {
+            title: "Shift report",
+            content: Some("| Line | Output |\n|---|---|\n| 3 | **92** |"),
+            html: None,
+            markdown: true,
+            metadata: &metadata,
+            variables: &variables,
+        };
+        let html = notification.message().html.unwrap();
+        assert!(html.contains("<table>"));
+        assert!(html.contains("<strong>92</strong>"));
+        notification.content =
+            Some("<script>alert(1)</script>\n\nLine <img src=x onerror=alert(1)> **3**");
+        assert_eq!(
+            notification.message().html.as_deref(),
+            Some(
+                "&lt;script&gt;alert(1)&lt;/script&gt;\n<p>Line &lt;img src=x onerror=alert(1)&gt; <strong>3</strong></p>\n"
+            )
+        );
+        notification.html = Some("<p>Report</p>");
+        assert_eq!(
+            notification.message().html.as_deref(),
+            Some("<p>Report</p>")
+        );
+        notification.markdown = false;
+        notification.html = None;
+        assert_eq!(notification.message().html, None);
+    }
+
+    #[test]
+    fn validation() {
+        assert!(template().validate().is_ok());
+        let mut template = template();
+        template.service_types.insert(
+            "fax".to_string(),
+            Variant {
+                subject: Some("{{ title }}".to_string()),
+                ..Default::default()
+            },
+        );
+        assert!(matches!(
+            template.validate(),
+            Err(Error::UnknownServiceType(_))
+        ));
+        template.service_types.remove("fax");
+        template.service_types.get_mut("smtp").unwrap().body = Some("{% if %}".to_string());
+        assert!(matches!(
+            template.validate(),
+            Err(Error::Syntax {
+                field: BODY,
+                service_type: Some(_),
+                ..
+            })
+        ));
+    }
+}
@@ -7,7 +7,7 @@ use std::sync::Arc;
 use std::time::Duration;
 use tower_http::classify::ServerErrorsFailureClass;
 use tower_http::trace::DefaultOnResponse;
-use tracing::{Span, debug_span, info};
+use tracing::{Span, debug_span, error, info};
 
 fn init() -> anyhow::Result<notification::config::Config> {
     let config_path = notification::config::config_path();
@@ -47,10 +47,20 @@ async fn wait_for_shutdown_signal() {
 
 async fn serve(config: notification::config::Config, config_path: PathBuf) -> anyhow::Result<()> {
     let port = config.port;
-    let app = notis_server::server::new(Arc::new(notification::server::Server::new(
-        config,
-        config_path,
-    )));
+    let smtp_ingress = config.smtp_ingress.clone();
+    let server = Arc::new(notification::server::Server::new(config, config_path)?);
+    tokio::spawn(notification::queue::run(server.queue(), server.clone()));
+    tokio::spawn(notification::services::smtp::bounces::run(server.clone()));
+    tokio::spawn(notification::services::smtp::pool::run());
+    if let Some(smtp_ingress) = smtp_ingress {
+        let server = server.clone();
+        tokio::spawn(async move {
+            if let Err(e) = notification::smtp_ingress::serve(smtp_ingress, server).await {
+                error!("Smtp ingress stopped: {e}");
+            }
+        });
+    }
+    let app = notis_server::server::new(server);
     let app = app.layer(
         tower_http::trace::TraceLayer::new_for_http()
             .make_span_with(|request: &Request<_>| {
@@ -0,0 +1,61 @@
+use async_trait::async_trait;
+use axum::extract::*;
+use axum_extra::extract::{CookieJar, Multipart};
+use bytes::Bytes;
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{models, types::*};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum HistoryGetResponse {
+    /// Success
+    Status200_Success(models::HistoryPage),
+    /// Bad Request
+    Status400_BadRequest(models::Reason),
+    /// History is not enabled
+    Status404_HistoryIsNotEnabled,
+    /// Internal Server Error
+    Status500_InternalServerError(models::Reason),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum HistoryIdAttachmentsIndexGetResponse {
+    /// Success
+    Status200_Success(ByteArray),
+    /// Attachment not found
+    Status404_AttachmentNotFound,
+    /// Internal Server Error
+    Status500_InternalServerError(models::Reason),
+}
+
+/// History
+#[async_trait]
+#[allow(clippy::ptr_arg)]
+pub trait History {
+    /// Search the history of delivered and failed notifications, the most recent first.
+    ///
+    /// HistoryGet - GET /history
+    async fn history_get(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+        query_params: models::HistoryGetQueryParams,
+    ) -> Result<HistoryGetResponse, ()>;
+
+    /// Get the stored content of an attachment of a notification in the history.
+    ///
+    /// HistoryIdAttachmentsIndexGet - GET /history/{id}/attachments/{index}
+    async fn history_id_attachments_index_get(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+        path_params: models::HistoryIdAttachmentsIndexGetPathParams,
+    ) -> Result<HistoryIdAttachmentsIndexGetResponse, ()>;
+}
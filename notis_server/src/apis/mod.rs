@@ -1,2 +1,5 @@
+pub mod history;
 pub mod notifications;
+pub mod routes;
 pub mod services;
+pub mod templates;
@@ -10,9 +10,41 @@ use crate::{models, types::*};
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[must_use]
 #[allow(clippy::large_enum_variant)]
-pub enum NotificationsPostResponse {
+pub enum NotificationsFanoutPostResponse {
+    /// Result for each service, the notification may be queued for only some of them
+    Status202_ResultForEachService(Vec<models::FanoutResult>),
+    /// Bad Request
+    Status400_BadRequest(models::Reason),
+    /// Internal Server Error
+    Status500_InternalServerError(models::Reason),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum NotificationsGetResponse {
+    /// Success
+    Status200_Success(Vec<models::NotificationStatus>),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum NotificationsIdGetResponse {
     /// Success
-    Status200_Success,
+    Status200_Success(models::NotificationStatus),
+    /// Notification not found
+    Status404_NotificationNotFound,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum NotificationsPostResponse {
+    /// Notification was queued for delivery or routed
+    Status202_NotificationWasQueuedForDeliveryOrRouted(models::NotificationsPost202Response),
+    /// Bad Request
+    Status400_BadRequest(models::Reason),
     /// No default service found
     Status404_NoDefaultServiceFound,
     /// Internal Server Error
@@ -23,8 +55,8 @@ pub enum NotificationsPostResponse {
 #[must_use]
 #[allow(clippy::large_enum_variant)]
 pub enum ServicesIdNotificationsPostResponse {
-    /// Success
-    Status200_Success,
+    /// Notification was queued for delivery
+    Status202_NotificationWasQueuedForDelivery(models::NotificationId),
     /// Bad Request
     Status400_BadRequest(models::Reason),
     /// Service not found
@@ -37,6 +69,39 @@ pub enum ServicesIdNotificationsPostResponse {
 #[async_trait]
 #[allow(clippy::ptr_arg)]
 pub trait Notifications {
+    /// Send notification via multiple services and service groups, may contain additional options per service.
+    ///
+    /// NotificationsFanoutPost - POST /notifications/fanout
+    async fn notifications_fanout_post(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+        body: Multipart,
+    ) -> Result<NotificationsFanoutPostResponse, ()>;
+
+    /// Get the delivery status of notifications, optionally filtered.
+    ///
+    /// NotificationsGet - GET /notifications
+    async fn notifications_get(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+        query_params: models::NotificationsGetQueryParams,
+    ) -> Result<NotificationsGetResponse, ()>;
+
+    /// Get the delivery status of a notification.
+    ///
+    /// NotificationsIdGet - GET /notifications/{id}
+    async fn notifications_id_get(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+        path_params: models::NotificationsIdGetPathParams,
+    ) -> Result<NotificationsIdGetResponse, ()>;
+
     /// Send notification via the default service.
     ///
     /// NotificationsPost - POST /notifications
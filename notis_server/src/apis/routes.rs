@@ -0,0 +1,138 @@
+use async_trait::async_trait;
+use axum::extract::*;
+use axum_extra::extract::{CookieJar, Multipart};
+use bytes::Bytes;
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{models, types::*};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum RoutesDryRunPostResponse {
+    /// Success
+    Status200_Success(models::RouteDryRun),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum RoutesGetResponse {
+    /// Success
+    Status200_Success(std::collections::HashMap<String, crate::types::Object>),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum RoutesIdDeleteResponse {
+    /// Success
+    Status200_Success,
+    /// Route not found
+    Status404_RouteNotFound,
+    /// Internal Server Error
+    Status500_InternalServerError(models::Reason),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum RoutesIdGetResponse {
+    /// Success
+    Status200_Success(crate::types::Object),
+    /// Route not found
+    Status404_RouteNotFound,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum RoutesIdPutResponse {
+    /// Route was replaced
+    Status200_RouteWasReplaced,
+    /// Route was created
+    Status201_RouteWasCreated,
+    /// Bad Request
+    Status400_BadRequest(models::Reason),
+    /// Internal Server Error
+    Status500_InternalServerError(models::Reason),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum SchemaRouteGetResponse {
+    /// Success
+    Status200_Success(crate::types::Object),
+}
+
+/// Routes
+#[async_trait]
+#[allow(clippy::ptr_arg)]
+pub trait Routes {
+    /// Show the routes a notification would match and the targets it would be sent to.
+    ///
+    /// RoutesDryRunPost - POST /routes/dry_run
+    async fn routes_dry_run_post(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+        body: models::RouteSample,
+    ) -> Result<RoutesDryRunPostResponse, ()>;
+
+    /// Get all routes.
+    ///
+    /// RoutesGet - GET /routes
+    async fn routes_get(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+    ) -> Result<RoutesGetResponse, ()>;
+
+    /// Delete the route.
+    ///
+    /// RoutesIdDelete - DELETE /routes/{id}
+    async fn routes_id_delete(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+        path_params: models::RoutesIdDeletePathParams,
+    ) -> Result<RoutesIdDeleteResponse, ()>;
+
+    /// Get the route.
+    ///
+    /// RoutesIdGet - GET /routes/{id}
+    async fn routes_id_get(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+        path_params: models::RoutesIdGetPathParams,
+    ) -> Result<RoutesIdGetResponse, ()>;
+
+    /// Create a new route, or replace an existing one.
+    ///
+    /// RoutesIdPut - PUT /routes/{id}
+    async fn routes_id_put(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+        path_params: models::RoutesIdPutPathParams,
+        body: crate::types::Object,
+    ) -> Result<RoutesIdPutResponse, ()>;
+
+    /// Get the schema of routes.
+    ///
+    /// SchemaRouteGet - GET /schema/route
+    async fn schema_route_get(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+    ) -> Result<SchemaRouteGetResponse, ()>;
+}
@@ -141,6 +141,16 @@ pub enum ServicesIdPutResponse {
     Status500_InternalServerError(models::Reason),
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum ServicesIdReceiversGetResponse {
+    /// Success
+    Status200_Success(Vec<models::ReceiverHealth>),
+    /// Service not found
+    Status404_ServiceNotFound,
+}
+
 /// Services
 #[async_trait]
 #[allow(clippy::ptr_arg)]
@@ -276,4 +286,15 @@ pub trait Services {
         path_params: models::ServicesIdPutPathParams,
         body: models::ServicesIdPutRequest,
     ) -> Result<ServicesIdPutResponse, ()>;
+
+    /// Get the health of the receivers of a mail service reported by delivery status notifications.
+    ///
+    /// ServicesIdReceiversGet - GET /services/{id}/receivers
+    async fn services_id_receivers_get(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+        path_params: models::ServicesIdReceiversGetPathParams,
+    ) -> Result<ServicesIdReceiversGetResponse, ()>;
 }
@@ -0,0 +1,143 @@
+use async_trait::async_trait;
+use axum::extract::*;
+use axum_extra::extract::{CookieJar, Multipart};
+use bytes::Bytes;
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{models, types::*};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum SchemaTemplateGetResponse {
+    /// Success
+    Status200_Success(crate::types::Object),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum TemplatesGetResponse {
+    /// Success
+    Status200_Success(std::collections::HashMap<String, crate::types::Object>),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum TemplatesIdDeleteResponse {
+    /// Success
+    Status200_Success,
+    /// Template not found
+    Status404_TemplateNotFound,
+    /// Internal Server Error
+    Status500_InternalServerError(models::Reason),
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum TemplatesIdGetResponse {
+    /// Success
+    Status200_Success(crate::types::Object),
+    /// Template not found
+    Status404_TemplateNotFound,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum TemplatesIdPreviewPostResponse {
+    /// Success
+    Status200_Success(models::RenderedTemplate),
+    /// Bad Request
+    Status400_BadRequest(models::Reason),
+    /// Template not found
+    Status404_TemplateNotFound,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum TemplatesIdPutResponse {
+    /// Template was replaced
+    Status200_TemplateWasReplaced,
+    /// Template was created
+    Status201_TemplateWasCreated,
+    /// Bad Request
+    Status400_BadRequest(models::Reason),
+    /// Internal Server Error
+    Status500_InternalServerError(models::Reason),
+}
+
+/// Templates
+#[async_trait]
+#[allow(clippy::ptr_arg)]
+pub trait Templates {
+    /// Get the schema of templates.
+    ///
+    /// SchemaTemplateGet - GET /schema/template
+    async fn schema_template_get(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+    ) -> Result<SchemaTemplateGetResponse, ()>;
+
+    /// Get all templates.
+    ///
+    /// TemplatesGet - GET /templates
+    async fn templates_get(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+    ) -> Result<TemplatesGetResponse, ()>;
+
+    /// Delete the template.
+    ///
+    /// TemplatesIdDelete - DELETE /templates/{id}
+    async fn templates_id_delete(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+        path_params: models::TemplatesIdDeletePathParams,
+    ) -> Result<TemplatesIdDeleteResponse, ()>;
+
+    /// Get the template.
+    ///
+    /// TemplatesIdGet - GET /templates/{id}
+    async fn templates_id_get(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+        path_params: models::TemplatesIdGetPathParams,
+    ) -> Result<TemplatesIdGetResponse, ()>;
+
+    /// Render a sample notification with the template.
+    ///
+    /// TemplatesIdPreviewPost - POST /templates/{id}/preview
+    async fn templates_id_preview_post(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+        path_params: models::TemplatesIdPreviewPostPathParams,
+        body: models::TemplateSample,
+    ) -> Result<TemplatesIdPreviewPostResponse, ()>;
+
+    /// Create a new template, or replace an existing one.
+    ///
+    /// TemplatesIdPut - PUT /templates/{id}
+    async fn templates_id_put(
+        &self,
+        method: Method,
+        host: Host,
+        cookies: CookieJar,
+        path_params: models::TemplatesIdPutPathParams,
+        body: crate::types::Object,
+    ) -> Result<TemplatesIdPutResponse, ()>;
+}
@@ -7,12 +7,91 @@ use validator::Validate;
 use crate::header;
 use crate::{models, types::*};
 
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct HistoryGetQueryParams {
+    #[serde(rename = "service")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<models::DeliveryStatus>,
+    /// Only notifications finished at or after this time
+    #[serde(rename = "since")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+    /// Only notifications finished before this time
+    #[serde(rename = "until")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until: Option<chrono::DateTime<chrono::Utc>>,
+    /// Words which all have to occur in the title or content
+    #[serde(rename = "search")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search: Option<String>,
+    #[serde(rename = "offset")]
+    #[validate(range(min = 0i32))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i32>,
+    #[serde(rename = "limit")]
+    #[validate(range(min = 1i32, max = 1000i32))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct HistoryIdAttachmentsIndexGetPathParams {
+    pub id: uuid::Uuid,
+    /// Position of the attachment in the notification
+    #[validate(range(min = 0i32))]
+    pub index: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct NotificationsGetQueryParams {
+    #[serde(rename = "service")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<models::DeliveryStatus>,
+    /// Only notifications created at or after this time
+    #[serde(rename = "since")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct NotificationsIdGetPathParams {
+    pub id: uuid::Uuid,
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
 pub struct ServicesIdNotificationsPostPathParams {
     pub id: String,
 }
 
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct RoutesIdDeletePathParams {
+    pub id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct RoutesIdGetPathParams {
+    pub id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct RoutesIdPutPathParams {
+    pub id: String,
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
 pub struct SchemaServiceTypesServiceTypeConfigGetPathParams {
@@ -69,6 +148,36 @@ pub struct ServicesIdPutPathParams {
     pub id: String,
 }
 
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ServicesIdReceiversGetPathParams {
+    pub id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct TemplatesIdDeletePathParams {
+    pub id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct TemplatesIdGetPathParams {
+    pub id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct TemplatesIdPreviewPostPathParams {
+    pub id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct TemplatesIdPutPathParams {
+    pub id: String,
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
 pub struct DefaultServiceGet200Response {
@@ -351,40 +460,96 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<DefaultServi
     }
 }
 
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize, Hash,
+)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum DeliveryStatus {
+    #[serde(rename = "queued")]
+    Queued,
+    #[serde(rename = "sending")]
+    Sending,
+    #[serde(rename = "retrying")]
+    Retrying,
+    #[serde(rename = "delivered")]
+    Delivered,
+    #[serde(rename = "failed")]
+    Failed,
+}
+
+impl std::fmt::Display for DeliveryStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            DeliveryStatus::Queued => write!(f, "queued"),
+            DeliveryStatus::Sending => write!(f, "sending"),
+            DeliveryStatus::Retrying => write!(f, "retrying"),
+            DeliveryStatus::Delivered => write!(f, "delivered"),
+            DeliveryStatus::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+impl std::str::FromStr for DeliveryStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "queued" => std::result::Result::Ok(DeliveryStatus::Queued),
+            "sending" => std::result::Result::Ok(DeliveryStatus::Sending),
+            "retrying" => std::result::Result::Ok(DeliveryStatus::Retrying),
+            "delivered" => std::result::Result::Ok(DeliveryStatus::Delivered),
+            "failed" => std::result::Result::Ok(DeliveryStatus::Failed),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct NotificationsPostRequest {
-    /// Short title of the notification
-    #[serde(rename = "title")]
-    pub title: String,
+pub struct FanoutResult {
+    /// Id of the notification service
+    #[serde(rename = "service")]
+    pub service: String,
 
-    /// Optional content of the notification
-    #[serde(rename = "content")]
+    /// Id of the queued notification, missing if the notification could not be queued
+    #[serde(rename = "id")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content: Option<String>,
+    pub id: Option<uuid::Uuid>,
+
+    #[serde(rename = "error")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<models::Reason>,
 }
 
-impl NotificationsPostRequest {
+impl FanoutResult {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(title: String) -> NotificationsPostRequest {
-        NotificationsPostRequest {
-            title,
-            content: None,
+    pub fn new(service: String) -> FanoutResult {
+        FanoutResult {
+            service,
+            id: None,
+            error: None,
         }
     }
 }
 
-/// Converts the NotificationsPostRequest value to the Query Parameters representation (style=form, explode=false)
+/// Converts the FanoutResult value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for NotificationsPostRequest {
+impl std::fmt::Display for FanoutResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("title".to_string()),
-            Some(self.title.to_string()),
-            self.content
+            Some("service".to_string()),
+            Some(self.service.to_string()),
+            self.id
                 .as_ref()
-                .map(|content| ["content".to_string(), content.to_string()].join(",")),
+                .map(|id| ["id".to_string(), id.to_string()].join(",")),
+            // Skipping error in query parameter serialization
         ];
 
         write!(
@@ -395,10 +560,10 @@ impl std::fmt::Display for NotificationsPostRequest {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a NotificationsPostRequest value
+/// Converts Query Parameters representation (style=form, explode=false) to a FanoutResult value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for NotificationsPostRequest {
+impl std::str::FromStr for FanoutResult {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -406,8 +571,9 @@ impl std::str::FromStr for NotificationsPostRequest {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub title: Vec<String>,
-            pub content: Vec<String>,
+            pub service: Vec<String>,
+            pub id: Vec<uuid::Uuid>,
+            pub error: Vec<models::Reason>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -421,7 +587,7 @@ impl std::str::FromStr for NotificationsPostRequest {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing NotificationsPostRequest".to_string(),
+                        "Missing value while parsing FanoutResult".to_string(),
                     )
                 }
             };
@@ -430,16 +596,22 @@ impl std::str::FromStr for NotificationsPostRequest {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "title" => intermediate_rep.title.push(
+                    "service" => intermediate_rep.service.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "content" => intermediate_rep.content.push(
-                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    "id" => intermediate_rep.id.push(
+                        <uuid::Uuid as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "error" => intermediate_rep.error.push(
+                        <models::Reason as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing NotificationsPostRequest".to_string(),
+                            "Unexpected key while parsing FanoutResult".to_string(),
                         )
                     }
                 }
@@ -450,31 +622,32 @@ impl std::str::FromStr for NotificationsPostRequest {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(NotificationsPostRequest {
-            title: intermediate_rep
-                .title
+        std::result::Result::Ok(FanoutResult {
+            service: intermediate_rep
+                .service
                 .into_iter()
                 .next()
-                .ok_or_else(|| "title missing in NotificationsPostRequest".to_string())?,
-            content: intermediate_rep.content.into_iter().next(),
+                .ok_or_else(|| "service missing in FanoutResult".to_string())?,
+            id: intermediate_rep.id.into_iter().next(),
+            error: intermediate_rep.error.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<NotificationsPostRequest> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<FanoutResult> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<NotificationsPostRequest>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<FanoutResult>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<NotificationsPostRequest>,
+        hdr_value: header::IntoHeaderValue<FanoutResult>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for NotificationsPostRequest - value: {} is invalid {}",
+                "Invalid header value for FanoutResult - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -482,18 +655,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<NotificationsPostRequest>> fo
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<NotificationsPostRequest> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<FanoutResult> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <NotificationsPostRequest as std::str::FromStr>::from_str(value) {
+                match <FanoutResult as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into NotificationsPostRequest - {}",
+                        "Unable to convert header value '{}' into FanoutResult - {}",
                         value, err
                     )),
                 }
@@ -508,28 +681,54 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Notification
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct Reason {
-    #[serde(rename = "reason")]
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub reason: Option<String>,
+pub struct HistoryAttachment {
+    #[serde(rename = "file_name")]
+    pub file_name: String,
+
+    #[serde(rename = "content_type")]
+    pub content_type: String,
+
+    /// Size of the attachment in bytes
+    #[serde(rename = "size")]
+    pub size: i64,
+
+    /// Whether the content of the attachment is stored and can be downloaded
+    #[serde(rename = "stored")]
+    pub stored: bool,
 }
 
-impl Reason {
+impl HistoryAttachment {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new() -> Reason {
-        Reason { reason: None }
+    pub fn new(
+        file_name: String,
+        content_type: String,
+        size: i64,
+        stored: bool,
+    ) -> HistoryAttachment {
+        HistoryAttachment {
+            file_name,
+            content_type,
+            size,
+            stored,
+        }
     }
 }
 
-/// Converts the Reason value to the Query Parameters representation (style=form, explode=false)
+/// Converts the HistoryAttachment value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for Reason {
+impl std::fmt::Display for HistoryAttachment {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let params: Vec<Option<String>> = vec![self
-            .reason
-            .as_ref()
-            .map(|reason| ["reason".to_string(), reason.to_string()].join(","))];
+        let params: Vec<Option<String>> = vec![
+            Some("file_name".to_string()),
+            Some(self.file_name.to_string()),
+            Some("content_type".to_string()),
+            Some(self.content_type.to_string()),
+            Some("size".to_string()),
+            Some(self.size.to_string()),
+            Some("stored".to_string()),
+            Some(self.stored.to_string()),
+        ];
 
         write!(
             f,
@@ -539,10 +738,10 @@ impl std::fmt::Display for Reason {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a Reason value
+/// Converts Query Parameters representation (style=form, explode=false) to a HistoryAttachment value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for Reason {
+impl std::str::FromStr for HistoryAttachment {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -550,7 +749,10 @@ impl std::str::FromStr for Reason {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub reason: Vec<String>,
+            pub file_name: Vec<String>,
+            pub content_type: Vec<String>,
+            pub size: Vec<i64>,
+            pub stored: Vec<bool>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -564,7 +766,7 @@ impl std::str::FromStr for Reason {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing Reason".to_string(),
+                        "Missing value while parsing HistoryAttachment".to_string(),
                     )
                 }
             };
@@ -573,12 +775,24 @@ impl std::str::FromStr for Reason {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "reason" => intermediate_rep.reason.push(
+                    "file_name" => intermediate_rep.file_name.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "content_type" => intermediate_rep.content_type.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
+                    #[allow(clippy::redundant_clone)]
+                    "size" => intermediate_rep.size.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "stored" => intermediate_rep.stored.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing Reason".to_string(),
+                            "Unexpected key while parsing HistoryAttachment".to_string(),
                         )
                     }
                 }
@@ -589,26 +803,45 @@ impl std::str::FromStr for Reason {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(Reason {
-            reason: intermediate_rep.reason.into_iter().next(),
+        std::result::Result::Ok(HistoryAttachment {
+            file_name: intermediate_rep
+                .file_name
+                .into_iter()
+                .next()
+                .ok_or_else(|| "file_name missing in HistoryAttachment".to_string())?,
+            content_type: intermediate_rep
+                .content_type
+                .into_iter()
+                .next()
+                .ok_or_else(|| "content_type missing in HistoryAttachment".to_string())?,
+            size: intermediate_rep
+                .size
+                .into_iter()
+                .next()
+                .ok_or_else(|| "size missing in HistoryAttachment".to_string())?,
+            stored: intermediate_rep
+                .stored
+                .into_iter()
+                .next()
+                .ok_or_else(|| "stored missing in HistoryAttachment".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<Reason> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<HistoryAttachment> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<Reason>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<HistoryAttachment>> for HeaderValue {
     type Error = String;
 
     fn try_from(
-        hdr_value: header::IntoHeaderValue<Reason>,
+        hdr_value: header::IntoHeaderValue<HistoryAttachment>,
     ) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
             std::result::Result::Ok(value) => std::result::Result::Ok(value),
             std::result::Result::Err(e) => std::result::Result::Err(format!(
-                "Invalid header value for Reason - value: {} is invalid {}",
+                "Invalid header value for HistoryAttachment - value: {} is invalid {}",
                 hdr_value, e
             )),
         }
@@ -616,18 +849,18 @@ impl std::convert::TryFrom<header::IntoHeaderValue<Reason>> for HeaderValue {
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Reason> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<HistoryAttachment> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
             std::result::Result::Ok(value) => {
-                match <Reason as std::str::FromStr>::from_str(value) {
+                match <HistoryAttachment as std::str::FromStr>::from_str(value) {
                     std::result::Result::Ok(value) => {
                         std::result::Result::Ok(header::IntoHeaderValue(value))
                     }
                     std::result::Result::Err(err) => std::result::Result::Err(format!(
-                        "Unable to convert header value '{}' into Reason - {}",
+                        "Unable to convert header value '{}' into HistoryAttachment - {}",
                         value, err
                     )),
                 }
@@ -642,31 +875,117 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Reason> {
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct ServicesIdGet200Response {
-    #[serde(rename = "type")]
-    pub r#type: String,
-
+pub struct HistoryEntry {
     #[serde(rename = "id")]
-    pub id: String,
+    pub id: uuid::Uuid,
+
+    /// Id of the notification service
+    #[serde(rename = "service")]
+    pub service: String,
+
+    #[serde(rename = "title")]
+    pub title: String,
+
+    #[serde(rename = "content")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+
+    #[serde(rename = "receivers")]
+    pub receivers: Vec<String>,
+
+    #[serde(rename = "attachments")]
+    #[validate(nested)]
+    pub attachments: Vec<models::HistoryAttachment>,
+
+    #[serde(rename = "status")]
+    pub status: models::DeliveryStatus,
+
+    #[serde(rename = "attempts")]
+    pub attempts: i32,
+
+    #[serde(rename = "error")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<models::Reason>,
+
+    #[serde(rename = "created")]
+    pub created: chrono::DateTime<chrono::Utc>,
+
+    /// Time of the delivery or of the last failed attempt
+    #[serde(rename = "finished")]
+    pub finished: chrono::DateTime<chrono::Utc>,
+
+    /// Id of the notification service that delivered the notification, differs from service for failover chains
+    #[serde(rename = "delivered_by")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivered_by: Option<String>,
 }
 
-impl ServicesIdGet200Response {
+impl HistoryEntry {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(r#type: String, id: String) -> ServicesIdGet200Response {
-        ServicesIdGet200Response { r#type, id }
+    pub fn new(
+        id: uuid::Uuid,
+        service: String,
+        title: String,
+        receivers: Vec<String>,
+        attachments: Vec<models::HistoryAttachment>,
+        status: models::DeliveryStatus,
+        attempts: i32,
+        created: chrono::DateTime<chrono::Utc>,
+        finished: chrono::DateTime<chrono::Utc>,
+    ) -> HistoryEntry {
+        HistoryEntry {
+            id,
+            service,
+            title,
+            content: None,
+            receivers,
+            attachments,
+            status,
+            attempts,
+            error: None,
+            created,
+            finished,
+            delivered_by: None,
+        }
     }
 }
 
-/// Converts the ServicesIdGet200Response value to the Query Parameters representation (style=form, explode=false)
+/// Converts the HistoryEntry value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for ServicesIdGet200Response {
+impl std::fmt::Display for HistoryEntry {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            Some("type".to_string()),
-            Some(self.r#type.to_string()),
             Some("id".to_string()),
             Some(self.id.to_string()),
+            Some("service".to_string()),
+            Some(self.service.to_string()),
+            Some("title".to_string()),
+            Some(self.title.to_string()),
+            self.content
+                .as_ref()
+                .map(|content| ["content".to_string(), content.to_string()].join(",")),
+            Some("receivers".to_string()),
+            Some(
+                self.receivers
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+            // Skipping attachments in query parameter serialization
+            // Skipping status in query parameter serialization
+            Some("attempts".to_string()),
+            Some(self.attempts.to_string()),
+            // Skipping error in query parameter serialization
+            Some("created".to_string()),
+            Some(self.created.to_string()),
+            Some("finished".to_string()),
+            Some(self.finished.to_string()),
+            self.delivered_by.as_ref().map(|delivered_by| {
+                ["delivered_by".to_string(), delivered_by.to_string()].join(",")
+            }),
         ];
 
         write!(
@@ -677,10 +996,10 @@ impl std::fmt::Display for ServicesIdGet200Response {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a ServicesIdGet200Response value
+/// Converts Query Parameters representation (style=form, explode=false) to a HistoryEntry value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for ServicesIdGet200Response {
+impl std::str::FromStr for HistoryEntry {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -688,8 +1007,18 @@ impl std::str::FromStr for ServicesIdGet200Response {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub r#type: Vec<String>,
-            pub id: Vec<String>,
+            pub id: Vec<uuid::Uuid>,
+            pub service: Vec<String>,
+            pub title: Vec<String>,
+            pub content: Vec<String>,
+            pub receivers: Vec<Vec<String>>,
+            pub attachments: Vec<Vec<models::HistoryAttachment>>,
+            pub status: Vec<models::DeliveryStatus>,
+            pub attempts: Vec<i32>,
+            pub error: Vec<models::Reason>,
+            pub created: Vec<chrono::DateTime<chrono::Utc>>,
+            pub finished: Vec<chrono::DateTime<chrono::Utc>>,
+            pub delivered_by: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -703,7 +1032,7 @@ impl std::str::FromStr for ServicesIdGet200Response {
                 Some(x) => x,
                 None => {
                     return std::result::Result::Err(
-                        "Missing value while parsing ServicesIdGet200Response".to_string(),
+                        "Missing value while parsing HistoryEntry".to_string(),
                     )
                 }
             };
@@ -712,16 +1041,65 @@ impl std::str::FromStr for ServicesIdGet200Response {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "type" => intermediate_rep.r#type.push(
+                    "id" => intermediate_rep.id.push(
+                        <uuid::Uuid as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "service" => intermediate_rep.service.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     #[allow(clippy::redundant_clone)]
-                    "id" => intermediate_rep.id.push(
+                    "title" => intermediate_rep.title.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "content" => intermediate_rep.content.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "receivers" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in HistoryEntry"
+                                .to_string(),
+                        )
+                    }
+                    "attachments" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in HistoryEntry"
+                                .to_string(),
+                        )
+                    }
+                    #[allow(clippy::redundant_clone)]
+                    "status" => intermediate_rep.status.push(
+                        <models::DeliveryStatus as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "attempts" => intermediate_rep.attempts.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "error" => intermediate_rep.error.push(
+                        <models::Reason as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "created" => intermediate_rep.created.push(
+                        <chrono::DateTime<chrono::Utc> as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "finished" => intermediate_rep.finished.push(
+                        <chrono::DateTime<chrono::Utc> as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "delivered_by" => intermediate_rep.delivered_by.push(
                         <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
                     ),
                     _ => {
                         return std::result::Result::Err(
-                            "Unexpected key while parsing ServicesIdGet200Response".to_string(),
+                            "Unexpected key while parsing HistoryEntry".to_string(),
                         )
                     }
                 }
@@ -732,9 +1110,2538 @@ impl std::str::FromStr for ServicesIdGet200Response {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(ServicesIdGet200Response {
-            r#type: intermediate_rep
-                .r#type
+        std::result::Result::Ok(HistoryEntry {
+            id: intermediate_rep
+                .id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "id missing in HistoryEntry".to_string())?,
+            service: intermediate_rep
+                .service
+                .into_iter()
+                .next()
+                .ok_or_else(|| "service missing in HistoryEntry".to_string())?,
+            title: intermediate_rep
+                .title
+                .into_iter()
+                .next()
+                .ok_or_else(|| "title missing in HistoryEntry".to_string())?,
+            content: intermediate_rep.content.into_iter().next(),
+            receivers: intermediate_rep
+                .receivers
+                .into_iter()
+                .next()
+                .ok_or_else(|| "receivers missing in HistoryEntry".to_string())?,
+            attachments: intermediate_rep
+                .attachments
+                .into_iter()
+                .next()
+                .ok_or_else(|| "attachments missing in HistoryEntry".to_string())?,
+            status: intermediate_rep
+                .status
+                .into_iter()
+                .next()
+                .ok_or_else(|| "status missing in HistoryEntry".to_string())?,
+            attempts: intermediate_rep
+                .attempts
+                .into_iter()
+                .next()
+                .ok_or_else(|| "attempts missing in HistoryEntry".to_string())?,
+            error: intermediate_rep.error.into_iter().next(),
+            created: intermediate_rep
+                .created
+                .into_iter()
+                .next()
+                .ok_or_else(|| "created missing in HistoryEntry".to_string())?,
+            finished: intermediate_rep
+                .finished
+                .into_iter()
+                .next()
+                .ok_or_else(|| "finished missing in HistoryEntry".to_string())?,
+            delivered_by: intermediate_rep.delivered_by.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<HistoryEntry> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<HistoryEntry>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<HistoryEntry>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for HistoryEntry - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<HistoryEntry> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <HistoryEntry as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into HistoryEntry - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct HistoryPage {
+    /// Number of all notifications matching the query
+    #[serde(rename = "total")]
+    pub total: i64,
+
+    #[serde(rename = "entries")]
+    #[validate(nested)]
+    pub entries: Vec<models::HistoryEntry>,
+}
+
+impl HistoryPage {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(total: i64, entries: Vec<models::HistoryEntry>) -> HistoryPage {
+        HistoryPage { total, entries }
+    }
+}
+
+/// Converts the HistoryPage value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for HistoryPage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("total".to_string()),
+            Some(self.total.to_string()),
+            // Skipping entries in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a HistoryPage value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for HistoryPage {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub total: Vec<i64>,
+            pub entries: Vec<Vec<models::HistoryEntry>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing HistoryPage".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "total" => intermediate_rep.total.push(
+                        <i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "entries" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in HistoryPage"
+                                .to_string(),
+                        )
+                    }
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing HistoryPage".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(HistoryPage {
+            total: intermediate_rep
+                .total
+                .into_iter()
+                .next()
+                .ok_or_else(|| "total missing in HistoryPage".to_string())?,
+            entries: intermediate_rep
+                .entries
+                .into_iter()
+                .next()
+                .ok_or_else(|| "entries missing in HistoryPage".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<HistoryPage> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<HistoryPage>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<HistoryPage>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for HistoryPage - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<HistoryPage> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <HistoryPage as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into HistoryPage - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct NotificationId {
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+}
+
+impl NotificationId {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(id: uuid::Uuid) -> NotificationId {
+        NotificationId { id }
+    }
+}
+
+/// Converts the NotificationId value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for NotificationId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![Some("id".to_string()), Some(self.id.to_string())];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a NotificationId value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for NotificationId {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub id: Vec<uuid::Uuid>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing NotificationId".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(
+                        <uuid::Uuid as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing NotificationId".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(NotificationId {
+            id: intermediate_rep
+                .id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "id missing in NotificationId".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<NotificationId> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<NotificationId>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<NotificationId>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for NotificationId - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<NotificationId> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <NotificationId as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into NotificationId - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct NotificationStatus {
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+
+    /// Id of the notification service
+    #[serde(rename = "service")]
+    pub service: String,
+
+    #[serde(rename = "status")]
+    pub status: models::DeliveryStatus,
+
+    /// Number of finished delivery attempts
+    #[serde(rename = "attempts")]
+    pub attempts: i32,
+
+    #[serde(rename = "created")]
+    pub created: chrono::DateTime<chrono::Utc>,
+
+    /// Time of the last change of the status
+    #[serde(rename = "updated")]
+    pub updated: chrono::DateTime<chrono::Utc>,
+
+    /// Time of the next delivery attempt of queued and retrying notifications
+    #[serde(rename = "next_attempt")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_attempt: Option<chrono::DateTime<chrono::Utc>>,
+
+    #[serde(rename = "last_error")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<models::Reason>,
+
+    /// Id of the notification service that delivered the notification, differs from service for failover chains
+    #[serde(rename = "delivered_by")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivered_by: Option<String>,
+
+    /// Delivery status notifications of the receivers read from the bounce mailbox of a mail service
+    #[serde(rename = "receiver_reports")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receiver_reports: Option<Vec<models::ReceiverReport>>,
+}
+
+impl NotificationStatus {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        id: uuid::Uuid,
+        service: String,
+        status: models::DeliveryStatus,
+        attempts: i32,
+        created: chrono::DateTime<chrono::Utc>,
+        updated: chrono::DateTime<chrono::Utc>,
+    ) -> NotificationStatus {
+        NotificationStatus {
+            id,
+            service,
+            status,
+            attempts,
+            created,
+            updated,
+            next_attempt: None,
+            last_error: None,
+            delivered_by: None,
+            receiver_reports: None,
+        }
+    }
+}
+
+/// Converts the NotificationStatus value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for NotificationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+            Some("service".to_string()),
+            Some(self.service.to_string()),
+            // Skipping status in query parameter serialization
+            Some("attempts".to_string()),
+            Some(self.attempts.to_string()),
+            Some("created".to_string()),
+            Some(self.created.to_string()),
+            Some("updated".to_string()),
+            Some(self.updated.to_string()),
+            self.next_attempt.as_ref().map(|next_attempt| {
+                ["next_attempt".to_string(), next_attempt.to_string()].join(",")
+            }),
+            // Skipping last_error in query parameter serialization
+            self.delivered_by.as_ref().map(|delivered_by| {
+                ["delivered_by".to_string(), delivered_by.to_string()].join(",")
+            }),
+            // Skipping receiver_reports in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a NotificationStatus value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for NotificationStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub id: Vec<uuid::Uuid>,
+            pub service: Vec<String>,
+            pub status: Vec<models::DeliveryStatus>,
+            pub attempts: Vec<i32>,
+            pub created: Vec<chrono::DateTime<chrono::Utc>>,
+            pub updated: Vec<chrono::DateTime<chrono::Utc>>,
+            pub next_attempt: Vec<chrono::DateTime<chrono::Utc>>,
+            pub last_error: Vec<models::Reason>,
+            pub delivered_by: Vec<String>,
+            pub receiver_reports: Vec<Vec<models::ReceiverReport>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing NotificationStatus".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(
+                        <uuid::Uuid as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "service" => intermediate_rep.service.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "status" => intermediate_rep.status.push(
+                        <models::DeliveryStatus as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "attempts" => intermediate_rep.attempts.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "created" => intermediate_rep.created.push(
+                        <chrono::DateTime<chrono::Utc> as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "updated" => intermediate_rep.updated.push(
+                        <chrono::DateTime<chrono::Utc> as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "next_attempt" => intermediate_rep.next_attempt.push(
+                        <chrono::DateTime<chrono::Utc> as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "last_error" => intermediate_rep.last_error.push(
+                        <models::Reason as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "delivered_by" => intermediate_rep.delivered_by.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "receiver_reports" => return std::result::Result::Err(
+                        "Parsing a container in this style is not supported in NotificationStatus"
+                            .to_string(),
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing NotificationStatus".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(NotificationStatus {
+            id: intermediate_rep
+                .id
+                .into_iter()
+                .next()
+                .ok_or_else(|| "id missing in NotificationStatus".to_string())?,
+            service: intermediate_rep
+                .service
+                .into_iter()
+                .next()
+                .ok_or_else(|| "service missing in NotificationStatus".to_string())?,
+            status: intermediate_rep
+                .status
+                .into_iter()
+                .next()
+                .ok_or_else(|| "status missing in NotificationStatus".to_string())?,
+            attempts: intermediate_rep
+                .attempts
+                .into_iter()
+                .next()
+                .ok_or_else(|| "attempts missing in NotificationStatus".to_string())?,
+            created: intermediate_rep
+                .created
+                .into_iter()
+                .next()
+                .ok_or_else(|| "created missing in NotificationStatus".to_string())?,
+            updated: intermediate_rep
+                .updated
+                .into_iter()
+                .next()
+                .ok_or_else(|| "updated missing in NotificationStatus".to_string())?,
+            next_attempt: intermediate_rep.next_attempt.into_iter().next(),
+            last_error: intermediate_rep.last_error.into_iter().next(),
+            delivered_by: intermediate_rep.delivered_by.into_iter().next(),
+            receiver_reports: intermediate_rep.receiver_reports.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<NotificationStatus> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<NotificationStatus>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<NotificationStatus>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for NotificationStatus - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<NotificationStatus> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <NotificationStatus as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into NotificationStatus - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+/// Id of the notification queued for the default service, or the result for each target of the matching routes if it was routed
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+#[allow(non_camel_case_types, clippy::large_enum_variant)]
+pub enum NotificationsPost202Response {
+    NotificationId(Box<models::NotificationId>),
+    VecOfFanoutResult(Box<Vec<models::FanoutResult>>),
+}
+
+impl validator::Validate for NotificationsPost202Response {
+    fn validate(&self) -> std::result::Result<(), validator::ValidationErrors> {
+        match self {
+            Self::NotificationId(x) => x.validate(),
+            Self::VecOfFanoutResult(_) => std::result::Result::Ok(()),
+        }
+    }
+}
+
+impl From<models::NotificationId> for NotificationsPost202Response {
+    fn from(value: models::NotificationId) -> Self {
+        Self::NotificationId(Box::new(value))
+    }
+}
+
+impl From<Vec<models::FanoutResult>> for NotificationsPost202Response {
+    fn from(value: Vec<models::FanoutResult>) -> Self {
+        Self::VecOfFanoutResult(Box::new(value))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a NotificationsPost202Response value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for NotificationsPost202Response {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        serde_json::from_str(s)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct NotificationsPostRequest {
+    /// Short title of the notification
+    #[serde(rename = "title")]
+    pub title: String,
+
+    /// Optional content of the notification
+    #[serde(rename = "content")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<models::Severity>,
+
+    #[serde(rename = "tags")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<std::collections::HashMap<String, String>>,
+
+    /// Name of the app or instance which sends the notification
+    #[serde(rename = "source")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+
+    /// Time of the event the notification is about
+    #[serde(rename = "timestamp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Id of a template rendering the notification
+    #[serde(rename = "template")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+
+    /// Variables of the template
+    #[serde(rename = "variables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variables: Option<crate::types::Object>,
+
+    /// Html variant of the content, sent besides it by services supporting html
+    #[serde(rename = "html")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+
+    /// Whether the content is Markdown, which is rendered into the html if there is none
+    #[serde(rename = "markdown")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub markdown: Option<bool>,
+}
+
+impl NotificationsPostRequest {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(title: String) -> NotificationsPostRequest {
+        NotificationsPostRequest {
+            title,
+            content: None,
+            severity: None,
+            tags: None,
+            source: None,
+            timestamp: None,
+            template: None,
+            variables: None,
+            html: None,
+            markdown: None,
+        }
+    }
+}
+
+/// Converts the NotificationsPostRequest value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for NotificationsPostRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("title".to_string()),
+            Some(self.title.to_string()),
+            self.content
+                .as_ref()
+                .map(|content| ["content".to_string(), content.to_string()].join(",")),
+            // Skipping severity in query parameter serialization
+            // Skipping tags in query parameter serialization
+            self.source
+                .as_ref()
+                .map(|source| ["source".to_string(), source.to_string()].join(",")),
+            self.timestamp
+                .as_ref()
+                .map(|timestamp| ["timestamp".to_string(), timestamp.to_string()].join(",")),
+            self.template
+                .as_ref()
+                .map(|template| ["template".to_string(), template.to_string()].join(",")),
+            // Skipping variables in query parameter serialization
+            self.html
+                .as_ref()
+                .map(|html| ["html".to_string(), html.to_string()].join(",")),
+            self.markdown
+                .as_ref()
+                .map(|markdown| ["markdown".to_string(), markdown.to_string()].join(",")),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a NotificationsPostRequest value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for NotificationsPostRequest {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub title: Vec<String>,
+            pub content: Vec<String>,
+            pub severity: Vec<models::Severity>,
+            pub tags: Vec<std::collections::HashMap<String, String>>,
+            pub source: Vec<String>,
+            pub timestamp: Vec<chrono::DateTime<chrono::Utc>>,
+            pub template: Vec<String>,
+            pub variables: Vec<crate::types::Object>,
+            pub html: Vec<String>,
+            pub markdown: Vec<bool>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing NotificationsPostRequest".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "title" => intermediate_rep.title.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "content" => intermediate_rep.content.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "severity" => intermediate_rep.severity.push(<models::Severity as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "tags" => return std::result::Result::Err("Parsing a container in this style is not supported in NotificationsPostRequest".to_string()),
+                    #[allow(clippy::redundant_clone)]
+                    "source" => intermediate_rep.source.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "timestamp" => intermediate_rep.timestamp.push(<chrono::DateTime<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "template" => intermediate_rep.template.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "variables" => intermediate_rep.variables.push(<crate::types::Object as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "html" => intermediate_rep.html.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "markdown" => intermediate_rep.markdown.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing NotificationsPostRequest".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(NotificationsPostRequest {
+            title: intermediate_rep
+                .title
+                .into_iter()
+                .next()
+                .ok_or_else(|| "title missing in NotificationsPostRequest".to_string())?,
+            content: intermediate_rep.content.into_iter().next(),
+            severity: intermediate_rep.severity.into_iter().next(),
+            tags: intermediate_rep.tags.into_iter().next(),
+            source: intermediate_rep.source.into_iter().next(),
+            timestamp: intermediate_rep.timestamp.into_iter().next(),
+            template: intermediate_rep.template.into_iter().next(),
+            variables: intermediate_rep.variables.into_iter().next(),
+            html: intermediate_rep.html.into_iter().next(),
+            markdown: intermediate_rep.markdown.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<NotificationsPostRequest> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<NotificationsPostRequest>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<NotificationsPostRequest>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for NotificationsPostRequest - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<NotificationsPostRequest> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <NotificationsPostRequest as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into NotificationsPostRequest - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Reason {
+    #[serde(rename = "reason")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+
+    /// Receivers which the server rejected, e.g. the smtp server of a mail service
+    #[serde(rename = "rejected_receivers")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rejected_receivers: Option<Vec<models::RejectedReceiver>>,
+}
+
+impl Reason {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new() -> Reason {
+        Reason {
+            reason: None,
+            rejected_receivers: None,
+        }
+    }
+}
+
+/// Converts the Reason value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for Reason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            self.reason
+                .as_ref()
+                .map(|reason| ["reason".to_string(), reason.to_string()].join(",")),
+            // Skipping rejected_receivers in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a Reason value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for Reason {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub reason: Vec<String>,
+            pub rejected_receivers: Vec<Vec<models::RejectedReceiver>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing Reason".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "reason" => intermediate_rep.reason.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    "rejected_receivers" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in Reason"
+                                .to_string(),
+                        )
+                    }
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing Reason".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(Reason {
+            reason: intermediate_rep.reason.into_iter().next(),
+            rejected_receivers: intermediate_rep.rejected_receivers.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Reason> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<Reason>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<Reason>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for Reason - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Reason> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <Reason as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into Reason - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ReceiverHealth {
+    #[serde(rename = "address")]
+    pub address: String,
+
+    /// Failed deliveries since the last successful one
+    #[serde(rename = "failures")]
+    pub failures: i32,
+
+    /// Whether the failures reached the dead_after of the bounce mailbox
+    #[serde(rename = "dead")]
+    pub dead: bool,
+
+    #[serde(rename = "last_report")]
+    #[validate(nested)]
+    pub last_report: models::ReceiverReport,
+}
+
+impl ReceiverHealth {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        address: String,
+        failures: i32,
+        dead: bool,
+        last_report: models::ReceiverReport,
+    ) -> ReceiverHealth {
+        ReceiverHealth {
+            address,
+            failures,
+            dead,
+            last_report,
+        }
+    }
+}
+
+/// Converts the ReceiverHealth value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for ReceiverHealth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("address".to_string()),
+            Some(self.address.to_string()),
+            Some("failures".to_string()),
+            Some(self.failures.to_string()),
+            Some("dead".to_string()),
+            Some(self.dead.to_string()),
+            // Skipping last_report in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ReceiverHealth value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ReceiverHealth {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub address: Vec<String>,
+            pub failures: Vec<i32>,
+            pub dead: Vec<bool>,
+            pub last_report: Vec<models::ReceiverReport>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ReceiverHealth".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "address" => intermediate_rep.address.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "failures" => intermediate_rep.failures.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "dead" => intermediate_rep.dead.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "last_report" => intermediate_rep.last_report.push(
+                        <models::ReceiverReport as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ReceiverHealth".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ReceiverHealth {
+            address: intermediate_rep
+                .address
+                .into_iter()
+                .next()
+                .ok_or_else(|| "address missing in ReceiverHealth".to_string())?,
+            failures: intermediate_rep
+                .failures
+                .into_iter()
+                .next()
+                .ok_or_else(|| "failures missing in ReceiverHealth".to_string())?,
+            dead: intermediate_rep
+                .dead
+                .into_iter()
+                .next()
+                .ok_or_else(|| "dead missing in ReceiverHealth".to_string())?,
+            last_report: intermediate_rep
+                .last_report
+                .into_iter()
+                .next()
+                .ok_or_else(|| "last_report missing in ReceiverHealth".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ReceiverHealth> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<ReceiverHealth>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ReceiverHealth>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for ReceiverHealth - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ReceiverHealth> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ReceiverHealth as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into ReceiverHealth - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+/// Delivery status notification of the mail server of a receiver
+pub struct ReceiverReport {
+    #[serde(rename = "address")]
+    pub address: String,
+
+    #[serde(rename = "state")]
+    pub state: models::ReceiverState,
+
+    /// Enhanced status code, e.g. 5.1.1
+    #[serde(rename = "status")]
+    pub status: String,
+
+    /// Diagnostic of the reporting mail server
+    #[serde(rename = "diagnostic")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diagnostic: Option<String>,
+
+    /// Time the report was read from the bounce mailbox
+    #[serde(rename = "time")]
+    pub time: chrono::DateTime<chrono::Utc>,
+}
+
+impl ReceiverReport {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(
+        address: String,
+        state: models::ReceiverState,
+        status: String,
+        time: chrono::DateTime<chrono::Utc>,
+    ) -> ReceiverReport {
+        ReceiverReport {
+            address,
+            state,
+            status,
+            diagnostic: None,
+            time,
+        }
+    }
+}
+
+/// Converts the ReceiverReport value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for ReceiverReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("address".to_string()),
+            Some(self.address.to_string()),
+            // Skipping state in query parameter serialization
+            Some("status".to_string()),
+            Some(self.status.to_string()),
+            self.diagnostic
+                .as_ref()
+                .map(|diagnostic| ["diagnostic".to_string(), diagnostic.to_string()].join(",")),
+            Some("time".to_string()),
+            Some(self.time.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ReceiverReport value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ReceiverReport {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub address: Vec<String>,
+            pub state: Vec<models::ReceiverState>,
+            pub status: Vec<String>,
+            pub diagnostic: Vec<String>,
+            pub time: Vec<chrono::DateTime<chrono::Utc>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ReceiverReport".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "address" => intermediate_rep.address.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "state" => intermediate_rep.state.push(
+                        <models::ReceiverState as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "status" => intermediate_rep.status.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "diagnostic" => intermediate_rep.diagnostic.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "time" => intermediate_rep.time.push(
+                        <chrono::DateTime<chrono::Utc> as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ReceiverReport".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ReceiverReport {
+            address: intermediate_rep
+                .address
+                .into_iter()
+                .next()
+                .ok_or_else(|| "address missing in ReceiverReport".to_string())?,
+            state: intermediate_rep
+                .state
+                .into_iter()
+                .next()
+                .ok_or_else(|| "state missing in ReceiverReport".to_string())?,
+            status: intermediate_rep
+                .status
+                .into_iter()
+                .next()
+                .ok_or_else(|| "status missing in ReceiverReport".to_string())?,
+            diagnostic: intermediate_rep.diagnostic.into_iter().next(),
+            time: intermediate_rep
+                .time
+                .into_iter()
+                .next()
+                .ok_or_else(|| "time missing in ReceiverReport".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ReceiverReport> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<ReceiverReport>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<ReceiverReport>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for ReceiverReport - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ReceiverReport> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <ReceiverReport as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into ReceiverReport - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize, Hash,
+)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum ReceiverState {
+    #[serde(rename = "delivered")]
+    Delivered,
+    #[serde(rename = "delayed")]
+    Delayed,
+    #[serde(rename = "failed")]
+    Failed,
+}
+
+impl std::fmt::Display for ReceiverState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            ReceiverState::Delivered => write!(f, "delivered"),
+            ReceiverState::Delayed => write!(f, "delayed"),
+            ReceiverState::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+impl std::str::FromStr for ReceiverState {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "delivered" => std::result::Result::Ok(ReceiverState::Delivered),
+            "delayed" => std::result::Result::Ok(ReceiverState::Delayed),
+            "failed" => std::result::Result::Ok(ReceiverState::Failed),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct RejectedReceiver {
+    #[serde(rename = "address")]
+    pub address: String,
+
+    #[serde(rename = "code")]
+    pub code: i32,
+
+    #[serde(rename = "transient")]
+    pub transient: bool,
+
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl RejectedReceiver {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(address: String, code: i32, transient: bool) -> RejectedReceiver {
+        RejectedReceiver {
+            address,
+            code,
+            transient,
+            message: None,
+        }
+    }
+}
+
+/// Converts the RejectedReceiver value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for RejectedReceiver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("address".to_string()),
+            Some(self.address.to_string()),
+            Some("code".to_string()),
+            Some(self.code.to_string()),
+            Some("transient".to_string()),
+            Some(self.transient.to_string()),
+            self.message
+                .as_ref()
+                .map(|message| ["message".to_string(), message.to_string()].join(",")),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a RejectedReceiver value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for RejectedReceiver {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub address: Vec<String>,
+            pub code: Vec<i32>,
+            pub transient: Vec<bool>,
+            pub message: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing RejectedReceiver".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "address" => intermediate_rep.address.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "code" => intermediate_rep.code.push(
+                        <i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "transient" => intermediate_rep.transient.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "message" => intermediate_rep.message.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing RejectedReceiver".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(RejectedReceiver {
+            address: intermediate_rep
+                .address
+                .into_iter()
+                .next()
+                .ok_or_else(|| "address missing in RejectedReceiver".to_string())?,
+            code: intermediate_rep
+                .code
+                .into_iter()
+                .next()
+                .ok_or_else(|| "code missing in RejectedReceiver".to_string())?,
+            transient: intermediate_rep
+                .transient
+                .into_iter()
+                .next()
+                .ok_or_else(|| "transient missing in RejectedReceiver".to_string())?,
+            message: intermediate_rep.message.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<RejectedReceiver> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<RejectedReceiver>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<RejectedReceiver>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for RejectedReceiver - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<RejectedReceiver> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <RejectedReceiver as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into RejectedReceiver - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct RenderedTemplate {
+    #[serde(rename = "title")]
+    pub title: String,
+
+    #[serde(rename = "content")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+
+    /// Html variant of the content, sent besides it by services supporting html
+    #[serde(rename = "html")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+}
+
+impl RenderedTemplate {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(title: String) -> RenderedTemplate {
+        RenderedTemplate {
+            title,
+            content: None,
+            html: None,
+        }
+    }
+}
+
+/// Converts the RenderedTemplate value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for RenderedTemplate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("title".to_string()),
+            Some(self.title.to_string()),
+            self.content
+                .as_ref()
+                .map(|content| ["content".to_string(), content.to_string()].join(",")),
+            self.html
+                .as_ref()
+                .map(|html| ["html".to_string(), html.to_string()].join(",")),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a RenderedTemplate value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for RenderedTemplate {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub title: Vec<String>,
+            pub content: Vec<String>,
+            pub html: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing RenderedTemplate".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "title" => intermediate_rep.title.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "content" => intermediate_rep.content.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "html" => intermediate_rep.html.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing RenderedTemplate".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(RenderedTemplate {
+            title: intermediate_rep
+                .title
+                .into_iter()
+                .next()
+                .ok_or_else(|| "title missing in RenderedTemplate".to_string())?,
+            content: intermediate_rep.content.into_iter().next(),
+            html: intermediate_rep.html.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<RenderedTemplate> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<RenderedTemplate>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<RenderedTemplate>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for RenderedTemplate - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<RenderedTemplate> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <RenderedTemplate as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into RenderedTemplate - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct RouteDryRun {
+    /// Ids of the matching routes
+    #[serde(rename = "routes")]
+    pub routes: Vec<String>,
+
+    /// Targets the notification would be sent to, the default service if no route matches
+    #[serde(rename = "targets")]
+    #[validate(nested)]
+    pub targets: Vec<models::RouteTarget>,
+}
+
+impl RouteDryRun {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(routes: Vec<String>, targets: Vec<models::RouteTarget>) -> RouteDryRun {
+        RouteDryRun { routes, targets }
+    }
+}
+
+/// Converts the RouteDryRun value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for RouteDryRun {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("routes".to_string()),
+            Some(
+                self.routes
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ),
+            // Skipping targets in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a RouteDryRun value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for RouteDryRun {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub routes: Vec<Vec<String>>,
+            pub targets: Vec<Vec<models::RouteTarget>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing RouteDryRun".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    "routes" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in RouteDryRun"
+                                .to_string(),
+                        )
+                    }
+                    "targets" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in RouteDryRun"
+                                .to_string(),
+                        )
+                    }
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing RouteDryRun".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(RouteDryRun {
+            routes: intermediate_rep
+                .routes
+                .into_iter()
+                .next()
+                .ok_or_else(|| "routes missing in RouteDryRun".to_string())?,
+            targets: intermediate_rep
+                .targets
+                .into_iter()
+                .next()
+                .ok_or_else(|| "targets missing in RouteDryRun".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<RouteDryRun> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<RouteDryRun>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<RouteDryRun>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for RouteDryRun - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<RouteDryRun> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <RouteDryRun as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into RouteDryRun - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct RouteSample {
+    #[serde(rename = "title")]
+    pub title: String,
+
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<models::Severity>,
+
+    #[serde(rename = "tags")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<std::collections::HashMap<String, String>>,
+
+    /// Name of the app or instance which sends the notification
+    #[serde(rename = "source")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+
+    /// Time at which the notification is sent, the current time if omitted
+    #[serde(rename = "time")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl RouteSample {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(title: String) -> RouteSample {
+        RouteSample {
+            title,
+            severity: None,
+            tags: None,
+            source: None,
+            time: None,
+        }
+    }
+}
+
+/// Converts the RouteSample value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for RouteSample {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("title".to_string()),
+            Some(self.title.to_string()),
+            // Skipping severity in query parameter serialization
+            // Skipping tags in query parameter serialization
+            self.source
+                .as_ref()
+                .map(|source| ["source".to_string(), source.to_string()].join(",")),
+            self.time
+                .as_ref()
+                .map(|time| ["time".to_string(), time.to_string()].join(",")),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a RouteSample value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for RouteSample {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub title: Vec<String>,
+            pub severity: Vec<models::Severity>,
+            pub tags: Vec<std::collections::HashMap<String, String>>,
+            pub source: Vec<String>,
+            pub time: Vec<chrono::DateTime<chrono::Utc>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing RouteSample".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "title" => intermediate_rep.title.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "severity" => intermediate_rep.severity.push(
+                        <models::Severity as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    "tags" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in RouteSample"
+                                .to_string(),
+                        )
+                    }
+                    #[allow(clippy::redundant_clone)]
+                    "source" => intermediate_rep.source.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "time" => intermediate_rep.time.push(
+                        <chrono::DateTime<chrono::Utc> as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing RouteSample".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(RouteSample {
+            title: intermediate_rep
+                .title
+                .into_iter()
+                .next()
+                .ok_or_else(|| "title missing in RouteSample".to_string())?,
+            severity: intermediate_rep.severity.into_iter().next(),
+            tags: intermediate_rep.tags.into_iter().next(),
+            source: intermediate_rep.source.into_iter().next(),
+            time: intermediate_rep.time.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<RouteSample> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<RouteSample>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<RouteSample>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for RouteSample - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<RouteSample> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <RouteSample as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into RouteSample - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct RouteTarget {
+    /// Id of the notification service or failover chain
+    #[serde(rename = "service")]
+    pub service: String,
+
+    #[serde(rename = "options")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<crate::types::Object>,
+}
+
+impl RouteTarget {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(service: String) -> RouteTarget {
+        RouteTarget {
+            service,
+            options: None,
+        }
+    }
+}
+
+/// Converts the RouteTarget value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for RouteTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("service".to_string()),
+            Some(self.service.to_string()),
+            // Skipping options in query parameter serialization
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a RouteTarget value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for RouteTarget {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub service: Vec<String>,
+            pub options: Vec<crate::types::Object>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing RouteTarget".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "service" => intermediate_rep.service.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "options" => intermediate_rep.options.push(
+                        <crate::types::Object as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing RouteTarget".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(RouteTarget {
+            service: intermediate_rep
+                .service
+                .into_iter()
+                .next()
+                .ok_or_else(|| "service missing in RouteTarget".to_string())?,
+            options: intermediate_rep.options.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<RouteTarget> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<RouteTarget>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<RouteTarget>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for RouteTarget - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<RouteTarget> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <RouteTarget as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into RouteTarget - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ServicesIdGet200Response {
+    #[serde(rename = "type")]
+    pub r#type: String,
+
+    #[serde(rename = "id")]
+    pub id: String,
+}
+
+impl ServicesIdGet200Response {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(r#type: String, id: String) -> ServicesIdGet200Response {
+        ServicesIdGet200Response { r#type, id }
+    }
+}
+
+/// Converts the ServicesIdGet200Response value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for ServicesIdGet200Response {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            Some("type".to_string()),
+            Some(self.r#type.to_string()),
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ServicesIdGet200Response value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ServicesIdGet200Response {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub r#type: Vec<String>,
+            pub id: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing ServicesIdGet200Response".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "type" => intermediate_rep.r#type.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing ServicesIdGet200Response".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ServicesIdGet200Response {
+            r#type: intermediate_rep
+                .r#type
                 .into_iter()
                 .next()
                 .ok_or_else(|| "type missing in ServicesIdGet200Response".to_string())?,
@@ -943,3 +3850,318 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ServicesIdPu
         }
     }
 }
+
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize, Hash,
+)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum Severity {
+    #[serde(rename = "critical")]
+    Critical,
+    #[serde(rename = "error")]
+    Error,
+    #[serde(rename = "warning")]
+    Warning,
+    #[serde(rename = "info")]
+    Info,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Severity::Critical => write!(f, "critical"),
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Info => write!(f, "info"),
+        }
+    }
+}
+
+impl std::str::FromStr for Severity {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "critical" => std::result::Result::Ok(Severity::Critical),
+            "error" => std::result::Result::Ok(Severity::Error),
+            "warning" => std::result::Result::Ok(Severity::Warning),
+            "info" => std::result::Result::Ok(Severity::Info),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct TemplateSample {
+    /// Type of the service the notification is rendered for, e.g. `smtp`
+    #[serde(rename = "service_type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_type: Option<String>,
+
+    #[serde(rename = "title")]
+    pub title: String,
+
+    #[serde(rename = "content")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+
+    #[serde(rename = "severity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<models::Severity>,
+
+    #[serde(rename = "tags")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<std::collections::HashMap<String, String>>,
+
+    /// Name of the app or instance which sends the notification
+    #[serde(rename = "source")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+
+    /// Time of the event the notification is about
+    #[serde(rename = "timestamp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<chrono::DateTime<chrono::Utc>>,
+
+    /// Variables of the template
+    #[serde(rename = "variables")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variables: Option<crate::types::Object>,
+
+    /// Html variant of the content, sent besides it by services supporting html
+    #[serde(rename = "html")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+
+    /// Whether the content is Markdown, which is rendered into the html if there is none
+    #[serde(rename = "markdown")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub markdown: Option<bool>,
+}
+
+impl TemplateSample {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(title: String) -> TemplateSample {
+        TemplateSample {
+            service_type: None,
+            title,
+            content: None,
+            severity: None,
+            tags: None,
+            source: None,
+            timestamp: None,
+            variables: None,
+            html: None,
+            markdown: None,
+        }
+    }
+}
+
+/// Converts the TemplateSample value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for TemplateSample {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            self.service_type.as_ref().map(|service_type| {
+                ["service_type".to_string(), service_type.to_string()].join(",")
+            }),
+            Some("title".to_string()),
+            Some(self.title.to_string()),
+            self.content
+                .as_ref()
+                .map(|content| ["content".to_string(), content.to_string()].join(",")),
+            // Skipping severity in query parameter serialization
+            // Skipping tags in query parameter serialization
+            self.source
+                .as_ref()
+                .map(|source| ["source".to_string(), source.to_string()].join(",")),
+            self.timestamp
+                .as_ref()
+                .map(|timestamp| ["timestamp".to_string(), timestamp.to_string()].join(",")),
+            // Skipping variables in query parameter serialization
+            self.html
+                .as_ref()
+                .map(|html| ["html".to_string(), html.to_string()].join(",")),
+            self.markdown
+                .as_ref()
+                .map(|markdown| ["markdown".to_string(), markdown.to_string()].join(",")),
+        ];
+
+        write!(
+            f,
+            "{}",
+            params.into_iter().flatten().collect::<Vec<_>>().join(",")
+        )
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a TemplateSample value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for TemplateSample {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub service_type: Vec<String>,
+            pub title: Vec<String>,
+            pub content: Vec<String>,
+            pub severity: Vec<models::Severity>,
+            pub tags: Vec<std::collections::HashMap<String, String>>,
+            pub source: Vec<String>,
+            pub timestamp: Vec<chrono::DateTime<chrono::Utc>>,
+            pub variables: Vec<crate::types::Object>,
+            pub html: Vec<String>,
+            pub markdown: Vec<bool>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => {
+                    return std::result::Result::Err(
+                        "Missing value while parsing TemplateSample".to_string(),
+                    )
+                }
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "service_type" => intermediate_rep.service_type.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "title" => intermediate_rep.title.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "content" => intermediate_rep.content.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "severity" => intermediate_rep.severity.push(
+                        <models::Severity as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    "tags" => {
+                        return std::result::Result::Err(
+                            "Parsing a container in this style is not supported in TemplateSample"
+                                .to_string(),
+                        )
+                    }
+                    #[allow(clippy::redundant_clone)]
+                    "source" => intermediate_rep.source.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "timestamp" => intermediate_rep.timestamp.push(
+                        <chrono::DateTime<chrono::Utc> as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "variables" => intermediate_rep.variables.push(
+                        <crate::types::Object as std::str::FromStr>::from_str(val)
+                            .map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "html" => intermediate_rep.html.push(
+                        <String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    #[allow(clippy::redundant_clone)]
+                    "markdown" => intermediate_rep.markdown.push(
+                        <bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?,
+                    ),
+                    _ => {
+                        return std::result::Result::Err(
+                            "Unexpected key while parsing TemplateSample".to_string(),
+                        )
+                    }
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(TemplateSample {
+            service_type: intermediate_rep.service_type.into_iter().next(),
+            title: intermediate_rep
+                .title
+                .into_iter()
+                .next()
+                .ok_or_else(|| "title missing in TemplateSample".to_string())?,
+            content: intermediate_rep.content.into_iter().next(),
+            severity: intermediate_rep.severity.into_iter().next(),
+            tags: intermediate_rep.tags.into_iter().next(),
+            source: intermediate_rep.source.into_iter().next(),
+            timestamp: intermediate_rep.timestamp.into_iter().next(),
+            variables: intermediate_rep.variables.into_iter().next(),
+            html: intermediate_rep.html.into_iter().next(),
+            markdown: intermediate_rep.markdown.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<TemplateSample> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<TemplateSample>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(
+        hdr_value: header::IntoHeaderValue<TemplateSample>,
+    ) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+            std::result::Result::Ok(value) => std::result::Result::Ok(value),
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Invalid header value for TemplateSample - value: {} is invalid {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<TemplateSample> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+            std::result::Result::Ok(value) => {
+                match <TemplateSample as std::str::FromStr>::from_str(value) {
+                    std::result::Result::Ok(value) => {
+                        std::result::Result::Ok(header::IntoHeaderValue(value))
+                    }
+                    std::result::Result::Err(err) => std::result::Result::Err(format!(
+                        "Unable to convert header value '{}' into TemplateSample - {}",
+                        value, err
+                    )),
+                }
+            }
+            std::result::Result::Err(e) => std::result::Result::Err(format!(
+                "Unable to convert header: {:?} to string: {}",
+                hdr_value, e
+            )),
+        }
+    }
+}
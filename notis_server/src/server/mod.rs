@@ -16,7 +16,12 @@ use crate::{apis, models};
 pub fn new<I, A>(api_impl: I) -> Router
 where
     I: AsRef<A> + Clone + Send + Sync + 'static,
-    A: apis::notifications::Notifications + apis::services::Services + 'static,
+    A: apis::history::History
+        + apis::notifications::Notifications
+        + apis::routes::Routes
+        + apis::services::Services
+        + apis::templates::Templates
+        + 'static,
 {
     // build our application with a route
     Router::new()
@@ -26,7 +31,30 @@ where
                 .get(default_service_get::<I, A>)
                 .post(default_service_post::<I, A>),
         )
-        .route("/notifications", post(notifications_post::<I, A>))
+        .route("/history", get(history_get::<I, A>))
+        .route(
+            "/history/:id/attachments/:index",
+            get(history_id_attachments_index_get::<I, A>),
+        )
+        .route(
+            "/notifications",
+            get(notifications_get::<I, A>).post(notifications_post::<I, A>),
+        )
+        .route(
+            "/notifications/fanout",
+            post(notifications_fanout_post::<I, A>).layer(DefaultBodyLimit::disable()),
+        )
+        .route("/notifications/:id", get(notifications_id_get::<I, A>))
+        .route("/routes", get(routes_get::<I, A>))
+        .route("/routes/dry_run", post(routes_dry_run_post::<I, A>))
+        .route(
+            "/routes/:id",
+            delete(routes_id_delete::<I, A>)
+                .get(routes_id_get::<I, A>)
+                .put(routes_id_put::<I, A>),
+        )
+        .route("/schema/route", get(schema_route_get::<I, A>))
+        .route("/schema/template", get(schema_template_get::<I, A>))
         .route(
             "/schema/service_types/:service_type/config",
             get(schema_service_types_service_type_config_get::<I, A>),
@@ -54,44 +82,51 @@ where
             "/services/:id/notifications/schema",
             get(services_id_notifications_schema_get::<I, A>),
         )
+        .route(
+            "/services/:id/receivers",
+            get(services_id_receivers_get::<I, A>),
+        )
+        .route("/templates", get(templates_get::<I, A>))
+        .route(
+            "/templates/:id",
+            delete(templates_id_delete::<I, A>)
+                .get(templates_id_get::<I, A>)
+                .put(templates_id_put::<I, A>),
+        )
+        .route(
+            "/templates/:id/preview",
+            post(templates_id_preview_post::<I, A>),
+        )
         .with_state(api_impl)
 }
 
-#[derive(validator::Validate)]
-#[allow(dead_code)]
-struct NotificationsPostBodyValidator<'a> {
-    #[validate(nested)]
-    body: &'a models::NotificationsPostRequest,
-}
-
 #[tracing::instrument(skip_all)]
-fn notifications_post_validation(
-    body: models::NotificationsPostRequest,
-) -> std::result::Result<(models::NotificationsPostRequest,), ValidationErrors> {
-    let b = NotificationsPostBodyValidator { body: &body };
-    b.validate()?;
+fn history_get_validation(
+    query_params: models::HistoryGetQueryParams,
+) -> std::result::Result<(models::HistoryGetQueryParams,), ValidationErrors> {
+    query_params.validate()?;
 
-    Ok((body,))
+    Ok((query_params,))
 }
-/// NotificationsPost - POST /notifications
+/// HistoryGet - GET /history
 #[tracing::instrument(skip_all)]
-async fn notifications_post<I, A>(
+async fn history_get<I, A>(
     method: Method,
     host: Host,
     cookies: CookieJar,
+    Query(query_params): Query<models::HistoryGetQueryParams>,
     State(api_impl): State<I>,
-    Json(body): Json<models::NotificationsPostRequest>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::notifications::Notifications,
+    A: apis::history::History,
 {
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || notifications_post_validation(body))
+    let validation = tokio::task::spawn_blocking(move || history_get_validation(query_params))
         .await
         .unwrap();
 
-    let Ok((body,)) = validation else {
+    let Ok((query_params,)) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -100,22 +135,64 @@ where
 
     let result = api_impl
         .as_ref()
-        .notifications_post(method, host, cookies, body)
+        .history_get(method, host, cookies, query_params)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::notifications::NotificationsPostResponse::Status200_Success => {
+            apis::history::HistoryGetResponse::Status200_Success(body) => {
                 let mut response = response.status(200);
-                response.body(Body::empty())
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
             }
-            apis::notifications::NotificationsPostResponse::Status404_NoDefaultServiceFound => {
+            apis::history::HistoryGetResponse::Status400_BadRequest(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::history::HistoryGetResponse::Status404_HistoryIsNotEnabled => {
                 let mut response = response.status(404);
                 response.body(Body::empty())
             }
-            apis::notifications::NotificationsPostResponse::Status500_InternalServerError(body) => {
+            apis::history::HistoryGetResponse::Status500_InternalServerError(body) => {
                 let mut response = response.status(500);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -153,20 +230,118 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn services_id_notifications_post_validation(
-    path_params: models::ServicesIdNotificationsPostPathParams,
-) -> std::result::Result<(models::ServicesIdNotificationsPostPathParams,), ValidationErrors> {
+fn history_id_attachments_index_get_validation(
+    path_params: models::HistoryIdAttachmentsIndexGetPathParams,
+) -> std::result::Result<(models::HistoryIdAttachmentsIndexGetPathParams,), ValidationErrors> {
     path_params.validate()?;
 
     Ok((path_params,))
 }
-/// ServicesIdNotificationsPost - POST /services/{id}/notifications
+/// HistoryIdAttachmentsIndexGet - GET /history/{id}/attachments/{index}
 #[tracing::instrument(skip_all)]
-async fn services_id_notifications_post<I, A>(
+async fn history_id_attachments_index_get<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    Path(path_params): Path<models::HistoryIdAttachmentsIndexGetPathParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::history::History,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || {
+        history_id_attachments_index_get_validation(path_params)
+    })
+    .await
+    .unwrap();
+
+    let Ok((path_params,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .history_id_attachments_index_get(method, host, cookies, path_params)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::history::HistoryIdAttachmentsIndexGetResponse::Status200_Success(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/octet-stream").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = body.0;
+                response.body(Body::from(body_content))
+            }
+            apis::history::HistoryIdAttachmentsIndexGetResponse::Status404_AttachmentNotFound => {
+                let mut response = response.status(404);
+                response.body(Body::empty())
+            }
+            apis::history::HistoryIdAttachmentsIndexGetResponse::Status500_InternalServerError(
+                body,
+            ) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn notifications_fanout_post_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// NotificationsFanoutPost - POST /notifications/fanout
+#[tracing::instrument(skip_all)]
+async fn notifications_fanout_post<I, A>(
     method: Method,
     host: Host,
     cookies: CookieJar,
-    Path(path_params): Path<models::ServicesIdNotificationsPostPathParams>,
     State(api_impl): State<I>,
     body: Multipart,
 ) -> Result<Response, StatusCode>
@@ -175,12 +350,11 @@ where
     A: apis::notifications::Notifications,
 {
     #[allow(clippy::redundant_closure)]
-    let validation =
-        tokio::task::spawn_blocking(move || services_id_notifications_post_validation(path_params))
-            .await
-            .unwrap();
+    let validation = tokio::task::spawn_blocking(move || notifications_fanout_post_validation())
+        .await
+        .unwrap();
 
-    let Ok((path_params,)) = validation else {
+    let Ok(()) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -189,59 +363,1373 @@ where
 
     let result = api_impl
         .as_ref()
-        .services_id_notifications_post(method, host, cookies, path_params, body)
+        .notifications_fanout_post(method, host, cookies, body)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
-                                            Ok(rsp) => match rsp {
-                                                apis::notifications::ServicesIdNotificationsPostResponse::Status200_Success
-                                                => {
-                                                  let mut response = response.status(200);
-                                                  response.body(Body::empty())
-                                                },
-                                                apis::notifications::ServicesIdNotificationsPostResponse::Status400_BadRequest
-                                                    (body)
-                                                => {
-                                                  let mut response = response.status(400);
-                                                  {
-                                                    let mut response_headers = response.headers_mut().unwrap();
-                                                    response_headers.insert(
-                                                        CONTENT_TYPE,
-                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
-                                                  }
+        Ok(rsp) => match rsp {
+            apis::notifications::NotificationsFanoutPostResponse::Status202_ResultForEachService(body) => {
+                let mut response = response.status(202);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
 
-                                                  let body_content =  tokio::task::spawn_blocking(move ||
-                                                      serde_json::to_vec(&body).map_err(|e| {
-                                                        error!(error = ?e);
-                                                        StatusCode::INTERNAL_SERVER_ERROR
-                                                      })).await.unwrap()?;
-                                                  response.body(Body::from(body_content))
-                                                },
-                                                apis::notifications::ServicesIdNotificationsPostResponse::Status404_ServiceNotFound
-                                                => {
-                                                  let mut response = response.status(404);
-                                                  response.body(Body::empty())
-                                                },
-                                                apis::notifications::ServicesIdNotificationsPostResponse::Status500_InternalServerError
-                                                    (body)
-                                                => {
-                                                  let mut response = response.status(500);
-                                                  {
-                                                    let mut response_headers = response.headers_mut().unwrap();
-                                                    response_headers.insert(
-                                                        CONTENT_TYPE,
-                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
-                                                  }
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::notifications::NotificationsFanoutPostResponse::Status400_BadRequest(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
 
-                                                  let body_content =  tokio::task::spawn_blocking(move ||
-                                                      serde_json::to_vec(&body).map_err(|e| {
-                                                        error!(error = ?e);
-                                                        StatusCode::INTERNAL_SERVER_ERROR
-                                                      })).await.unwrap()?;
-                                                  response.body(Body::from(body_content))
-                                                },
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::notifications::NotificationsFanoutPostResponse::Status500_InternalServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn notifications_get_validation(
+    query_params: models::NotificationsGetQueryParams,
+) -> std::result::Result<(models::NotificationsGetQueryParams,), ValidationErrors> {
+    query_params.validate()?;
+
+    Ok((query_params,))
+}
+/// NotificationsGet - GET /notifications
+#[tracing::instrument(skip_all)]
+async fn notifications_get<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    Query(query_params): Query<models::NotificationsGetQueryParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::notifications::Notifications,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation =
+        tokio::task::spawn_blocking(move || notifications_get_validation(query_params))
+            .await
+            .unwrap();
+
+    let Ok((query_params,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .notifications_get(method, host, cookies, query_params)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::notifications::NotificationsGetResponse::Status200_Success(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn notifications_id_get_validation(
+    path_params: models::NotificationsIdGetPathParams,
+) -> std::result::Result<(models::NotificationsIdGetPathParams,), ValidationErrors> {
+    path_params.validate()?;
+
+    Ok((path_params,))
+}
+/// NotificationsIdGet - GET /notifications/{id}
+#[tracing::instrument(skip_all)]
+async fn notifications_id_get<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    Path(path_params): Path<models::NotificationsIdGetPathParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::notifications::Notifications,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation =
+        tokio::task::spawn_blocking(move || notifications_id_get_validation(path_params))
+            .await
+            .unwrap();
+
+    let Ok((path_params,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .notifications_id_get(method, host, cookies, path_params)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::notifications::NotificationsIdGetResponse::Status200_Success(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::notifications::NotificationsIdGetResponse::Status404_NotificationNotFound => {
+                let mut response = response.status(404);
+                response.body(Body::empty())
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct NotificationsPostBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::NotificationsPostRequest,
+}
+
+#[tracing::instrument(skip_all)]
+fn notifications_post_validation(
+    body: models::NotificationsPostRequest,
+) -> std::result::Result<(models::NotificationsPostRequest,), ValidationErrors> {
+    let b = NotificationsPostBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((body,))
+}
+/// NotificationsPost - POST /notifications
+#[tracing::instrument(skip_all)]
+async fn notifications_post<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    State(api_impl): State<I>,
+    Json(body): Json<models::NotificationsPostRequest>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::notifications::Notifications,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || notifications_post_validation(body))
+        .await
+        .unwrap();
+
+    let Ok((body,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .notifications_post(method, host, cookies, body)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::notifications::NotificationsPostResponse::Status202_NotificationWasQueuedForDeliveryOrRouted(body) => {
+                let mut response = response.status(202);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::notifications::NotificationsPostResponse::Status400_BadRequest(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::notifications::NotificationsPostResponse::Status404_NoDefaultServiceFound => {
+                let mut response = response.status(404);
+                response.body(Body::empty())
+            }
+            apis::notifications::NotificationsPostResponse::Status500_InternalServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn services_id_notifications_post_validation(
+    path_params: models::ServicesIdNotificationsPostPathParams,
+) -> std::result::Result<(models::ServicesIdNotificationsPostPathParams,), ValidationErrors> {
+    path_params.validate()?;
+
+    Ok((path_params,))
+}
+/// ServicesIdNotificationsPost - POST /services/{id}/notifications
+#[tracing::instrument(skip_all)]
+async fn services_id_notifications_post<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    Path(path_params): Path<models::ServicesIdNotificationsPostPathParams>,
+    State(api_impl): State<I>,
+    body: Multipart,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::notifications::Notifications,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation =
+        tokio::task::spawn_blocking(move || services_id_notifications_post_validation(path_params))
+            .await
+            .unwrap();
+
+    let Ok((path_params,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .services_id_notifications_post(method, host, cookies, path_params, body)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::notifications::ServicesIdNotificationsPostResponse::Status202_NotificationWasQueuedForDelivery
+                                                    (body)
+                                                => {
+                let mut response = response.status(202);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+                                                },
+                                                apis::notifications::ServicesIdNotificationsPostResponse::Status400_BadRequest
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::notifications::ServicesIdNotificationsPostResponse::Status404_ServiceNotFound
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::notifications::ServicesIdNotificationsPostResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(_) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                response.status(500).body(Body::empty())
+                                            },
+                                        };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct RoutesDryRunPostBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::RouteSample,
+}
+
+#[tracing::instrument(skip_all)]
+fn routes_dry_run_post_validation(
+    body: models::RouteSample,
+) -> std::result::Result<(models::RouteSample,), ValidationErrors> {
+    let b = RoutesDryRunPostBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((body,))
+}
+/// RoutesDryRunPost - POST /routes/dry_run
+#[tracing::instrument(skip_all)]
+async fn routes_dry_run_post<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    State(api_impl): State<I>,
+    Json(body): Json<models::RouteSample>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::routes::Routes,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || routes_dry_run_post_validation(body))
+        .await
+        .unwrap();
+
+    let Ok((body,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .routes_dry_run_post(method, host, cookies, body)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::routes::RoutesDryRunPostResponse::Status200_Success(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn routes_get_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// RoutesGet - GET /routes
+#[tracing::instrument(skip_all)]
+async fn routes_get<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::routes::Routes,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || routes_get_validation())
+        .await
+        .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl.as_ref().routes_get(method, host, cookies).await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::routes::RoutesGetResponse::Status200_Success(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn routes_id_delete_validation(
+    path_params: models::RoutesIdDeletePathParams,
+) -> std::result::Result<(models::RoutesIdDeletePathParams,), ValidationErrors> {
+    path_params.validate()?;
+
+    Ok((path_params,))
+}
+/// RoutesIdDelete - DELETE /routes/{id}
+#[tracing::instrument(skip_all)]
+async fn routes_id_delete<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    Path(path_params): Path<models::RoutesIdDeletePathParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::routes::Routes,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || routes_id_delete_validation(path_params))
+        .await
+        .unwrap();
+
+    let Ok((path_params,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .routes_id_delete(method, host, cookies, path_params)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::routes::RoutesIdDeleteResponse::Status200_Success => {
+                let mut response = response.status(200);
+                response.body(Body::empty())
+            }
+            apis::routes::RoutesIdDeleteResponse::Status404_RouteNotFound => {
+                let mut response = response.status(404);
+                response.body(Body::empty())
+            }
+            apis::routes::RoutesIdDeleteResponse::Status500_InternalServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn routes_id_get_validation(
+    path_params: models::RoutesIdGetPathParams,
+) -> std::result::Result<(models::RoutesIdGetPathParams,), ValidationErrors> {
+    path_params.validate()?;
+
+    Ok((path_params,))
+}
+/// RoutesIdGet - GET /routes/{id}
+#[tracing::instrument(skip_all)]
+async fn routes_id_get<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    Path(path_params): Path<models::RoutesIdGetPathParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::routes::Routes,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || routes_id_get_validation(path_params))
+        .await
+        .unwrap();
+
+    let Ok((path_params,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .routes_id_get(method, host, cookies, path_params)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::routes::RoutesIdGetResponse::Status200_Success(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::routes::RoutesIdGetResponse::Status404_RouteNotFound => {
+                let mut response = response.status(404);
+                response.body(Body::empty())
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct RoutesIdPutBodyValidator<'a> {
+    body: &'a crate::types::Object,
+}
+
+#[tracing::instrument(skip_all)]
+fn routes_id_put_validation(
+    path_params: models::RoutesIdPutPathParams,
+    body: crate::types::Object,
+) -> std::result::Result<(models::RoutesIdPutPathParams, crate::types::Object), ValidationErrors> {
+    path_params.validate()?;
+    let b = RoutesIdPutBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((path_params, body))
+}
+/// RoutesIdPut - PUT /routes/{id}
+#[tracing::instrument(skip_all)]
+async fn routes_id_put<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    Path(path_params): Path<models::RoutesIdPutPathParams>,
+    State(api_impl): State<I>,
+    Json(body): Json<crate::types::Object>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::routes::Routes,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation =
+        tokio::task::spawn_blocking(move || routes_id_put_validation(path_params, body))
+            .await
+            .unwrap();
+
+    let Ok((path_params, body)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .routes_id_put(method, host, cookies, path_params, body)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::routes::RoutesIdPutResponse::Status200_RouteWasReplaced => {
+                let mut response = response.status(200);
+                response.body(Body::empty())
+            }
+            apis::routes::RoutesIdPutResponse::Status201_RouteWasCreated => {
+                let mut response = response.status(201);
+                response.body(Body::empty())
+            }
+            apis::routes::RoutesIdPutResponse::Status400_BadRequest(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::routes::RoutesIdPutResponse::Status500_InternalServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn schema_route_get_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// SchemaRouteGet - GET /schema/route
+#[tracing::instrument(skip_all)]
+async fn schema_route_get<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::routes::Routes,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || schema_route_get_validation())
+        .await
+        .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .schema_route_get(method, host, cookies)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::routes::SchemaRouteGetResponse::Status200_Success(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn default_service_delete_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// DefaultServiceDelete - DELETE /default_service
+#[tracing::instrument(skip_all)]
+async fn default_service_delete<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::services::Services,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || default_service_delete_validation())
+        .await
+        .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .default_service_delete(method, host, cookies)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::services::DefaultServiceDeleteResponse::Status200_Success => {
+                let mut response = response.status(200);
+                response.body(Body::empty())
+            }
+            apis::services::DefaultServiceDeleteResponse::Status404_NoDefaultServiceRegistered => {
+                let mut response = response.status(404);
+                response.body(Body::empty())
+            }
+            apis::services::DefaultServiceDeleteResponse::Status500_InternalServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn default_service_get_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// DefaultServiceGet - GET /default_service
+#[tracing::instrument(skip_all)]
+async fn default_service_get<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::services::Services,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || default_service_get_validation())
+        .await
+        .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .default_service_get(method, host, cookies)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::services::DefaultServiceGetResponse::Status200_Success(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::services::DefaultServiceGetResponse::Status404_NoDefaultServiceRegistered => {
+                let mut response = response.status(404);
+                response.body(Body::empty())
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct DefaultServicePostBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::DefaultServicePostRequest,
+}
+
+#[tracing::instrument(skip_all)]
+fn default_service_post_validation(
+    body: models::DefaultServicePostRequest,
+) -> std::result::Result<(models::DefaultServicePostRequest,), ValidationErrors> {
+    let b = DefaultServicePostBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((body,))
+}
+/// DefaultServicePost - POST /default_service
+#[tracing::instrument(skip_all)]
+async fn default_service_post<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    State(api_impl): State<I>,
+    Json(body): Json<models::DefaultServicePostRequest>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::services::Services,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || default_service_post_validation(body))
+        .await
+        .unwrap();
+
+    let Ok((body,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .default_service_post(method, host, cookies, body)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::services::DefaultServicePostResponse::Status200_DefaultServiceWasReplaced => {
+                let mut response = response.status(200);
+                response.body(Body::empty())
+            }
+            apis::services::DefaultServicePostResponse::Status201_DefaultServiceWasSet => {
+                let mut response = response.status(201);
+                response.body(Body::empty())
+            }
+            apis::services::DefaultServicePostResponse::Status404_ServiceNotFound => {
+                let mut response = response.status(404);
+                response.body(Body::empty())
+            }
+            apis::services::DefaultServicePostResponse::Status500_InternalServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn schema_service_types_service_type_config_get_validation(
+    path_params: models::SchemaServiceTypesServiceTypeConfigGetPathParams,
+) -> std::result::Result<
+    (models::SchemaServiceTypesServiceTypeConfigGetPathParams,),
+    ValidationErrors,
+> {
+    path_params.validate()?;
+
+    Ok((path_params,))
+}
+/// SchemaServiceTypesServiceTypeConfigGet - GET /schema/service_types/{service_type}/config
+#[tracing::instrument(skip_all)]
+async fn schema_service_types_service_type_config_get<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    Path(path_params): Path<models::SchemaServiceTypesServiceTypeConfigGetPathParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::services::Services,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || {
+        schema_service_types_service_type_config_get_validation(path_params)
+    })
+    .await
+    .unwrap();
+
+    let Ok((path_params,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .schema_service_types_service_type_config_get(method, host, cookies, path_params)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::services::SchemaServiceTypesServiceTypeConfigGetResponse::Status200_Success
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::services::SchemaServiceTypesServiceTypeConfigGetResponse::Status404_ServiceTypeNotFound
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  response.body(Body::empty())
+                                                },
                                             },
                                             Err(_) => {
                                                 // Application code returned an error. This should not happen, as the implementation should
@@ -257,15 +1745,311 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn default_service_delete_validation() -> std::result::Result<(), ValidationErrors> {
-    Ok(())
+fn services_get_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
+}
+/// ServicesGet - GET /services
+#[tracing::instrument(skip_all)]
+async fn services_get<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::services::Services,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation = tokio::task::spawn_blocking(move || services_get_validation())
+        .await
+        .unwrap();
+
+    let Ok(()) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl.as_ref().services_get(method, host, cookies).await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::services::ServicesGetResponse::Status200_Success(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn services_id_config_get_validation(
+    path_params: models::ServicesIdConfigGetPathParams,
+) -> std::result::Result<(models::ServicesIdConfigGetPathParams,), ValidationErrors> {
+    path_params.validate()?;
+
+    Ok((path_params,))
+}
+/// ServicesIdConfigGet - GET /services/{id}/config
+#[tracing::instrument(skip_all)]
+async fn services_id_config_get<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    Path(path_params): Path<models::ServicesIdConfigGetPathParams>,
+    State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::services::Services,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation =
+        tokio::task::spawn_blocking(move || services_id_config_get_validation(path_params))
+            .await
+            .unwrap();
+
+    let Ok((path_params,)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .services_id_config_get(method, host, cookies, path_params)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::services::ServicesIdConfigGetResponse::Status200_Success(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::services::ServicesIdConfigGetResponse::Status404_ServiceNotFound => {
+                let mut response = response.status(404);
+                response.body(Body::empty())
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct ServicesIdConfigPatchBodyValidator<'a> {
+    body: &'a crate::types::Object,
+}
+
+#[tracing::instrument(skip_all)]
+fn services_id_config_patch_validation(
+    path_params: models::ServicesIdConfigPatchPathParams,
+    body: crate::types::Object,
+) -> std::result::Result<
+    (
+        models::ServicesIdConfigPatchPathParams,
+        crate::types::Object,
+    ),
+    ValidationErrors,
+> {
+    path_params.validate()?;
+    let b = ServicesIdConfigPatchBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((path_params, body))
+}
+/// ServicesIdConfigPatch - PATCH /services/{id}/config
+#[tracing::instrument(skip_all)]
+async fn services_id_config_patch<I, A>(
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    Path(path_params): Path<models::ServicesIdConfigPatchPathParams>,
+    State(api_impl): State<I>,
+    Json(body): Json<crate::types::Object>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::services::Services,
+{
+    #[allow(clippy::redundant_closure)]
+    let validation =
+        tokio::task::spawn_blocking(move || services_id_config_patch_validation(path_params, body))
+            .await
+            .unwrap();
+
+    let Ok((path_params, body)) = validation else {
+        return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+    };
+
+    let result = api_impl
+        .as_ref()
+        .services_id_config_patch(method, host, cookies, path_params, body)
+        .await;
+
+    let mut response = Response::builder();
+
+    let resp = match result {
+        Ok(rsp) => match rsp {
+            apis::services::ServicesIdConfigPatchResponse::Status200_Success => {
+                let mut response = response.status(200);
+                response.body(Body::empty())
+            }
+            apis::services::ServicesIdConfigPatchResponse::Status400_BadRequest(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::services::ServicesIdConfigPatchResponse::Status404_ServiceNotFound => {
+                let mut response = response.status(404);
+                response.body(Body::empty())
+            }
+            apis::services::ServicesIdConfigPatchResponse::Status500_InternalServerError(body) => {
+                let mut response = response.status(500);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
+
+    resp.map_err(|e| {
+        error!(error = ?e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+#[tracing::instrument(skip_all)]
+fn services_id_config_schema_get_validation(
+    path_params: models::ServicesIdConfigSchemaGetPathParams,
+    query_params: models::ServicesIdConfigSchemaGetQueryParams,
+) -> std::result::Result<
+    (
+        models::ServicesIdConfigSchemaGetPathParams,
+        models::ServicesIdConfigSchemaGetQueryParams,
+    ),
+    ValidationErrors,
+> {
+    path_params.validate()?;
+    query_params.validate()?;
+
+    Ok((path_params, query_params))
 }
-/// DefaultServiceDelete - DELETE /default_service
+/// ServicesIdConfigSchemaGet - GET /services/{id}/config/schema
 #[tracing::instrument(skip_all)]
-async fn default_service_delete<I, A>(
+async fn services_id_config_schema_get<I, A>(
     method: Method,
     host: Host,
     cookies: CookieJar,
+    Path(path_params): Path<models::ServicesIdConfigSchemaGetPathParams>,
+    Query(query_params): Query<models::ServicesIdConfigSchemaGetQueryParams>,
     State(api_impl): State<I>,
 ) -> Result<Response, StatusCode>
 where
@@ -273,11 +2057,13 @@ where
     A: apis::services::Services,
 {
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || default_service_delete_validation())
-        .await
-        .unwrap();
+    let validation = tokio::task::spawn_blocking(move || {
+        services_id_config_schema_get_validation(path_params, query_params)
+    })
+    .await
+    .unwrap();
 
-    let Ok(()) = validation else {
+    let Ok((path_params, query_params)) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -286,23 +2072,15 @@ where
 
     let result = api_impl
         .as_ref()
-        .default_service_delete(method, host, cookies)
+        .services_id_config_schema_get(method, host, cookies, path_params, query_params)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::services::DefaultServiceDeleteResponse::Status200_Success => {
+            apis::services::ServicesIdConfigSchemaGetResponse::Status200_Success(body) => {
                 let mut response = response.status(200);
-                response.body(Body::empty())
-            }
-            apis::services::DefaultServiceDeleteResponse::Status404_NoDefaultServiceRegistered => {
-                let mut response = response.status(404);
-                response.body(Body::empty())
-            }
-            apis::services::DefaultServiceDeleteResponse::Status500_InternalServerError(body) => {
-                let mut response = response.status(500);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
                     response_headers.insert(
@@ -324,6 +2102,10 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
+            apis::services::ServicesIdConfigSchemaGetResponse::Status404_ServiceNotFound => {
+                let mut response = response.status(404);
+                response.body(Body::empty())
+            }
         },
         Err(_) => {
             // Application code returned an error. This should not happen, as the implementation should
@@ -339,15 +2121,20 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn default_service_get_validation() -> std::result::Result<(), ValidationErrors> {
-    Ok(())
+fn services_id_delete_validation(
+    path_params: models::ServicesIdDeletePathParams,
+) -> std::result::Result<(models::ServicesIdDeletePathParams,), ValidationErrors> {
+    path_params.validate()?;
+
+    Ok((path_params,))
 }
-/// DefaultServiceGet - GET /default_service
+/// ServicesIdDelete - DELETE /services/{id}
 #[tracing::instrument(skip_all)]
-async fn default_service_get<I, A>(
+async fn services_id_delete<I, A>(
     method: Method,
     host: Host,
     cookies: CookieJar,
+    Path(path_params): Path<models::ServicesIdDeletePathParams>,
     State(api_impl): State<I>,
 ) -> Result<Response, StatusCode>
 where
@@ -355,11 +2142,12 @@ where
     A: apis::services::Services,
 {
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || default_service_get_validation())
-        .await
-        .unwrap();
+    let validation =
+        tokio::task::spawn_blocking(move || services_id_delete_validation(path_params))
+            .await
+            .unwrap();
 
-    let Ok(()) = validation else {
+    let Ok((path_params,)) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -368,15 +2156,23 @@ where
 
     let result = api_impl
         .as_ref()
-        .default_service_get(method, host, cookies)
+        .services_id_delete(method, host, cookies, path_params)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::services::DefaultServiceGetResponse::Status200_Success(body) => {
+            apis::services::ServicesIdDeleteResponse::Status200_Success => {
                 let mut response = response.status(200);
+                response.body(Body::empty())
+            }
+            apis::services::ServicesIdDeleteResponse::Status404_ServiceNotFound => {
+                let mut response = response.status(404);
+                response.body(Body::empty())
+            }
+            apis::services::ServicesIdDeleteResponse::Status500_InternalServerError(body) => {
+                let mut response = response.status(500);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
                     response_headers.insert(
@@ -398,10 +2194,6 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::services::DefaultServiceGetResponse::Status404_NoDefaultServiceRegistered => {
-                let mut response = response.status(404);
-                response.body(Body::empty())
-            }
         },
         Err(_) => {
             // Application code returned an error. This should not happen, as the implementation should
@@ -416,41 +2208,33 @@ where
     })
 }
 
-#[derive(validator::Validate)]
-#[allow(dead_code)]
-struct DefaultServicePostBodyValidator<'a> {
-    #[validate(nested)]
-    body: &'a models::DefaultServicePostRequest,
-}
-
 #[tracing::instrument(skip_all)]
-fn default_service_post_validation(
-    body: models::DefaultServicePostRequest,
-) -> std::result::Result<(models::DefaultServicePostRequest,), ValidationErrors> {
-    let b = DefaultServicePostBodyValidator { body: &body };
-    b.validate()?;
+fn services_id_get_validation(
+    path_params: models::ServicesIdGetPathParams,
+) -> std::result::Result<(models::ServicesIdGetPathParams,), ValidationErrors> {
+    path_params.validate()?;
 
-    Ok((body,))
+    Ok((path_params,))
 }
-/// DefaultServicePost - POST /default_service
+/// ServicesIdGet - GET /services/{id}
 #[tracing::instrument(skip_all)]
-async fn default_service_post<I, A>(
+async fn services_id_get<I, A>(
     method: Method,
     host: Host,
     cookies: CookieJar,
+    Path(path_params): Path<models::ServicesIdGetPathParams>,
     State(api_impl): State<I>,
-    Json(body): Json<models::DefaultServicePostRequest>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
     A: apis::services::Services,
 {
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || default_service_post_validation(body))
+    let validation = tokio::task::spawn_blocking(move || services_id_get_validation(path_params))
         .await
         .unwrap();
 
-    let Ok((body,)) = validation else {
+    let Ok((path_params,)) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -459,27 +2243,15 @@ where
 
     let result = api_impl
         .as_ref()
-        .default_service_post(method, host, cookies, body)
+        .services_id_get(method, host, cookies, path_params)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::services::DefaultServicePostResponse::Status200_DefaultServiceWasReplaced => {
+            apis::services::ServicesIdGetResponse::Status200_Success(body) => {
                 let mut response = response.status(200);
-                response.body(Body::empty())
-            }
-            apis::services::DefaultServicePostResponse::Status201_DefaultServiceWasSet => {
-                let mut response = response.status(201);
-                response.body(Body::empty())
-            }
-            apis::services::DefaultServicePostResponse::Status404_ServiceNotFound => {
-                let mut response = response.status(404);
-                response.body(Body::empty())
-            }
-            apis::services::DefaultServicePostResponse::Status500_InternalServerError(body) => {
-                let mut response = response.status(500);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
                     response_headers.insert(
@@ -501,6 +2273,10 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
+            apis::services::ServicesIdGetResponse::Status404_ServiceNotFound => {
+                let mut response = response.status(404);
+                response.body(Body::empty())
+            }
         },
         Err(_) => {
             // Application code returned an error. This should not happen, as the implementation should
@@ -516,23 +2292,20 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn schema_service_types_service_type_config_get_validation(
-    path_params: models::SchemaServiceTypesServiceTypeConfigGetPathParams,
-) -> std::result::Result<
-    (models::SchemaServiceTypesServiceTypeConfigGetPathParams,),
-    ValidationErrors,
-> {
+fn services_id_notifications_schema_get_validation(
+    path_params: models::ServicesIdNotificationsSchemaGetPathParams,
+) -> std::result::Result<(models::ServicesIdNotificationsSchemaGetPathParams,), ValidationErrors> {
     path_params.validate()?;
 
     Ok((path_params,))
 }
-/// SchemaServiceTypesServiceTypeConfigGet - GET /schema/service_types/{service_type}/config
+/// ServicesIdNotificationsSchemaGet - GET /services/{id}/notifications/schema
 #[tracing::instrument(skip_all)]
-async fn schema_service_types_service_type_config_get<I, A>(
+async fn services_id_notifications_schema_get<I, A>(
     method: Method,
     host: Host,
     cookies: CookieJar,
-    Path(path_params): Path<models::SchemaServiceTypesServiceTypeConfigGetPathParams>,
+    Path(path_params): Path<models::ServicesIdNotificationsSchemaGetPathParams>,
     State(api_impl): State<I>,
 ) -> Result<Response, StatusCode>
 where
@@ -541,7 +2314,7 @@ where
 {
     #[allow(clippy::redundant_closure)]
     let validation = tokio::task::spawn_blocking(move || {
-        schema_service_types_service_type_config_get_validation(path_params)
+        services_id_notifications_schema_get_validation(path_params)
     })
     .await
     .unwrap();
@@ -555,43 +2328,47 @@ where
 
     let result = api_impl
         .as_ref()
-        .schema_service_types_service_type_config_get(method, host, cookies, path_params)
+        .services_id_notifications_schema_get(method, host, cookies, path_params)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
-                                            Ok(rsp) => match rsp {
-                                                apis::services::SchemaServiceTypesServiceTypeConfigGetResponse::Status200_Success
-                                                    (body)
-                                                => {
-                                                  let mut response = response.status(200);
-                                                  {
-                                                    let mut response_headers = response.headers_mut().unwrap();
-                                                    response_headers.insert(
-                                                        CONTENT_TYPE,
-                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
-                                                  }
+        Ok(rsp) => match rsp {
+            apis::services::ServicesIdNotificationsSchemaGetResponse::Status200_Success(body) => {
+                let mut response = response.status(200);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
 
-                                                  let body_content =  tokio::task::spawn_blocking(move ||
-                                                      serde_json::to_vec(&body).map_err(|e| {
-                                                        error!(error = ?e);
-                                                        StatusCode::INTERNAL_SERVER_ERROR
-                                                      })).await.unwrap()?;
-                                                  response.body(Body::from(body_content))
-                                                },
-                                                apis::services::SchemaServiceTypesServiceTypeConfigGetResponse::Status404_ServiceTypeNotFound
-                                                => {
-                                                  let mut response = response.status(404);
-                                                  response.body(Body::empty())
-                                                },
-                                            },
-                                            Err(_) => {
-                                                // Application code returned an error. This should not happen, as the implementation should
-                                                // return a valid response.
-                                                response.status(500).body(Body::empty())
-                                            },
-                                        };
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::services::ServicesIdNotificationsSchemaGetResponse::Status404_ServiceNotFound => {
+                let mut response = response.status(404);
+                response.body(Body::empty())
+            }
+        },
+        Err(_) => {
+            // Application code returned an error. This should not happen, as the implementation should
+            // return a valid response.
+            response.status(500).body(Body::empty())
+        }
+    };
 
     resp.map_err(|e| {
         error!(error = ?e);
@@ -599,42 +2376,99 @@ where
     })
 }
 
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct ServicesIdPutBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::ServicesIdPutRequest,
+}
+
 #[tracing::instrument(skip_all)]
-fn services_get_validation() -> std::result::Result<(), ValidationErrors> {
-    Ok(())
+fn services_id_put_validation(
+    path_params: models::ServicesIdPutPathParams,
+    body: models::ServicesIdPutRequest,
+) -> std::result::Result<
+    (
+        models::ServicesIdPutPathParams,
+        models::ServicesIdPutRequest,
+    ),
+    ValidationErrors,
+> {
+    path_params.validate()?;
+    let b = ServicesIdPutBodyValidator { body: &body };
+    b.validate()?;
+
+    Ok((path_params, body))
 }
-/// ServicesGet - GET /services
+/// ServicesIdPut - PUT /services/{id}
 #[tracing::instrument(skip_all)]
-async fn services_get<I, A>(
+async fn services_id_put<I, A>(
     method: Method,
     host: Host,
     cookies: CookieJar,
+    Path(path_params): Path<models::ServicesIdPutPathParams>,
     State(api_impl): State<I>,
+    Json(body): Json<models::ServicesIdPutRequest>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
     A: apis::services::Services,
 {
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || services_get_validation())
-        .await
-        .unwrap();
+    let validation =
+        tokio::task::spawn_blocking(move || services_id_put_validation(path_params, body))
+            .await
+            .unwrap();
 
-    let Ok(()) = validation else {
+    let Ok((path_params, body)) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
             .map_err(|_| StatusCode::BAD_REQUEST);
     };
 
-    let result = api_impl.as_ref().services_get(method, host, cookies).await;
+    let result = api_impl
+        .as_ref()
+        .services_id_put(method, host, cookies, path_params, body)
+        .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::services::ServicesGetResponse::Status200_Success(body) => {
+            apis::services::ServicesIdPutResponse::Status200_ServiceWasReplaced => {
                 let mut response = response.status(200);
+                response.body(Body::empty())
+            }
+            apis::services::ServicesIdPutResponse::Status201_ServiceWasCreated => {
+                let mut response = response.status(201);
+                response.body(Body::empty())
+            }
+            apis::services::ServicesIdPutResponse::Status400_BadRequest(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::services::ServicesIdPutResponse::Status500_InternalServerError(body) => {
+                let mut response = response.status(500);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
                     response_headers.insert(
@@ -671,20 +2505,20 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn services_id_config_get_validation(
-    path_params: models::ServicesIdConfigGetPathParams,
-) -> std::result::Result<(models::ServicesIdConfigGetPathParams,), ValidationErrors> {
+fn services_id_receivers_get_validation(
+    path_params: models::ServicesIdReceiversGetPathParams,
+) -> std::result::Result<(models::ServicesIdReceiversGetPathParams,), ValidationErrors> {
     path_params.validate()?;
 
     Ok((path_params,))
 }
-/// ServicesIdConfigGet - GET /services/{id}/config
+/// ServicesIdReceiversGet - GET /services/{id}/receivers
 #[tracing::instrument(skip_all)]
-async fn services_id_config_get<I, A>(
+async fn services_id_receivers_get<I, A>(
     method: Method,
     host: Host,
     cookies: CookieJar,
-    Path(path_params): Path<models::ServicesIdConfigGetPathParams>,
+    Path(path_params): Path<models::ServicesIdReceiversGetPathParams>,
     State(api_impl): State<I>,
 ) -> Result<Response, StatusCode>
 where
@@ -693,7 +2527,7 @@ where
 {
     #[allow(clippy::redundant_closure)]
     let validation =
-        tokio::task::spawn_blocking(move || services_id_config_get_validation(path_params))
+        tokio::task::spawn_blocking(move || services_id_receivers_get_validation(path_params))
             .await
             .unwrap();
 
@@ -706,14 +2540,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .services_id_config_get(method, host, cookies, path_params)
+        .services_id_receivers_get(method, host, cookies, path_params)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::services::ServicesIdConfigGetResponse::Status200_Success(body) => {
+            apis::services::ServicesIdReceiversGetResponse::Status200_Success(body) => {
                 let mut response = response.status(200);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -736,7 +2570,7 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::services::ServicesIdConfigGetResponse::Status404_ServiceNotFound => {
+            apis::services::ServicesIdReceiversGetResponse::Status404_ServiceNotFound => {
                 let mut response = response.status(404);
                 response.body(Body::empty())
             }
@@ -754,50 +2588,28 @@ where
     })
 }
 
-#[derive(validator::Validate)]
-#[allow(dead_code)]
-struct ServicesIdConfigPatchBodyValidator<'a> {
-    body: &'a crate::types::Object,
-}
-
 #[tracing::instrument(skip_all)]
-fn services_id_config_patch_validation(
-    path_params: models::ServicesIdConfigPatchPathParams,
-    body: crate::types::Object,
-) -> std::result::Result<
-    (
-        models::ServicesIdConfigPatchPathParams,
-        crate::types::Object,
-    ),
-    ValidationErrors,
-> {
-    path_params.validate()?;
-    let b = ServicesIdConfigPatchBodyValidator { body: &body };
-    b.validate()?;
-
-    Ok((path_params, body))
+fn schema_template_get_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
 }
-/// ServicesIdConfigPatch - PATCH /services/{id}/config
+/// SchemaTemplateGet - GET /schema/template
 #[tracing::instrument(skip_all)]
-async fn services_id_config_patch<I, A>(
+async fn schema_template_get<I, A>(
     method: Method,
     host: Host,
     cookies: CookieJar,
-    Path(path_params): Path<models::ServicesIdConfigPatchPathParams>,
     State(api_impl): State<I>,
-    Json(body): Json<crate::types::Object>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::services::Services,
+    A: apis::templates::Templates,
 {
     #[allow(clippy::redundant_closure)]
-    let validation =
-        tokio::task::spawn_blocking(move || services_id_config_patch_validation(path_params, body))
-            .await
-            .unwrap();
+    let validation = tokio::task::spawn_blocking(move || schema_template_get_validation())
+        .await
+        .unwrap();
 
-    let Ok((path_params, body)) = validation else {
+    let Ok(()) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -806,46 +2618,15 @@ where
 
     let result = api_impl
         .as_ref()
-        .services_id_config_patch(method, host, cookies, path_params, body)
+        .schema_template_get(method, host, cookies)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::services::ServicesIdConfigPatchResponse::Status200_Success => {
+            apis::templates::SchemaTemplateGetResponse::Status200_Success(body) => {
                 let mut response = response.status(200);
-                response.body(Body::empty())
-            }
-            apis::services::ServicesIdConfigPatchResponse::Status400_BadRequest(body) => {
-                let mut response = response.status(400);
-                {
-                    let mut response_headers = response.headers_mut().unwrap();
-                    response_headers.insert(
-                        CONTENT_TYPE,
-                        HeaderValue::from_str("application/json").map_err(|e| {
-                            error!(error = ?e);
-                            StatusCode::INTERNAL_SERVER_ERROR
-                        })?,
-                    );
-                }
-
-                let body_content = tokio::task::spawn_blocking(move || {
-                    serde_json::to_vec(&body).map_err(|e| {
-                        error!(error = ?e);
-                        StatusCode::INTERNAL_SERVER_ERROR
-                    })
-                })
-                .await
-                .unwrap()?;
-                response.body(Body::from(body_content))
-            }
-            apis::services::ServicesIdConfigPatchResponse::Status404_ServiceNotFound => {
-                let mut response = response.status(404);
-                response.body(Body::empty())
-            }
-            apis::services::ServicesIdConfigPatchResponse::Status500_InternalServerError(body) => {
-                let mut response = response.status(500);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
                     response_headers.insert(
@@ -882,59 +2663,40 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn services_id_config_schema_get_validation(
-    path_params: models::ServicesIdConfigSchemaGetPathParams,
-    query_params: models::ServicesIdConfigSchemaGetQueryParams,
-) -> std::result::Result<
-    (
-        models::ServicesIdConfigSchemaGetPathParams,
-        models::ServicesIdConfigSchemaGetQueryParams,
-    ),
-    ValidationErrors,
-> {
-    path_params.validate()?;
-    query_params.validate()?;
-
-    Ok((path_params, query_params))
+fn templates_get_validation() -> std::result::Result<(), ValidationErrors> {
+    Ok(())
 }
-/// ServicesIdConfigSchemaGet - GET /services/{id}/config/schema
+/// TemplatesGet - GET /templates
 #[tracing::instrument(skip_all)]
-async fn services_id_config_schema_get<I, A>(
+async fn templates_get<I, A>(
     method: Method,
     host: Host,
     cookies: CookieJar,
-    Path(path_params): Path<models::ServicesIdConfigSchemaGetPathParams>,
-    Query(query_params): Query<models::ServicesIdConfigSchemaGetQueryParams>,
     State(api_impl): State<I>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::services::Services,
+    A: apis::templates::Templates,
 {
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || {
-        services_id_config_schema_get_validation(path_params, query_params)
-    })
-    .await
-    .unwrap();
+    let validation = tokio::task::spawn_blocking(move || templates_get_validation())
+        .await
+        .unwrap();
 
-    let Ok((path_params, query_params)) = validation else {
+    let Ok(()) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
             .map_err(|_| StatusCode::BAD_REQUEST);
     };
 
-    let result = api_impl
-        .as_ref()
-        .services_id_config_schema_get(method, host, cookies, path_params, query_params)
-        .await;
+    let result = api_impl.as_ref().templates_get(method, host, cookies).await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::services::ServicesIdConfigSchemaGetResponse::Status200_Success(body) => {
+            apis::templates::TemplatesGetResponse::Status200_Success(body) => {
                 let mut response = response.status(200);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -957,10 +2719,6 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::services::ServicesIdConfigSchemaGetResponse::Status404_ServiceNotFound => {
-                let mut response = response.status(404);
-                response.body(Body::empty())
-            }
         },
         Err(_) => {
             // Application code returned an error. This should not happen, as the implementation should
@@ -976,29 +2734,29 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn services_id_delete_validation(
-    path_params: models::ServicesIdDeletePathParams,
-) -> std::result::Result<(models::ServicesIdDeletePathParams,), ValidationErrors> {
+fn templates_id_delete_validation(
+    path_params: models::TemplatesIdDeletePathParams,
+) -> std::result::Result<(models::TemplatesIdDeletePathParams,), ValidationErrors> {
     path_params.validate()?;
 
     Ok((path_params,))
 }
-/// ServicesIdDelete - DELETE /services/{id}
+/// TemplatesIdDelete - DELETE /templates/{id}
 #[tracing::instrument(skip_all)]
-async fn services_id_delete<I, A>(
+async fn templates_id_delete<I, A>(
     method: Method,
     host: Host,
     cookies: CookieJar,
-    Path(path_params): Path<models::ServicesIdDeletePathParams>,
+    Path(path_params): Path<models::TemplatesIdDeletePathParams>,
     State(api_impl): State<I>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::services::Services,
+    A: apis::templates::Templates,
 {
     #[allow(clippy::redundant_closure)]
     let validation =
-        tokio::task::spawn_blocking(move || services_id_delete_validation(path_params))
+        tokio::task::spawn_blocking(move || templates_id_delete_validation(path_params))
             .await
             .unwrap();
 
@@ -1011,22 +2769,22 @@ where
 
     let result = api_impl
         .as_ref()
-        .services_id_delete(method, host, cookies, path_params)
+        .templates_id_delete(method, host, cookies, path_params)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::services::ServicesIdDeleteResponse::Status200_Success => {
+            apis::templates::TemplatesIdDeleteResponse::Status200_Success => {
                 let mut response = response.status(200);
                 response.body(Body::empty())
             }
-            apis::services::ServicesIdDeleteResponse::Status404_ServiceNotFound => {
+            apis::templates::TemplatesIdDeleteResponse::Status404_TemplateNotFound => {
                 let mut response = response.status(404);
                 response.body(Body::empty())
             }
-            apis::services::ServicesIdDeleteResponse::Status500_InternalServerError(body) => {
+            apis::templates::TemplatesIdDeleteResponse::Status500_InternalServerError(body) => {
                 let mut response = response.status(500);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -1064,28 +2822,28 @@ where
 }
 
 #[tracing::instrument(skip_all)]
-fn services_id_get_validation(
-    path_params: models::ServicesIdGetPathParams,
-) -> std::result::Result<(models::ServicesIdGetPathParams,), ValidationErrors> {
+fn templates_id_get_validation(
+    path_params: models::TemplatesIdGetPathParams,
+) -> std::result::Result<(models::TemplatesIdGetPathParams,), ValidationErrors> {
     path_params.validate()?;
 
     Ok((path_params,))
 }
-/// ServicesIdGet - GET /services/{id}
+/// TemplatesIdGet - GET /templates/{id}
 #[tracing::instrument(skip_all)]
-async fn services_id_get<I, A>(
+async fn templates_id_get<I, A>(
     method: Method,
     host: Host,
     cookies: CookieJar,
-    Path(path_params): Path<models::ServicesIdGetPathParams>,
+    Path(path_params): Path<models::TemplatesIdGetPathParams>,
     State(api_impl): State<I>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::services::Services,
+    A: apis::templates::Templates,
 {
     #[allow(clippy::redundant_closure)]
-    let validation = tokio::task::spawn_blocking(move || services_id_get_validation(path_params))
+    let validation = tokio::task::spawn_blocking(move || templates_id_get_validation(path_params))
         .await
         .unwrap();
 
@@ -1098,14 +2856,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .services_id_get(method, host, cookies, path_params)
+        .templates_id_get(method, host, cookies, path_params)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::services::ServicesIdGetResponse::Status200_Success(body) => {
+            apis::templates::TemplatesIdGetResponse::Status200_Success(body) => {
                 let mut response = response.status(200);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -1128,7 +2886,7 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::services::ServicesIdGetResponse::Status404_ServiceNotFound => {
+            apis::templates::TemplatesIdGetResponse::Status404_TemplateNotFound => {
                 let mut response = response.status(404);
                 response.body(Body::empty())
             }
@@ -1146,35 +2904,52 @@ where
     })
 }
 
+#[derive(validator::Validate)]
+#[allow(dead_code)]
+struct TemplatesIdPreviewPostBodyValidator<'a> {
+    #[validate(nested)]
+    body: &'a models::TemplateSample,
+}
+
 #[tracing::instrument(skip_all)]
-fn services_id_notifications_schema_get_validation(
-    path_params: models::ServicesIdNotificationsSchemaGetPathParams,
-) -> std::result::Result<(models::ServicesIdNotificationsSchemaGetPathParams,), ValidationErrors> {
+fn templates_id_preview_post_validation(
+    path_params: models::TemplatesIdPreviewPostPathParams,
+    body: models::TemplateSample,
+) -> std::result::Result<
+    (
+        models::TemplatesIdPreviewPostPathParams,
+        models::TemplateSample,
+    ),
+    ValidationErrors,
+> {
     path_params.validate()?;
+    let b = TemplatesIdPreviewPostBodyValidator { body: &body };
+    b.validate()?;
 
-    Ok((path_params,))
+    Ok((path_params, body))
 }
-/// ServicesIdNotificationsSchemaGet - GET /services/{id}/notifications/schema
+/// TemplatesIdPreviewPost - POST /templates/{id}/preview
 #[tracing::instrument(skip_all)]
-async fn services_id_notifications_schema_get<I, A>(
+async fn templates_id_preview_post<I, A>(
     method: Method,
     host: Host,
     cookies: CookieJar,
-    Path(path_params): Path<models::ServicesIdNotificationsSchemaGetPathParams>,
+    Path(path_params): Path<models::TemplatesIdPreviewPostPathParams>,
     State(api_impl): State<I>,
+    Json(body): Json<models::TemplateSample>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::services::Services,
+    A: apis::templates::Templates,
 {
     #[allow(clippy::redundant_closure)]
     let validation = tokio::task::spawn_blocking(move || {
-        services_id_notifications_schema_get_validation(path_params)
+        templates_id_preview_post_validation(path_params, body)
     })
     .await
     .unwrap();
 
-    let Ok((path_params,)) = validation else {
+    let Ok((path_params, body)) = validation else {
         return Response::builder()
             .status(StatusCode::BAD_REQUEST)
             .body(Body::from(validation.unwrap_err().to_string()))
@@ -1183,14 +2958,14 @@ where
 
     let result = api_impl
         .as_ref()
-        .services_id_notifications_schema_get(method, host, cookies, path_params)
+        .templates_id_preview_post(method, host, cookies, path_params, body)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::services::ServicesIdNotificationsSchemaGetResponse::Status200_Success(body) => {
+            apis::templates::TemplatesIdPreviewPostResponse::Status200_Success(body) => {
                 let mut response = response.status(200);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -1213,7 +2988,30 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::services::ServicesIdNotificationsSchemaGetResponse::Status404_ServiceNotFound => {
+            apis::templates::TemplatesIdPreviewPostResponse::Status400_BadRequest(body) => {
+                let mut response = response.status(400);
+                {
+                    let mut response_headers = response.headers_mut().unwrap();
+                    response_headers.insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_str("application/json").map_err(|e| {
+                            error!(error = ?e);
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        })?,
+                    );
+                }
+
+                let body_content = tokio::task::spawn_blocking(move || {
+                    serde_json::to_vec(&body).map_err(|e| {
+                        error!(error = ?e);
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    })
+                })
+                .await
+                .unwrap()?;
+                response.body(Body::from(body_content))
+            }
+            apis::templates::TemplatesIdPreviewPostResponse::Status404_TemplateNotFound => {
                 let mut response = response.status(404);
                 response.body(Body::empty())
             }
@@ -1233,45 +3031,39 @@ where
 
 #[derive(validator::Validate)]
 #[allow(dead_code)]
-struct ServicesIdPutBodyValidator<'a> {
-    #[validate(nested)]
-    body: &'a models::ServicesIdPutRequest,
+struct TemplatesIdPutBodyValidator<'a> {
+    body: &'a crate::types::Object,
 }
 
 #[tracing::instrument(skip_all)]
-fn services_id_put_validation(
-    path_params: models::ServicesIdPutPathParams,
-    body: models::ServicesIdPutRequest,
-) -> std::result::Result<
-    (
-        models::ServicesIdPutPathParams,
-        models::ServicesIdPutRequest,
-    ),
-    ValidationErrors,
-> {
+fn templates_id_put_validation(
+    path_params: models::TemplatesIdPutPathParams,
+    body: crate::types::Object,
+) -> std::result::Result<(models::TemplatesIdPutPathParams, crate::types::Object), ValidationErrors>
+{
     path_params.validate()?;
-    let b = ServicesIdPutBodyValidator { body: &body };
+    let b = TemplatesIdPutBodyValidator { body: &body };
     b.validate()?;
 
     Ok((path_params, body))
 }
-/// ServicesIdPut - PUT /services/{id}
+/// TemplatesIdPut - PUT /templates/{id}
 #[tracing::instrument(skip_all)]
-async fn services_id_put<I, A>(
+async fn templates_id_put<I, A>(
     method: Method,
     host: Host,
     cookies: CookieJar,
-    Path(path_params): Path<models::ServicesIdPutPathParams>,
+    Path(path_params): Path<models::TemplatesIdPutPathParams>,
     State(api_impl): State<I>,
-    Json(body): Json<models::ServicesIdPutRequest>,
+    Json(body): Json<crate::types::Object>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::services::Services,
+    A: apis::templates::Templates,
 {
     #[allow(clippy::redundant_closure)]
     let validation =
-        tokio::task::spawn_blocking(move || services_id_put_validation(path_params, body))
+        tokio::task::spawn_blocking(move || templates_id_put_validation(path_params, body))
             .await
             .unwrap();
 
@@ -1284,22 +3076,22 @@ where
 
     let result = api_impl
         .as_ref()
-        .services_id_put(method, host, cookies, path_params, body)
+        .templates_id_put(method, host, cookies, path_params, body)
         .await;
 
     let mut response = Response::builder();
 
     let resp = match result {
         Ok(rsp) => match rsp {
-            apis::services::ServicesIdPutResponse::Status200_ServiceWasReplaced => {
+            apis::templates::TemplatesIdPutResponse::Status200_TemplateWasReplaced => {
                 let mut response = response.status(200);
                 response.body(Body::empty())
             }
-            apis::services::ServicesIdPutResponse::Status201_ServiceWasCreated => {
+            apis::templates::TemplatesIdPutResponse::Status201_TemplateWasCreated => {
                 let mut response = response.status(201);
                 response.body(Body::empty())
             }
-            apis::services::ServicesIdPutResponse::Status400_BadRequest(body) => {
+            apis::templates::TemplatesIdPutResponse::Status400_BadRequest(body) => {
                 let mut response = response.status(400);
                 {
                     let mut response_headers = response.headers_mut().unwrap();
@@ -1322,7 +3114,7 @@ where
                 .unwrap()?;
                 response.body(Body::from(body_content))
             }
-            apis::services::ServicesIdPutResponse::Status500_InternalServerError(body) => {
+            apis::templates::TemplatesIdPutResponse::Status500_InternalServerError(body) => {
                 let mut response = response.status(500);
                 {
                     let mut response_headers = response.headers_mut().unwrap();